@@ -0,0 +1,13 @@
+// expect: E0382
+// lesson: ownership
+// from: outln!(out, "{:?}", str_arr2);
+//
+// `for st in array` iterates by value and moves every element out.
+
+fn main() {
+    let str_arr2 = [String::from("one"), String::from("two")];
+    for st in str_arr2 {
+        println!("st = {}", st);
+    }
+    println!("{:?}", str_arr2);
+}
@@ -0,0 +1,16 @@
+// expect: E0382
+// lesson: ownership
+// from: outln!(out, "{:?}", str_arr1);
+//
+// An array of Strings is not Copy, so assigning it moves the whole array.
+
+fn main() {
+    let str_arr1 = [
+        String::from("one"),
+        String::from("two"),
+        String::from("three"),
+    ];
+    let str_arr2 = str_arr1;
+    println!("{:?}", str_arr2);
+    println!("{:?}", str_arr1);
+}
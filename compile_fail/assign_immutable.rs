@@ -0,0 +1,11 @@
+// expect: E0384
+// lesson: variables
+// from: x += 3;
+//
+// Bindings are immutable unless declared with `let mut`.
+
+fn main() {
+    let x = 5;
+    x += 3;
+    println!("x = {}", x);
+}
@@ -0,0 +1,13 @@
+// expect: E0597
+// lesson: borrowing
+//
+// A reference may not outlive the value it points to.
+
+fn main() {
+    let r;
+    {
+        let s = String::from("short-lived");
+        r = &s;
+    }
+    println!("{}", r);
+}
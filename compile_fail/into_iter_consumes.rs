@@ -0,0 +1,13 @@
+// expect: E0382
+// lesson: iterators
+// from: outln!(out, "{:?}", v3);
+//
+// `.into_iter()` takes the vector by value.
+
+fn main() {
+    let v3 = vec![1, 2, 3];
+    for x in v3.into_iter() {
+        println!("x = {}", x);
+    }
+    println!("{:?}", v3);
+}
@@ -0,0 +1,15 @@
+// expect: E0505
+// lesson: ownership
+//
+// A value cannot move while a reference to it is still in use.
+
+fn consume(s: String) {
+    println!("{}", s);
+}
+
+fn main() {
+    let s = String::from("hello");
+    let r = &s;
+    consume(s);
+    println!("{}", r);
+}
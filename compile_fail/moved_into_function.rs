@@ -0,0 +1,16 @@
+// expect: E0382
+// lesson: functions-ownership
+// from: outln!(out, "{}", s);
+//
+// Passing a String by value moves it into the function.
+
+fn take_ownership(s: String) -> String {
+    s
+}
+
+fn main() {
+    let s = String::from("Hello");
+    let s2 = take_ownership(s);
+    println!("s2 = {}", s2);
+    println!("{}", s);
+}
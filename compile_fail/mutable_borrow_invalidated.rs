@@ -0,0 +1,16 @@
+// expect: E0499
+// lesson: borrowing
+// from: outln!(out, "{}", mutable_borrow);
+//
+// Using the first `&mut` after a second one was taken means both are alive
+// at the same time.
+
+fn main() {
+    let mut mutable_string = String::from("Hello");
+    let mutable_borrow = &mut mutable_string;
+    mutable_borrow.push_str(", world!");
+
+    let mutable_borrow2 = &mut mutable_string;
+    mutable_borrow2.push_str(" Welcome to Rust!");
+    println!("{}", mutable_borrow);
+}
@@ -0,0 +1,12 @@
+// expect: E0502
+// lesson: borrowing
+//
+// A `&mut` cannot be taken while a `&` to the same value is still used.
+
+fn main() {
+    let mut s = String::from("hello");
+    let reader = &s;
+    let writer = &mut s;
+    writer.push_str(", world");
+    println!("{}", reader);
+}
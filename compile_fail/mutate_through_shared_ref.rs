@@ -0,0 +1,14 @@
+// expect: E0596
+// lesson: borrowing-functions
+//
+// A function that changes its argument must ask for `&mut`.
+
+fn add_suffix(s: &String) {
+    s.push_str("!");
+}
+
+fn main() {
+    let s = String::from("hello");
+    add_suffix(&s);
+    println!("{}", s);
+}
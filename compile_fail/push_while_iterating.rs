@@ -0,0 +1,14 @@
+// expect: E0502
+// lesson: vectors
+//
+// `push` may reallocate the vector, so it needs a `&mut` that conflicts with
+// the loop's `&`.
+
+fn main() {
+    let mut v = vec![1, 2, 3];
+    for x in &v {
+        if *x == 2 {
+            v.push(4);
+        }
+    }
+}
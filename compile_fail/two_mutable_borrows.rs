@@ -0,0 +1,12 @@
+// expect: E0499
+// lesson: borrowing
+//
+// Only one `&mut` may be alive at a time.
+
+fn main() {
+    let mut s = String::from("hello");
+    let first = &mut s;
+    let second = &mut s;
+    first.push('!');
+    second.push('?');
+}
@@ -0,0 +1,12 @@
+// expect: E0382
+// lesson: ownership
+// from: outln!(out, "s1 = {}", s1);
+//
+// Assigning a String moves it; the old name can no longer be used.
+
+fn main() {
+    let s1 = String::from("test ownership");
+    let s2 = s1;
+    println!("s2 = {}", s2);
+    println!("s1 = {}", s1);
+}
@@ -0,0 +1,392 @@
+//! ============================================================================
+//! CHALLENGE MODULE - "Will it compile?" backed by the local rustc
+//! ============================================================================
+//! `rust-lab challenge` shows short borrowing snippets one at a time. For each
+//! one the learner says whether it compiles and, if not, which rule it breaks.
+//! The snippet is then compiled for real (`rustc::check`) and rustc's verdict,
+//! error code and message are shown next to the learner's answer.
+//!
+//! | Code    | Rule broken                                 |
+//! |---------|---------------------------------------------|
+//! | `E0382` | A value is used after it was moved          |
+//! | `E0499` | Two mutable borrows are alive at once       |
+//! | `E0502` | A mutable borrow overlaps an immutable one  |
+//! | `E0506` | A value is assigned to while it is borrowed |
+//!
+//! rustc is the answer key: the bank stores no expected result, so the
+//! challenge can never disagree with the compiler. Snippets follow
+//! `borrowing::learn_borrowing`, and each points back to the section that
+//! explains it.
+//! ============================================================================
+
+use std::io::{self, BufRead, Write};
+
+use crate::highlight::{self, Colors};
+use crate::lessons::Registry;
+use crate::progress::Score;
+use crate::quiz::Rng;
+use crate::rustc;
+
+/// One snippet of the bank
+#[derive(Debug)]
+pub struct Challenge {
+    /// File name stem, shown in rustc's messages (`two_readers.rs`)
+    pub name: &'static str,
+    /// Id of the lesson that explains the snippet
+    pub lesson: &'static str,
+    /// Title of the lesson section (`out.section(..)`) that explains it
+    pub section: &'static str,
+    pub code: &'static str,
+}
+
+/// The reasons a learner can pick when they answer "does not compile"
+pub const REASONS: &[(&str, &str)] = &[
+    ("E0382", "A value is used after it was moved"),
+    ("E0499", "Two mutable borrows are alive at once"),
+    ("E0502", "A mutable borrow overlaps an immutable one"),
+    ("E0506", "A value is assigned to while it is borrowed"),
+];
+
+/// The snippet bank
+pub const CHALLENGES: &[Challenge] = &[
+    Challenge {
+        name: "two_readers",
+        lesson: "borrowing",
+        section: "Immutable Borrowing",
+        code: r#"fn main() {
+    let original = String::from("Hello, Rust!");
+    let borrow1 = &original;
+    let borrow2 = &original;
+    println!("{} {} {}", original, borrow1, borrow2);
+}"#,
+    },
+    Challenge {
+        name: "two_writers",
+        lesson: "borrowing",
+        section: "Mutable Borrowing",
+        code: r#"fn main() {
+    let mut s = String::from("Hello");
+    let first = &mut s;
+    let second = &mut s;
+    first.push_str(", world!");
+    second.push_str(" Welcome!");
+}"#,
+    },
+    Challenge {
+        name: "one_writer_after_another",
+        lesson: "borrowing",
+        section: "Mutable Borrowing",
+        code: r#"fn main() {
+    let mut s = String::from("Hello");
+    let first = &mut s;
+    first.push_str(", world!");
+    let second = &mut s;
+    second.push_str(" Welcome!");
+    println!("{}", s);
+}"#,
+    },
+    Challenge {
+        name: "first_writer_used_late",
+        lesson: "borrowing",
+        section: "Mutable Borrowing",
+        code: r#"fn main() {
+    let mut s = String::from("Hello");
+    let first = &mut s;
+    let second = &mut s;
+    second.push_str(" Welcome!");
+    println!("{}", first);
+}"#,
+    },
+    Challenge {
+        name: "owner_reads_during_write",
+        lesson: "borrowing",
+        section: "Mutable Borrowing",
+        code: r#"fn main() {
+    let mut s = String::from("Hello");
+    let writer = &mut s;
+    println!("{}", s);
+    writer.push_str(", world!");
+}"#,
+    },
+    Challenge {
+        name: "owner_writes_during_read",
+        lesson: "borrowing",
+        section: "Owner Direct Mutation",
+        code: r#"fn main() {
+    let mut arr = [12, 23, 34];
+    let first = &arr[0];
+    arr[0] += 1;
+    println!("{}", first);
+}"#,
+    },
+    Challenge {
+        name: "owner_writes_after_read",
+        lesson: "borrowing",
+        section: "Owner Direct Mutation",
+        code: r#"fn main() {
+    let mut arr = [12, 23, 34];
+    let first = &arr[0];
+    println!("{}", first);
+    arr[0] += 1;
+    println!("{:?}", arr);
+}"#,
+    },
+    Challenge {
+        name: "array_borrow_then_owner",
+        lesson: "borrowing",
+        section: "Mutable Borrow of Array",
+        code: r#"fn main() {
+    let mut arr = [1, 2, 3, 4, 5];
+    let arr_borrow = &mut arr;
+    for i in 0..arr_borrow.len() {
+        arr_borrow[i] += 10;
+    }
+    println!("{:?}", arr);
+}"#,
+    },
+    Challenge {
+        name: "push_while_reading",
+        lesson: "borrowing",
+        section: "Immutable Borrowing",
+        code: r#"fn main() {
+    let mut s = String::from("Hello");
+    let reader = &s;
+    s.push_str(", world!");
+    println!("{}", reader);
+}"#,
+    },
+    Challenge {
+        name: "lend_twice_to_function",
+        lesson: "borrowing-functions",
+        section: "Mutable Borrow in Function",
+        code: r#"fn append_world(s: &mut String) {
+    s.push_str(", world!");
+}
+
+fn main() {
+    let mut s = String::from("Hi");
+    append_world(&mut s);
+    append_world(&mut s);
+    println!("{}", s);
+}"#,
+    },
+    Challenge {
+        name: "borrow_across_function_call",
+        lesson: "borrowing-functions",
+        section: "Mutable Borrow in Function",
+        code: r#"fn append_world(s: &mut String) {
+    s.push_str(", world!");
+}
+
+fn main() {
+    let mut s = String::from("Hi");
+    let held = &mut s;
+    append_world(&mut s);
+    held.push('!');
+}"#,
+    },
+    Challenge {
+        name: "read_after_move",
+        lesson: "ownership",
+        section: "Move Semantics (String)",
+        code: r#"fn main() {
+    let s1 = String::from("test ownership");
+    let s2 = s1;
+    let r = &s1;
+    println!("{} {}", s2, r);
+}"#,
+    },
+];
+
+/// What the learner answered for one snippet
+enum Guess {
+    Compiles,
+    /// Fails with the error code of the rule they picked
+    Fails(&'static str),
+}
+
+/// Reads one trimmed, lowercase line; `None` at end of input
+fn read_reply(input: &mut dyn BufRead, out: &mut dyn Write) -> io::Result<Option<String>> {
+    write!(out, "> ")?;
+    out.flush()?;
+    let mut reply = String::new();
+    if input.read_line(&mut reply)? == 0 {
+        return Ok(None);
+    }
+    Ok(Some(reply.trim().to_lowercase()))
+}
+
+/// Asks "does it compile?" and, for "no", which rule is broken
+fn ask(input: &mut dyn BufRead, out: &mut dyn Write) -> io::Result<Option<Guess>> {
+    writeln!(out, "\nDoes it compile? (y / n)")?;
+    let compiles = loop {
+        match read_reply(input, out)?.as_deref() {
+            None => return Ok(None),
+            Some("y" | "yes") => break true,
+            Some("n" | "no") => break false,
+            Some(_) => writeln!(out, "  (answer y or n)")?,
+        }
+    };
+    if compiles {
+        return Ok(Some(Guess::Compiles));
+    }
+
+    writeln!(out, "Why not?")?;
+    for (i, (code, reason)) in REASONS.iter().enumerate() {
+        writeln!(out, "  {}) {} ({})", i + 1, reason, code)?;
+    }
+    loop {
+        let Some(reply) = read_reply(input, out)? else {
+            return Ok(None);
+        };
+        match reply.parse::<usize>() {
+            Ok(n) if (1..=REASONS.len()).contains(&n) => {
+                return Ok(Some(Guess::Fails(REASONS[n - 1].0)));
+            }
+            _ => writeln!(out, "  (answer 1 to {})", REASONS.len())?,
+        }
+    }
+}
+
+fn describe(guess: &Guess) -> String {
+    match guess {
+        Guess::Compiles => "compiles".to_string(),
+        Guess::Fails(code) => {
+            let reason = REASONS.iter().find(|(c, _)| c == code).map_or("", |r| r.1);
+            format!("does not compile — {} ({})", reason, code)
+        }
+    }
+}
+
+/// Shows every snippet in an order shuffled by `seed`, compiling each one
+/// after the learner answers
+///
+/// Returns `None` when the input ends before the last snippet.
+pub fn run(
+    registry: &Registry,
+    seed: u64,
+    colors: Colors,
+    input: &mut dyn BufRead,
+    out: &mut dyn Write,
+) -> io::Result<Option<Score>> {
+    let mut challenges: Vec<&Challenge> = CHALLENGES.iter().collect();
+    Rng::new(seed).shuffle(&mut challenges);
+    let total = challenges.len() as u32;
+    let mut correct = 0;
+
+    for (n, challenge) in challenges.into_iter().enumerate() {
+        writeln!(out, "\nSnippet {}/{}: {}.rs", n + 1, total, challenge.name)?;
+        write!(out, "{}", highlight::indented(challenge.code, colors))?;
+
+        let Some(guess) = ask(input, out)? else {
+            writeln!(
+                out,
+                "\nChallenge ended early ({} of {} answered).",
+                n, total
+            )?;
+            return Ok(None);
+        };
+
+        let file_name = format!("{}.rs", challenge.name);
+        let compilation = rustc::check(challenge.code, &file_name).map_err(io::Error::other)?;
+        let first_error = compilation.errors().next();
+
+        writeln!(out, "\nYou said: {}", describe(&guess))?;
+        match first_error {
+            None if compilation.success => writeln!(out, "rustc:    compiles ✅")?,
+            None => writeln!(out, "rustc:    does not compile (no error message)")?,
+            Some(error) => {
+                writeln!(out, "rustc:    does not compile ❌\n")?;
+                let code = error
+                    .code
+                    .as_deref()
+                    .map_or(String::new(), |c| format!("[{}]", c));
+                let fallback = format!("error{}: {}", code, error.message);
+                let rendered = error.rendered.as_deref().unwrap_or(&fallback);
+                for line in rendered.trim_end().lines() {
+                    writeln!(out, "    {}", line.trim_end())?;
+                }
+            }
+        }
+
+        let codes = compilation.error_codes();
+        let right = match guess {
+            Guess::Compiles => compilation.success,
+            Guess::Fails(code) => !compilation.success && codes.contains(&code),
+        };
+        if right {
+            correct += 1;
+            writeln!(out, "✅ Correct!")?;
+        } else {
+            writeln!(out, "❌ Not quite.")?;
+            if let Some(lesson) = registry.find(challenge.lesson) {
+                writeln!(
+                    out,
+                    "   📖 Review lesson {}, section \"{}\" (`rust-lab run {}`)",
+                    lesson.number(),
+                    challenge.section,
+                    lesson.id()
+                )?;
+            }
+        }
+    }
+
+    writeln!(out, "\nScore: {}/{}", correct, total)?;
+    Ok(Some(Score { correct, total }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lessons;
+    use crate::validate;
+
+    #[test]
+    fn every_failing_snippet_breaks_a_listed_rule() {
+        let problems: Vec<String> = CHALLENGES
+            .iter()
+            .filter_map(|challenge| {
+                let file_name = format!("{}.rs", challenge.name);
+                let compilation = match rustc::check(challenge.code, &file_name) {
+                    Ok(compilation) => compilation,
+                    Err(error) => return Some(error.to_string()),
+                };
+                let codes = compilation.error_codes();
+                let listed = codes
+                    .iter()
+                    .any(|code| REASONS.iter().any(|(reason, _)| reason == code));
+                (!compilation.success && !listed).then(|| {
+                    format!(
+                        "{}: rustc reported {:?}, none of them in REASONS",
+                        challenge.name, codes
+                    )
+                })
+            })
+            .collect();
+
+        assert!(problems.is_empty(), "\n{}", problems.join("\n"));
+    }
+
+    #[test]
+    fn challenges_have_unique_names_and_real_lesson_sections() {
+        let registry = lessons::registry();
+        let mut problems = Vec::new();
+        let mut seen = Vec::new();
+        for challenge in CHALLENGES {
+            if seen.contains(&challenge.name) {
+                problems.push(format!("duplicate challenge name '{}'", challenge.name));
+            }
+            seen.push(challenge.name);
+
+            let label = format!("challenge '{}'", challenge.name);
+            validate::check_lesson_section(
+                &registry,
+                &label,
+                challenge.lesson,
+                Some(challenge.section),
+                &mut problems,
+            );
+        }
+        assert_eq!(problems, Vec::<String>::new());
+    }
+}
@@ -0,0 +1,1577 @@
+//! ============================================================================
+//! CLI MODULE - Command-line parsing for the lesson runner
+//! ============================================================================
+//! Usage:
+//! - `rust-lab`                  → Run every lesson in order
+//! - `rust-lab list`             → List lessons grouped by phase
+//! - `rust-lab menu`             → Browse phases and lessons full screen (arrow keys)
+//! - `rust-lab run <lesson>`     → Run one lesson by id or number (`ownership`, `13`)
+//! - `rust-lab run <from>-<to>`  → Run an inclusive range of lessons (`6-9`, `6..=9`)
+//! - `rust-lab run <from>..<to>` → Run an exclusive range of lessons (`6..10`)
+//! - `rust-lab run --phase <N>`  → Run every lesson in phase N
+//! - `rust-lab run ... --quiet`  → Run lessons without printing their output
+//! - `rust-lab run ... --show-source` → Print each section's code before its output
+//! - `rust-lab run ... --step`   → Pause after each section (Enter/n, b, q); resumes later
+//! - `rust-lab export --format md|html --out <dir>` → Write one page per lesson + index
+//! - `rust-lab graph [--dot]`    → Show lesson prerequisites as text or Graphviz DOT
+//! - `rust-lab progress`         → Show completed lessons and quiz scores by phase
+//! - `rust-lab reset`            → Forget all recorded progress and flashcard schedules
+//! - `rust-lab quiz <lesson> [--seed <n>]` → Answer the lesson's questions; scores are recorded
+//! - `rust-lab predict <lesson> [--section <n>]` → Guess each section's output, then see a diff
+//! - `rust-lab challenge [--seed <n>]` → "Will it compile?": answer, then see rustc's verdict
+//! - `rust-lab explain [<file>]` → Link rustc/cargo JSON errors (file or stdin) to lessons
+//! - `rust-lab lint <path>`     → Flag beginner patterns in your .rs files, with lesson links
+//! - `rust-lab search <terms>`  → Find lessons and sections by title or Key Concepts text
+//! - `rust-lab glossary [term]` → Look up a term from the Key Concepts blocks (all terms without one)
+//! - `rust-lab cheatsheet [--format text|md]` → One-page reference of every lesson's concepts
+//! - `rust-lab review`          → Flashcards of glossary terms, spaced by how well you know them
+//! - `rust-lab exercises`       → List exercises (code for the learner to write)
+//! - `rust-lab check <exercise>` → Run an exercise's hidden test cases
+//! - `rust-lab roadmap [--write]` → Show roadmap items with lessons; `--write` ticks them
+//! - `rust-lab help`             → Show this usage text
+//!
+//! ============================================================================
+
+use std::fmt;
+use std::fs;
+use std::io::{self, IsTerminal, Read};
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+
+use crate::challenge;
+use crate::exercises::{self, Exercise, Outcome};
+use crate::explain;
+use crate::export::{self, Format};
+use crate::glossary;
+use crate::graph;
+use crate::highlight::{self, Colors};
+use crate::json;
+use crate::lessons::{self, Lesson, Phase, Registry};
+use crate::lint;
+use crate::menu::{self, Menu};
+use crate::output::{CaptureOutput, NullOutput, Output, SourceOutput, StdoutOutput};
+use crate::predict;
+use crate::progress::{self, Progress, ProgressError, ProgressStore};
+use crate::quiz;
+use crate::review::{self, Clock, DeckStore, SystemClock};
+use crate::roadmap::{self, Status};
+use crate::rustc::{self, RustcError};
+use crate::render::{self, MarkdownRenderer, TerminalRenderer};
+use crate::search::{self, Field};
+use crate::step::{self, Stepper};
+use crate::source;
+use crate::table::{Align, Style, Table};
+
+const USAGE: &str = "\
+Usage: rust-lab [COMMAND]
+
+Commands:
+  (none)                 Run every lesson in order
+  list                   List lessons grouped by phase
+  menu                   Browse the lessons full screen: arrow keys move,
+                         Enter opens a lesson, q goes back; ✓ marks completed
+                         lessons (a numbered prompt when not in a terminal)
+  run <lesson>           Run one lesson by id or number (e.g. `ownership`, `13`)
+  run <from>-<to>        Run an inclusive range of lessons (e.g. `6-9`, `6..=9`)
+  run <from>..<to>       Run an exclusive range of lessons (e.g. `6..10`)
+  run --phase <N>        Run every lesson in phase N
+                         Ranges and phases run in prerequisite order; a
+                         lesson whose prerequisites you have not completed
+                         starts with a warning naming them
+  export [--format md|html] [--out <dir>]
+                         Write one page per lesson plus an index
+                         (defaults: --format md --out export)
+  graph [--dot]          Show which lessons each lesson builds on; --dot prints
+                         Graphviz (rust-lab graph --dot | dot -Tsvg > graph.svg)
+  progress               Show completed lessons and quiz scores by phase
+  reset                  Forget all recorded progress and flashcard schedules
+  quiz <lesson> [--seed <n>]
+                         Answer the lesson's quiz questions (the same seed
+                         gives the same question order); scores are recorded
+  predict <lesson> [--section <n>]
+                         Show each section's code, type the output you
+                         expect, then compare it with the real output
+  challenge [--seed <n>]
+                         Will it compile? Decide for each borrowing snippet,
+                         then see what the local rustc actually says
+  explain [<file>]       Read rustc / cargo JSON diagnostics from a file (or
+                         stdin) and show which lesson explains each error,
+                         e.g. cargo build --message-format=json | rust-lab explain
+  lint <path>            Flag beginner patterns (&String parameters, .unwrap(),
+                         index loops, needless .clone(), ...) in a .rs file
+                         or every .rs file under a directory
+  search <terms>         Find lesson titles, sections and Key Concepts lines
+                         mentioning the terms (e.g. search iter_mut)
+  glossary [<term>]      Look up a term (e.g. glossary unwrap_or) collected
+                         from the lessons' Key Concepts; lists every term
+                         when none is given
+  cheatsheet [--format text|md]
+                         Print a one-page reference of every lesson's terms
+                         and key points (default: text)
+  review                 Review the glossary flashcards that are due: recall
+                         the meaning, then grade yourself 0–5; cards you know
+                         well come back less often
+  exercises              List exercises (function stubs for you to implement)
+  check <exercise>       Run the hidden test cases of an exercise
+  roadmap [--write] [--file <path>]
+                         Show which roadmap items have lessons and which are
+                         completed; --write ticks their checkboxes in the file
+                         (default file: learning_curve.md)
+  help                   Show this message
+
+Run options:
+  --quiet, -q            Run lessons without printing their output
+                         (quiet runs are not recorded as progress)
+  --show-source          Print the code of each lesson section before its
+                         output
+  --step                 Pause at every section: Enter or n goes on, b shows
+                         the previous section again, q stops; the next
+                         --step run of the same lessons resumes there";
+
+/// A parsed command line
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    RunAll,
+    List,
+    Menu,
+    Run(Selection, RunOptions),
+    Export { format: Format, out: PathBuf },
+    Graph { dot: bool },
+    Progress,
+    Reset,
+    Exercises,
+    Check(String),
+    Quiz { lesson: String, seed: Option<u64> },
+    Predict {
+        lesson: String,
+        section: Option<usize>,
+    },
+    Challenge { seed: Option<u64> },
+    /// `None` reads stdin
+    Explain(Option<PathBuf>),
+    Lint(PathBuf),
+    Search(String),
+    Glossary(Option<String>),
+    Cheatsheet { markdown: bool },
+    Review,
+    Roadmap { file: PathBuf, write: bool },
+    Help,
+}
+
+/// Flags accepted by `rust-lab run`
+#[derive(Debug, Default, PartialEq)]
+pub struct RunOptions {
+    pub quiet: bool,
+    pub show_source: bool,
+    pub step: bool,
+}
+
+/// Which lessons `rust-lab run` should execute
+#[derive(Debug, PartialEq)]
+pub enum Selection {
+    Lesson(String),
+    Range(RangeInclusive<u32>),
+    Phase(u32),
+}
+
+/// Everything that can go wrong while parsing or executing a command
+#[derive(Debug)]
+pub enum CliError {
+    UnknownCommand(String),
+    MissingArgument(&'static str),
+    UnexpectedArgument(String),
+    InvalidPhase(String),
+    EmptyRange(String),
+    UnknownLesson {
+        name: String,
+        suggestions: Vec<String>,
+    },
+    UnknownExercise {
+        name: String,
+        suggestions: Vec<String>,
+    },
+    NoQuestions(String),
+    InvalidSeed(String),
+    /// `count` is the number of sections, once the lesson is known
+    InvalidSection {
+        section: String,
+        count: Option<usize>,
+    },
+    ChecksFailed {
+        failed: usize,
+        total: usize,
+    },
+    InvalidFormat {
+        format: String,
+        expected: &'static str,
+    },
+    Io {
+        path: PathBuf,
+        error: io::Error,
+    },
+    Progress(ProgressError),
+    Rustc(RustcError),
+    NoDiagnostics(String),
+    LintFindings {
+        findings: usize,
+        files: usize,
+    },
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::UnknownCommand(cmd) => {
+                write!(f, "unknown command '{}' (try `rust-lab help`)", cmd)
+            }
+            CliError::MissingArgument(what) => write!(f, "missing argument: {}", what),
+            CliError::UnexpectedArgument(arg) => write!(f, "unexpected argument '{}'", arg),
+            CliError::InvalidPhase(phase) => write!(
+                f,
+                "invalid phase '{}' (phases are numbered 1 to {})",
+                phase,
+                Phase::ALL.len()
+            ),
+            CliError::EmptyRange(range) => write!(f, "no lessons in range '{}'", range),
+            CliError::InvalidFormat { format, expected } => {
+                write!(f, "unknown format '{}' (expected {})", format, expected)
+            }
+            CliError::Io { path, error } => write!(f, "{}: {}", path.display(), error),
+            CliError::UnknownExercise { name, suggestions } => {
+                write!(f, "unknown exercise '{}'", name)?;
+                if !suggestions.is_empty() {
+                    write!(f, "\n  did you mean: {}?", suggestions.join(", "))?;
+                }
+                write!(f, "\n  run `rust-lab exercises` to see every exercise")
+            }
+            CliError::NoQuestions(lesson) => {
+                write!(f, "lesson '{}' has no quiz questions yet", lesson)
+            }
+            CliError::InvalidSeed(seed) => {
+                write!(f, "invalid seed '{}' (expected a whole number)", seed)
+            }
+            CliError::InvalidSection { section, count } => match count {
+                Some(count) => write!(
+                    f,
+                    "invalid section '{}' (this lesson has sections 1 to {})",
+                    section, count
+                ),
+                None => write!(f, "invalid section '{}' (expected a number from 1)", section),
+            },
+            CliError::LintFindings { findings, files } => {
+                write!(f, "{} finding(s) in {} file(s)", findings, files)
+            }
+            CliError::ChecksFailed { failed, total } => {
+                write!(f, "{} of {} cases failed", failed, total)
+            }
+            CliError::Progress(error) => write!(f, "progress: {}", error),
+            CliError::Rustc(error) => write!(f, "rustc: {}", error),
+            CliError::NoDiagnostics(source) => write!(
+                f,
+                "no JSON diagnostics in {} (use `rustc --error-format=json` or \
+                 `cargo build --message-format=json`)",
+                source
+            ),
+            CliError::UnknownLesson { name, suggestions } => {
+                write!(f, "unknown lesson '{}'", name)?;
+                if !suggestions.is_empty() {
+                    write!(f, "\n  did you mean: {}?", suggestions.join(", "))?;
+                }
+                write!(f, "\n  run `rust-lab list` to see every lesson")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+impl From<ProgressError> for CliError {
+    fn from(error: ProgressError) -> Self {
+        CliError::Progress(error)
+    }
+}
+
+impl From<RustcError> for CliError {
+    fn from(error: RustcError) -> Self {
+        CliError::Rustc(error)
+    }
+}
+
+/// Parses the arguments that follow the program name
+pub fn parse<I>(args: I) -> Result<Command, CliError>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter();
+    let command = match args.next() {
+        None => return Ok(Command::RunAll),
+        Some(command) => command,
+    };
+
+    let parsed = match command.as_str() {
+        "list" | "ls" => Command::List,
+        "menu" => Command::Menu,
+        "help" | "--help" | "-h" => Command::Help,
+        "progress" => Command::Progress,
+        "reset" => Command::Reset,
+        "exercises" => Command::Exercises,
+        "graph" => {
+            let dot = match args.next() {
+                None => false,
+                Some(arg) if arg == "--dot" => true,
+                Some(arg) => return Err(CliError::UnexpectedArgument(arg)),
+            };
+            Command::Graph { dot }
+        }
+        "lint" => Command::Lint(
+            args.next()
+                .ok_or(CliError::MissingArgument("file or directory to lint"))?
+                .into(),
+        ),
+        "check" => Command::Check(
+            args.next()
+                .ok_or(CliError::MissingArgument("exercise name"))?,
+        ),
+        "run" => return parse_run(args),
+        "export" => return parse_export(args),
+        "roadmap" => return parse_roadmap(args),
+        "quiz" => return parse_quiz(args),
+        "predict" => return parse_predict(args),
+        "challenge" => return parse_challenge(args),
+        "search" => {
+            let terms: Vec<String> = args.by_ref().collect();
+            if terms.is_empty() {
+                return Err(CliError::MissingArgument("search terms"));
+            }
+            Command::Search(terms.join(" "))
+        }
+        "glossary" => {
+            let term: Vec<String> = args.by_ref().collect();
+            Command::Glossary((!term.is_empty()).then(|| term.join(" ")))
+        }
+        "cheatsheet" => return parse_cheatsheet(args),
+        "review" => Command::Review,
+        "explain" => Command::Explain(args.next().filter(|a| a != "-").map(PathBuf::from)),
+        _ => return Err(CliError::UnknownCommand(command)),
+    };
+
+    match args.next() {
+        Some(extra) => Err(CliError::UnexpectedArgument(extra)),
+        None => Ok(parsed),
+    }
+}
+
+/// Parses `run <selection> [options]`; flags may come before or after the selection
+fn parse_run(mut args: impl Iterator<Item = String>) -> Result<Command, CliError> {
+    let mut selection = None;
+    let mut options = RunOptions::default();
+
+    while let Some(arg) = args.next() {
+        let arg_text = arg.clone();
+        let parsed = match arg.as_str() {
+            "--quiet" | "-q" => {
+                options.quiet = true;
+                continue;
+            }
+            "--show-source" => {
+                options.show_source = true;
+                continue;
+            }
+            "--step" => {
+                options.step = true;
+                continue;
+            }
+            "--phase" | "-p" => {
+                let phase = args
+                    .next()
+                    .ok_or(CliError::MissingArgument("phase number"))?;
+                match phase.parse::<u32>() {
+                    Ok(n) if Phase::from_number(n).is_some() => Selection::Phase(n),
+                    _ => return Err(CliError::InvalidPhase(phase)),
+                }
+            }
+            _ if arg.starts_with('-') && parse_range(&arg).is_none() => {
+                return Err(CliError::UnexpectedArgument(arg));
+            }
+            _ => match parse_range(&arg) {
+                Some(range) if range.is_empty() => return Err(CliError::EmptyRange(arg)),
+                Some(range) => Selection::Range(range),
+                None => Selection::Lesson(arg),
+            },
+        };
+        // Only one lesson, range or phase may be given
+        if selection.replace(parsed).is_some() {
+            return Err(CliError::UnexpectedArgument(arg_text));
+        }
+    }
+
+    let selection = selection.ok_or(CliError::MissingArgument(
+        "lesson id, number, range or --phase <N>",
+    ))?;
+    // Nothing to pause at when nothing is printed
+    if options.quiet && options.step {
+        return Err(CliError::UnexpectedArgument("--step".to_string()));
+    }
+    Ok(Command::Run(selection, options))
+}
+
+/// Parses `export [--format md|html] [--out <dir>]`
+fn parse_export(mut args: impl Iterator<Item = String>) -> Result<Command, CliError> {
+    let mut format = Format::Markdown;
+    let mut out = PathBuf::from("export");
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" | "-f" => {
+                let name = args
+                    .next()
+                    .ok_or(CliError::MissingArgument("format (md or html)"))?;
+                format = Format::parse(&name).ok_or(CliError::InvalidFormat {
+                    format: name,
+                    expected: "md or html",
+                })?;
+            }
+            "--out" | "-o" => {
+                out = args
+                    .next()
+                    .ok_or(CliError::MissingArgument("output directory"))?
+                    .into();
+            }
+            _ => return Err(CliError::UnexpectedArgument(arg)),
+        }
+    }
+    Ok(Command::Export { format, out })
+}
+
+/// Parses `cheatsheet [--format text|md]`
+fn parse_cheatsheet(mut args: impl Iterator<Item = String>) -> Result<Command, CliError> {
+    let mut markdown = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" | "-f" => {
+                let name = args
+                    .next()
+                    .ok_or(CliError::MissingArgument("format (text or md)"))?;
+                markdown = match name.to_lowercase().as_str() {
+                    "text" | "txt" => false,
+                    "md" | "markdown" => true,
+                    _ => {
+                        return Err(CliError::InvalidFormat {
+                            format: name,
+                            expected: "text or md",
+                        });
+                    }
+                };
+            }
+            _ => return Err(CliError::UnexpectedArgument(arg)),
+        }
+    }
+    Ok(Command::Cheatsheet { markdown })
+}
+
+/// Parses `quiz <lesson> [--seed <n>]`
+fn parse_quiz(mut args: impl Iterator<Item = String>) -> Result<Command, CliError> {
+    let mut lesson = None;
+    let mut seed = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" | "-s" => {
+                let value = args.next().ok_or(CliError::MissingArgument("seed"))?;
+                seed = Some(value.parse().map_err(|_| CliError::InvalidSeed(value))?);
+            }
+            _ if arg.starts_with('-') || lesson.is_some() => {
+                return Err(CliError::UnexpectedArgument(arg));
+            }
+            _ => lesson = Some(arg),
+        }
+    }
+
+    let lesson = lesson.ok_or(CliError::MissingArgument("lesson id or number"))?;
+    Ok(Command::Quiz { lesson, seed })
+}
+
+/// Parses `challenge [--seed <n>]`
+fn parse_challenge(mut args: impl Iterator<Item = String>) -> Result<Command, CliError> {
+    let mut seed = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" | "-s" => {
+                let value = args.next().ok_or(CliError::MissingArgument("seed"))?;
+                seed = Some(value.parse().map_err(|_| CliError::InvalidSeed(value))?);
+            }
+            _ => return Err(CliError::UnexpectedArgument(arg)),
+        }
+    }
+    Ok(Command::Challenge { seed })
+}
+
+/// Parses `predict <lesson> [--section <n>]`; the section is checked against
+/// the lesson later
+fn parse_predict(mut args: impl Iterator<Item = String>) -> Result<Command, CliError> {
+    let mut lesson = None;
+    let mut section = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--section" | "-s" => {
+                let value = args
+                    .next()
+                    .ok_or(CliError::MissingArgument("section number"))?;
+                section = Some(value);
+            }
+            _ if arg.starts_with('-') || lesson.is_some() => {
+                return Err(CliError::UnexpectedArgument(arg));
+            }
+            _ => lesson = Some(arg),
+        }
+    }
+
+    let lesson = lesson.ok_or(CliError::MissingArgument("lesson id or number"))?;
+    let section = match section {
+        None => None,
+        Some(value) => match value.parse::<usize>() {
+            Ok(n) if n > 0 => Some(n),
+            _ => return Err(CliError::InvalidSection {
+                section: value,
+                count: None,
+            }),
+        },
+    };
+    Ok(Command::Predict { lesson, section })
+}
+
+/// Parses `roadmap [--write] [--file <path>]`
+fn parse_roadmap(mut args: impl Iterator<Item = String>) -> Result<Command, CliError> {
+    let mut file = PathBuf::from("learning_curve.md");
+    let mut write = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--write" | "-w" => write = true,
+            "--file" | "-f" => {
+                file = args
+                    .next()
+                    .ok_or(CliError::MissingArgument("roadmap file"))?
+                    .into();
+            }
+            _ => return Err(CliError::UnexpectedArgument(arg)),
+        }
+    }
+    Ok(Command::Roadmap { file, write })
+}
+
+/// Parses `a-b` / `a..=b` (inclusive) and `a..b` (exclusive, like Rust ranges)
+fn parse_range(arg: &str) -> Option<RangeInclusive<u32>> {
+    let number = |s: &str| s.trim().parse::<u32>().ok();
+
+    if let Some((start, end)) = arg.split_once("..=") {
+        return Some(number(start)?..=number(end)?);
+    }
+    if let Some((start, end)) = arg.split_once("..") {
+        // `5..5` is empty, just like in Rust, and so is `0..0`
+        let (start, end) = (number(start)?, number(end)?);
+        return Some(match end.checked_sub(1) {
+            Some(last) => start..=last,
+            None => RangeInclusive::new(1, 0),
+        });
+    }
+    if let Some((start, end)) = arg.split_once('-') {
+        return Some(number(start)?..=number(end)?);
+    }
+    None
+}
+
+/// Executes a parsed command against the lesson registry
+pub fn execute(command: Command, registry: &Registry) -> Result<(), CliError> {
+    match command {
+        Command::RunAll => {
+            lessons::run_all_lessons(registry, &mut StdoutOutput::default());
+            record_runs(&registry.lessons().collect::<Vec<_>>());
+        }
+        Command::Help => println!("{}", USAGE),
+        Command::List => print_lesson_list(registry),
+        Command::Menu => browse(registry)?,
+        Command::Export { format, out } => {
+            let written = export::export(registry, format, &out)
+                .map_err(|error| CliError::Io { path: out.clone(), error })?;
+            println!("Wrote {} pages to {}", written.len(), out.display());
+        }
+        Command::Run(selection, options) if options.step => {
+            let selected = select(registry, &selection)?;
+            step_lessons(registry, &selected, options.show_source)?;
+        }
+        Command::Run(selection, options) => {
+            let out: &mut dyn Output = if options.quiet {
+                &mut NullOutput
+            } else {
+                &mut StdoutOutput::default()
+            };
+            let selected = select(registry, &selection)?;
+            if !options.quiet {
+                warn_missing_prerequisites(registry, &selected);
+            }
+            for &lesson in &selected {
+                let meta = lesson.meta();
+                match lessons::module_source(meta.module) {
+                    Some(source) if options.show_source => {
+                        let mut with_source = SourceOutput::new(out, source, meta.entry);
+                        lessons::run_lesson(lesson, &mut with_source);
+                    }
+                    _ => lessons::run_lesson(lesson, out),
+                }
+            }
+            if !options.quiet {
+                record_runs(&selected);
+            }
+        }
+        Command::Graph { dot } => {
+            let graph = if dot {
+                graph::to_dot(registry)
+            } else {
+                graph::to_text(registry)
+            };
+            print!("{}", graph);
+        }
+        Command::Progress => print_progress(registry)?,
+        Command::Quiz { lesson, seed } => {
+            let lesson = select(registry, &Selection::Lesson(lesson))?[0];
+            take_quiz(lesson, seed.unwrap_or_else(progress::now))?;
+        }
+        Command::Predict { lesson, section } => {
+            let lesson = select(registry, &Selection::Lesson(lesson))?[0];
+            predict_output(lesson, section)?;
+        }
+        Command::Challenge { seed } => {
+            take_challenge(registry, seed.unwrap_or_else(progress::now))?
+        }
+        Command::Explain(file) => explain_errors(registry, file.as_deref())?,
+        Command::Lint(path) => lint_files(registry, &path)?,
+        Command::Search(query) => print_search(registry, &query),
+        Command::Glossary(term) => print_glossary(registry, term.as_deref()),
+        Command::Cheatsheet { markdown } => {
+            let blocks = glossary::cheatsheet(registry);
+            let text = if markdown {
+                render::render_all(&mut MarkdownRenderer::default(), &blocks)
+            } else {
+                let mut renderer =
+                    TerminalRenderer::new(Style::for_terminal(), Colors::for_stdout());
+                render::render_all(&mut renderer, &blocks)
+            };
+            print!("{}", text);
+        }
+        Command::Review => review_cards(registry)?,
+        Command::Exercises => print_exercise_list(registry),
+        Command::Check(name) => {
+            let exercise = exercises::find(&name).ok_or_else(|| CliError::UnknownExercise {
+                suggestions: suggest(
+                    &name,
+                    exercises::all()
+                        .into_iter()
+                        .map(|e| (e.meta().id, e.meta().title)),
+                ),
+                name,
+            })?;
+            check_exercise(exercise, registry)?;
+        }
+        Command::Roadmap { file, write } => show_roadmap(registry, &file, write)?,
+        Command::Reset => {
+            let progress = ProgressStore::locate()?;
+            let cards = DeckStore::locate()?;
+            let mut removed = Vec::new();
+            for (path, existed) in [
+                (progress.path(), progress.reset()?),
+                (cards.path(), cards.reset()?),
+            ] {
+                if existed {
+                    removed.push(path.display().to_string());
+                }
+            }
+            if removed.is_empty() {
+                println!("No progress recorded yet");
+            } else {
+                println!("Progress cleared ({} removed)", removed.join(", "));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Resolves a selection to the lessons it names; ranges and phases come in
+/// prerequisite order
+fn select<'r>(
+    registry: &'r Registry,
+    selection: &Selection,
+) -> Result<Vec<&'r dyn Lesson>, CliError> {
+    match selection {
+        Selection::Lesson(name) => match registry.find(name) {
+            Some(lesson) => Ok(vec![lesson]),
+            None => Err(CliError::UnknownLesson {
+                name: name.clone(),
+                suggestions: suggest(name, registry.lessons().map(|l| (l.id(), l.title()))),
+            }),
+        },
+        Selection::Range(range) => {
+            let lessons = in_prerequisite_order(registry, registry.in_range(range.clone()));
+            if lessons.is_empty() {
+                return Err(CliError::EmptyRange(format!(
+                    "{}-{}",
+                    range.start(),
+                    range.end()
+                )));
+            }
+            Ok(lessons)
+        }
+        Selection::Phase(number) => {
+            let phase = Phase::from_number(*number)
+                .ok_or_else(|| CliError::InvalidPhase(number.to_string()))?;
+            Ok(in_prerequisite_order(registry, registry.in_phase(phase)))
+        }
+    }
+}
+
+/// `lessons` sorted so that each comes after the lessons it requires
+fn in_prerequisite_order<'r>(
+    registry: &'r Registry,
+    lessons: impl Iterator<Item = &'r dyn Lesson>,
+) -> Vec<&'r dyn Lesson> {
+    let order = lessons::prerequisite_order(registry);
+    let mut lessons: Vec<&dyn Lesson> = lessons.collect();
+    lessons.sort_by_key(|lesson| order.iter().position(|o| o.id() == lesson.id()));
+    lessons
+}
+
+/// Marks lessons as run; a progress problem is a warning, never a failed run
+fn record_runs(lessons: &[&dyn Lesson]) {
+    let now = progress::now();
+    let result = ProgressStore::locate().and_then(|store| {
+        store.update(|progress| {
+            for lesson in lessons {
+                progress.record_run(lesson.id(), now);
+            }
+        })
+    });
+    if let Err(err) = result {
+        eprintln!("warning: progress not saved: {}", err);
+    }
+}
+
+/// Pages through the selected lessons (`run --step`), resuming where the
+/// last step-through of one of them stopped and saving where this one stops
+fn step_lessons(
+    registry: &Registry,
+    selected: &[&dyn Lesson],
+    show_source: bool,
+) -> Result<(), CliError> {
+    warn_missing_prerequisites(registry, selected);
+    let steppers: Vec<Stepper> = selected
+        .iter()
+        .map(|&lesson| {
+            let meta = lesson.meta();
+            let mut capture = CaptureOutput::default();
+            match lessons::module_source(meta.module) {
+                Some(source) if show_source => {
+                    let mut with_source = SourceOutput::new(&mut capture, source, meta.entry);
+                    lessons::run_lesson(lesson, &mut with_source);
+                }
+                _ => lessons::run_lesson(lesson, &mut capture),
+            }
+            Stepper::new(lesson, capture.into_blocks())
+        })
+        .collect();
+
+    // An unreadable progress file starts from the top; saving reports it below
+    let store = ProgressStore::locate();
+    let saved = store.as_ref().ok().and_then(|store| store.load().ok());
+    let start = saved
+        .and_then(|progress| {
+            steppers.iter().enumerate().find_map(|(i, stepper)| {
+                let page = progress.lesson(stepper.lesson.id())?.viewed? as usize;
+                Some((i, page.min(stepper.pages.len() - 1)))
+            })
+        })
+        .unwrap_or((0, 0));
+    if start != (0, 0) {
+        let (i, page) = start;
+        println!(
+            "Resuming lesson {} at {} (b goes back)\n",
+            steppers[i].lesson.number(),
+            if page == 0 {
+                "the introduction".to_string()
+            } else {
+                format!("section {}", page)
+            }
+        );
+    }
+
+    let stepped = step::run(
+        &steppers,
+        start,
+        Style::for_terminal(),
+        Colors::for_stdout(),
+        &mut io::stdin().lock(),
+        &mut io::stdout(),
+    )
+    .map_err(|error| CliError::Io {
+        path: PathBuf::from("<terminal>"),
+        error,
+    })?;
+
+    let now = progress::now();
+    let result = store.and_then(|store| {
+        store.update(|progress| {
+            for stepper in &steppers {
+                progress.set_viewed(stepper.lesson.id(), None);
+            }
+            for &i in &stepped.finished {
+                progress.record_run(steppers[i].lesson.id(), now);
+            }
+            if let Some((i, page)) = stepped.stopped_at {
+                progress.set_viewed(steppers[i].lesson.id(), Some(page as u32));
+            }
+        })
+    });
+    if let Err(err) = result {
+        eprintln!("warning: progress not saved: {}", err);
+    }
+    Ok(())
+}
+
+/// Runs the lesson menu; lessons read to the end count as run
+fn browse(registry: &Registry) -> Result<(), CliError> {
+    let progress = match ProgressStore::locate().and_then(|store| store.load()) {
+        Ok(progress) => progress,
+        Err(err) => {
+            eprintln!("warning: progress not loaded: {}", err);
+            Progress::default()
+        }
+    };
+    let completed = |lesson: &dyn Lesson| {
+        progress
+            .lesson(lesson.id())
+            .is_some_and(|p| p.completed.is_some())
+    };
+    let mut menu = Menu::new(registry, completed, Colors::for_stdout());
+    menu::run(&mut menu).map_err(|error| CliError::Io {
+        path: PathBuf::from("<terminal>"),
+        error,
+    })?;
+
+    let read = menu.read();
+    if !read.is_empty() {
+        record_runs(&read);
+    }
+    Ok(())
+}
+
+/// Warns about prerequisites of the selected lessons that are neither
+/// completed nor run earlier in the same selection
+fn warn_missing_prerequisites(registry: &Registry, selected: &[&dyn Lesson]) {
+    // Without readable progress there is nothing to compare against;
+    // `record_runs` reports the problem after the run
+    let Ok(progress) = ProgressStore::locate().and_then(|store| store.load()) else {
+        return;
+    };
+    for (i, &lesson) in selected.iter().enumerate() {
+        let missing = graph::missing(registry, lesson, |required| {
+            selected[..i].iter().any(|l| l.id() == required.id())
+                || progress
+                    .lesson(required.id())
+                    .is_some_and(|p| p.completed.is_some())
+        });
+        if missing.is_empty() {
+            continue;
+        }
+        eprintln!(
+            "warning: lesson {} ({}) builds on lessons you have not completed:",
+            lesson.number(),
+            lesson.title()
+        );
+        for required in missing {
+            eprintln!(
+                "  {:>3}  {} (rust-lab run {})",
+                required.number(),
+                required.title(),
+                required.id()
+            );
+        }
+    }
+}
+
+/// One table per phase: completion, run count, last run and best quiz score
+fn print_progress(registry: &Registry) -> Result<(), CliError> {
+    let store = ProgressStore::locate()?;
+    let progress = store.load()?;
+
+    let completed = |lesson: &dyn Lesson| {
+        progress
+            .lesson(lesson.id())
+            .is_some_and(|p| p.completed.is_some())
+    };
+    let total = registry.lessons().count();
+    let done = registry.lessons().filter(|&l| completed(l)).count();
+    println!("Progress: {}/{} lessons completed", done, total);
+
+    for phase in Phase::ALL {
+        let lessons: Vec<&dyn Lesson> = registry.in_phase(phase).collect();
+        let done = lessons.iter().filter(|&&l| completed(l)).count();
+        println!(
+            "\nPhase {} — {} ({}/{})",
+            phase.number(),
+            phase.title(),
+            done,
+            lessons.len()
+        );
+
+        let mut table = Table::new(&["#", "Lesson", "Done", "Runs", "Last run", "Quiz"])
+            .align(2, Align::Center);
+        for lesson in lessons {
+            let entry = progress.lesson(lesson.id()).cloned().unwrap_or_default();
+            let number = lesson.number().to_string();
+            let runs = entry.runs.to_string();
+            let last_run = entry.last_run.map(progress::format_date).unwrap_or_default();
+            let quiz = entry
+                .quiz
+                .map(|score| format!("{}/{}", score.correct, score.total))
+                .unwrap_or_default();
+            let mark = if entry.completed.is_some() { "✅" } else { "" };
+            table = table.row(&[&number, lesson.title(), mark, &runs, &last_run, &quiz]);
+        }
+        print!("{}", table.render(Style::for_terminal()));
+    }
+    Ok(())
+}
+
+/// Prints every roadmap section with the status of its items; with `write`,
+/// also ticks the checkboxes of completed items in the file
+fn show_roadmap(registry: &Registry, file: &Path, write: bool) -> Result<(), CliError> {
+    let io_error = |error| CliError::Io {
+        path: file.to_path_buf(),
+        error,
+    };
+    let text = fs::read_to_string(file).map_err(io_error)?;
+    let progress = ProgressStore::locate()?.load()?;
+
+    for section in roadmap::parse(&text) {
+        if section.items.is_empty() {
+            continue;
+        }
+        let statuses: Vec<Status> = section
+            .items
+            .iter()
+            .map(|item| roadmap::status(item, registry, &progress))
+            .collect();
+        let covered = statuses.iter().filter(|&&s| s != Status::NoLesson).count();
+        let completed = statuses.iter().filter(|&&s| s == Status::Completed).count();
+        println!(
+            "\n{} ({} with lessons, {} completed)",
+            section.title,
+            covered,
+            completed
+        );
+
+        let mut table = Table::new(&["", "Item", "Lessons"]).align(0, Align::Center);
+        for (item, status) in section.items.iter().zip(statuses) {
+            let mark = match status {
+                Status::NoLesson => "",
+                Status::Available => "📘",
+                Status::Completed => "✅",
+            };
+            let lessons = roadmap::lessons_for(&item.text).join(", ");
+            table = table.row(&[mark, &item.text, &lessons]);
+        }
+        print!("{}", table.render(Style::for_terminal()));
+    }
+    println!("\n✅ completed   📘 has lessons to run   (blank) no lesson yet");
+
+    if write {
+        let (synced, changed) = roadmap::sync_checkboxes(&text, registry, &progress);
+        if changed > 0 {
+            fs::write(file, synced).map_err(io_error)?;
+        }
+        println!("Ticked {} checkbox(es) in {}", changed, file.display());
+    }
+    Ok(())
+}
+
+/// Asks the questions on stdin / stdout and records a finished quiz
+fn take_quiz(lesson: &dyn Lesson, seed: u64) -> Result<(), CliError> {
+    if lesson.questions().is_empty() {
+        return Err(CliError::NoQuestions(lesson.id().to_string()));
+    }
+    println!(
+        "Quiz: lesson {} — {} ({} questions, seed {})",
+        lesson.number(),
+        lesson.title(),
+        lesson.questions().len(),
+        seed
+    );
+
+    let score = quiz::run(lesson, seed, &mut io::stdin().lock(), &mut io::stdout())
+        .map_err(|error| CliError::Io {
+            path: PathBuf::from("<terminal>"),
+            error,
+        })?;
+
+    if let Some(score) = score {
+        let saved = ProgressStore::locate()
+            .and_then(|store| store.update(|progress| progress.record_quiz(lesson.id(), score)));
+        if let Err(err) = saved {
+            eprintln!("warning: quiz score not saved: {}", err);
+        }
+    }
+    Ok(())
+}
+
+/// Reviews the due flashcards on stdin / stdout and saves their new schedules
+fn review_cards(registry: &Registry) -> Result<(), CliError> {
+    let store = DeckStore::locate()?;
+    let mut deck = store.load()?;
+    let terms = glossary::terms(registry);
+    let clock = SystemClock;
+    let cards = deck.due(&terms, clock.now(), review::NEW_PER_SESSION);
+    if cards.is_empty() {
+        match deck.next_due() {
+            Some(time) => println!(
+                "No cards due. The next one is due on {}.",
+                progress::format_date(time)
+            ),
+            None => println!("No flashcards found"),
+        }
+        return Ok(());
+    }
+    println!(
+        "Review: {} card{} ({} glossary terms in all, q to stop)",
+        cards.len(),
+        if cards.len() == 1 { "" } else { "s" },
+        terms.len()
+    );
+
+    let summary = review::run(
+        registry,
+        &cards,
+        &mut deck,
+        &clock,
+        &mut io::stdin().lock(),
+        &mut io::stdout(),
+    )
+    .map_err(|error| CliError::Io {
+        path: PathBuf::from("<terminal>"),
+        error,
+    })?;
+    if summary.reviewed > 0 {
+        store.save(&deck)?;
+    }
+    Ok(())
+}
+
+/// Runs predict mode on stdin / stdout
+fn predict_output(lesson: &dyn Lesson, section: Option<usize>) -> Result<(), CliError> {
+    let count = predict::segments(lesson).len();
+    if let Some(n) = section.filter(|&n| n > count) {
+        return Err(CliError::InvalidSection {
+            section: n.to_string(),
+            count: Some(count),
+        });
+    }
+    println!(
+        "Predict the output: lesson {} — {} ({} section{})",
+        lesson.number(),
+        lesson.title(),
+        count,
+        if count == 1 { "" } else { "s" }
+    );
+
+    let colors = Colors::for_stdout();
+    predict::run(
+        lesson,
+        section,
+        colors,
+        &mut io::stdin().lock(),
+        &mut io::stdout(),
+    )
+    .map_err(|error| CliError::Io {
+        path: PathBuf::from("<terminal>"),
+        error,
+    })?;
+    Ok(())
+}
+
+/// Runs the "will it compile?" challenge on stdin / stdout
+fn take_challenge(registry: &Registry, seed: u64) -> Result<(), CliError> {
+    // Fail before the first question rather than after the first answer
+    let version = rustc::version()?;
+    println!(
+        "Will it compile? {} snippets, checked with {} (seed {})",
+        challenge::CHALLENGES.len(),
+        version,
+        seed
+    );
+
+    let colors = Colors::for_stdout();
+    challenge::run(
+        registry,
+        seed,
+        colors,
+        &mut io::stdin().lock(),
+        &mut io::stdout(),
+    )
+    .map_err(|error| CliError::Io {
+        path: PathBuf::from("<terminal>"),
+        error,
+    })?;
+    Ok(())
+}
+
+/// Reads diagnostics from `file` or stdin and links each error to a lesson
+fn explain_errors(registry: &Registry, file: Option<&Path>) -> Result<(), CliError> {
+    let (text, source) = match file {
+        Some(path) => {
+            let text = fs::read_to_string(path).map_err(|error| CliError::Io {
+                path: path.to_path_buf(),
+                error,
+            })?;
+            (text, path.display().to_string())
+        }
+        None => {
+            if io::stdin().is_terminal() {
+                return Err(CliError::MissingArgument(
+                    "file with rustc JSON output (or pipe it to stdin)",
+                ));
+            }
+            let mut text = String::new();
+            io::stdin()
+                .read_to_string(&mut text)
+                .map_err(|error| CliError::Io {
+                    path: PathBuf::from("<stdin>"),
+                    error,
+                })?;
+            (text, "stdin".to_string())
+        }
+    };
+
+    let diagnostics = rustc::parse_diagnostics(&text);
+    // cargo prints only artifact records for a clean build, so look for any
+    // JSON at all before complaining
+    if diagnostics.is_empty() && !text.lines().any(|l| json::parse(l.trim()).is_ok()) {
+        return Err(CliError::NoDiagnostics(source));
+    }
+    explain::report(registry, &diagnostics, &mut io::stdout()).map_err(|error| CliError::Io {
+        path: PathBuf::from("<terminal>"),
+        error,
+    })
+}
+
+/// One term's entries, or every term as a table when `term` is `None`
+fn print_glossary(registry: &Registry, term: Option<&str>) {
+    let terms = glossary::terms(registry);
+    let lesson_label = |id: &str| {
+        registry
+            .find(id)
+            .map_or(id.to_string(), |l| format!("{} {}", l.number(), l.id()))
+    };
+
+    let Some(query) = term else {
+        let mut table = Table::new(&["Term", "Meaning", "Lesson"]);
+        for entry in glossary::alphabetical(&terms) {
+            table = table.row(&[&entry.term, &entry.definition, &lesson_label(entry.lesson)]);
+        }
+        println!("Glossary: {} terms (rust-lab glossary <term> to look one up)", terms.len());
+        print!("{}", table.render(Style::for_terminal()));
+        return;
+    };
+
+    let found = glossary::lookup(&terms, query);
+    if found.is_empty() {
+        println!(
+            "No glossary entry for \"{}\". Try `rust-lab search {}`.",
+            query, query
+        );
+        return;
+    }
+    for entry in found {
+        println!("\n{}", entry.term);
+        println!("  {}", entry.definition);
+        if let Some(lesson) = registry.find(entry.lesson) {
+            println!(
+                "  📖 Lesson {}: {} ({}) → rust-lab run {}",
+                lesson.number(),
+                lesson.title(),
+                entry.group,
+                lesson.id()
+            );
+        }
+    }
+}
+
+/// Most search hits printed
+const MAX_HITS: usize = 10;
+
+/// The best matches for `query`, each with a snippet and the command to run
+fn print_search(registry: &Registry, query: &str) {
+    let index = search::Index::build(registry);
+    let hits = index.search(query);
+    if hits.is_empty() {
+        println!("No matches for \"{}\".", query);
+        return;
+    }
+
+    let colors = Colors::for_stdout();
+    let more = if hits.len() > MAX_HITS {
+        format!(", best {} shown", MAX_HITS)
+    } else {
+        String::new()
+    };
+    println!("{} match(es) for \"{}\"{}:", hits.len(), query, more);
+    for entry in hits.iter().take(MAX_HITS) {
+        let Some(lesson) = registry.find(entry.lesson) else {
+            continue;
+        };
+        let place = match (entry.field, &entry.section) {
+            (Field::Section, Some(section)) => format!("section \"{}\"", section),
+            (Field::Concepts, _) => "Key Concepts".to_string(),
+            _ => "title".to_string(),
+        };
+        println!(
+            "\n  📘 Lesson {}: {} — {}",
+            lesson.number(),
+            lesson.title(),
+            place
+        );
+        if entry.field != Field::Title {
+            println!("     {}", search::snippet(entry, query, colors));
+        }
+        println!("     → rust-lab run {}", lesson.id());
+    }
+}
+
+/// Lints every `.rs` file under `path`; any finding makes the command fail
+fn lint_files(registry: &Registry, path: &Path) -> Result<(), CliError> {
+    let io_error = |path: &Path| {
+        let path = path.to_path_buf();
+        move |error| CliError::Io { path, error }
+    };
+    let files = lint::rust_files(path).map_err(io_error(path))?;
+    let colors = Colors::for_stdout();
+
+    let mut findings = 0;
+    let mut flagged_files = 0;
+    for file in &files {
+        let source = fs::read_to_string(file).map_err(io_error(file))?;
+        let found = lint::lint(&source);
+        if !found.is_empty() {
+            flagged_files += 1;
+        }
+        for finding in &found {
+            let rule = finding.rule;
+            println!(
+                "{}:{}:{}: {} [{}]",
+                file.display(),
+                finding.line,
+                finding.column,
+                rule.title,
+                rule.id
+            );
+            if let Some(line) = source.lines().nth(finding.line - 1) {
+                print!("{}", highlight::indented(line.trim(), colors));
+            }
+            println!("    💡 {}", rule.advice);
+            if let Some(lesson) = registry.find(rule.lesson) {
+                let section = rule
+                    .section
+                    .map_or(String::new(), |title| format!(", section \"{}\"", title));
+                println!(
+                    "    📖 Lesson {} ({}){} → rust-lab run {}",
+                    lesson.number(),
+                    lesson.title(),
+                    section,
+                    lesson.id()
+                );
+            }
+            println!();
+        }
+        findings += found.len();
+    }
+
+    if findings == 0 {
+        println!("No findings in {} file(s) ✨", files.len());
+        Ok(())
+    } else {
+        Err(CliError::LintFindings {
+            findings,
+            files: flagged_files,
+        })
+    }
+}
+
+fn print_exercise_list(registry: &Registry) {
+    let mut table = Table::new(&["Exercise", "Lesson", "File", "Task"]);
+    for exercise in exercises::all() {
+        let meta = exercise.meta();
+        let lesson = registry
+            .find(meta.lesson)
+            .map_or(String::new(), |l| format!("{}. {}", l.number(), l.id()));
+        let file = format!("src/exercises/{}.rs", meta.module);
+        table = table.row(&[meta.id, &lesson, &file, meta.title]);
+    }
+    print!("{}", table.render(Style::for_terminal()));
+    println!("\nImplement the stub, then run `rust-lab check <exercise>`.");
+}
+
+/// Runs the hidden cases and prints expected vs actual for every failure
+fn check_exercise(exercise: &dyn Exercise, registry: &Registry) -> Result<(), CliError> {
+    let meta = exercise.meta();
+    println!("Exercise {} — {}", meta.id, meta.title);
+    print!("Edit src/exercises/{}.rs → fn {}", meta.module, meta.function);
+    match registry.find(meta.lesson) {
+        Some(lesson) => println!(" (see lesson {}: {})\n", lesson.number(), lesson.title()),
+        None => println!("\n"),
+    }
+
+    let results = exercises::check(exercise);
+    for result in &results {
+        match &result.outcome {
+            Outcome::Passed => println!("  ✅ {} == {}", result.call, result.expected),
+            Outcome::Failed { actual } => {
+                println!("  ❌ {}", result.call);
+                println!("       expected: {}", result.expected);
+                println!("       actual:   {}", actual);
+            }
+            Outcome::Panicked(message) => {
+                println!("  💥 {}", result.call);
+                println!("       expected: {}", result.expected);
+                println!("       panicked: {}", message);
+            }
+        }
+    }
+
+    let failed = results
+        .iter()
+        .filter(|r| r.outcome != Outcome::Passed)
+        .count();
+    println!("\n{}/{} cases passed", results.len() - failed, results.len());
+
+    // Nothing implemented yet: repeat the task from the stub's doc comment
+    let untouched = results
+        .iter()
+        .all(|r| matches!(&r.outcome, Outcome::Panicked(m) if m.starts_with("not yet implemented")));
+    if untouched
+        && let Some(task) = exercises::module_source(meta.module)
+            .and_then(|code| source::doc_comment(code, meta.function))
+    {
+        println!("\nTask:\n{}", task);
+    }
+
+    if failed == 0 {
+        Ok(())
+    } else {
+        Err(CliError::ChecksFailed {
+            failed,
+            total: results.len(),
+        })
+    }
+}
+
+fn print_lesson_list(registry: &Registry) {
+    for phase in Phase::ALL {
+        println!("\nPhase {} — {}", phase.number(), phase.title());
+        for lesson in registry.in_phase(phase) {
+            println!(
+                "  {:>3}  {:<22} {}",
+                lesson.number(),
+                lesson.id(),
+                lesson.title()
+            );
+        }
+    }
+}
+
+/// ============================================================================
+/// NEAREST-MATCH SUGGESTIONS
+/// ============================================================================
+/// Ids (of lessons or exercises) within a small edit distance of the input,
+/// or ids / titles that contain the input, closest first.
+/// ============================================================================
+fn suggest<'a>(name: &str, candidates: impl Iterator<Item = (&'a str, &'a str)>) -> Vec<String> {
+    let name = name.to_lowercase();
+    let max_distance = (name.chars().count() / 3).max(2);
+
+    let mut candidates: Vec<(usize, &str)> = candidates
+        .filter_map(|(id, title)| {
+            let distance = edit_distance(&name, id);
+            let contains =
+                id.contains(name.as_str()) || title.to_lowercase().contains(name.as_str());
+            (distance <= max_distance || contains).then_some((distance, id))
+        })
+        .collect();
+
+    candidates.sort();
+    candidates
+        .into_iter()
+        .take(3)
+        .map(|(_, id)| id.to_string())
+        .collect()
+}
+
+/// Levenshtein distance between two strings (by chars)
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsed(args: &str) -> Result<Command, CliError> {
+        parse(args.split_whitespace().map(String::from))
+    }
+
+    fn run(selection: Selection, options: RunOptions) -> Command {
+        Command::Run(selection, options)
+    }
+
+    #[test]
+    fn ranges_are_inclusive_exclusive_or_dashed() {
+        assert_eq!(parse_range("6-9"), Some(6..=9));
+        assert_eq!(parse_range("6..=9"), Some(6..=9));
+        assert_eq!(parse_range("6..10"), Some(6..=9));
+        assert_eq!(parse_range(" 6 - 9 "), Some(6..=9));
+        assert_eq!(parse_range("ownership"), None);
+        assert_eq!(parse_range("6-x"), None);
+        assert_eq!(
+            parsed("run 6..10").unwrap(),
+            run(Selection::Range(6..=9), RunOptions::default())
+        );
+    }
+
+    #[test]
+    fn empty_and_reversed_ranges_are_rejected() {
+        for range in ["0..0", "5..5", "16-3", "9..=6"] {
+            assert!(
+                matches!(parsed(&format!("run {}", range)), Err(CliError::EmptyRange(r)) if r == range),
+                "{}",
+                range
+            );
+        }
+        // Not empty, but no lesson is numbered 0
+        let registry = lessons::registry();
+        assert!(matches!(
+            select(&registry, &Selection::Range(0..=0)),
+            Err(CliError::EmptyRange(_))
+        ));
+    }
+
+    #[test]
+    fn phases_must_exist() {
+        assert_eq!(
+            parsed("run --phase 1").unwrap(),
+            run(Selection::Phase(1), RunOptions::default())
+        );
+        assert_eq!(
+            parsed("run -p 5").unwrap(),
+            run(Selection::Phase(5), RunOptions::default())
+        );
+        for phase in ["0", "6", "two"] {
+            assert!(
+                matches!(parsed(&format!("run --phase {}", phase)), Err(CliError::InvalidPhase(p)) if p == phase),
+                "{}",
+                phase
+            );
+        }
+        assert!(matches!(
+            parsed("run --phase"),
+            Err(CliError::MissingArgument("phase number"))
+        ));
+    }
+
+    #[test]
+    fn run_flags_go_before_or_after_the_selection() {
+        let options = RunOptions {
+            quiet: true,
+            show_source: true,
+            step: false,
+        };
+        let lesson = || Selection::Lesson("ownership".to_string());
+        assert_eq!(
+            parsed("run --quiet ownership --show-source").unwrap(),
+            run(lesson(), options)
+        );
+        assert_eq!(
+            parsed("run ownership --step").unwrap(),
+            run(
+                lesson(),
+                RunOptions {
+                    step: true,
+                    ..RunOptions::default()
+                }
+            )
+        );
+        // Nothing to pause at when nothing is printed, in either order
+        for args in ["run --quiet ownership --step", "run --step -q ownership"] {
+            assert!(
+                matches!(parsed(args), Err(CliError::UnexpectedArgument(a)) if a == "--step"),
+                "{}",
+                args
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_flags_and_extra_arguments_are_rejected() {
+        assert!(matches!(
+            parsed("run --loud ownership"),
+            Err(CliError::UnexpectedArgument(a)) if a == "--loud"
+        ));
+        assert!(matches!(
+            parsed("run ownership borrowing"),
+            Err(CliError::UnexpectedArgument(a)) if a == "borrowing"
+        ));
+        assert!(matches!(
+            parsed("list --all"),
+            Err(CliError::UnexpectedArgument(a)) if a == "--all"
+        ));
+        assert!(matches!(
+            parsed("lesson 3"),
+            Err(CliError::UnknownCommand(c)) if c == "lesson"
+        ));
+        assert!(matches!(parsed("run"), Err(CliError::MissingArgument(_))));
+        assert_eq!(parsed("").unwrap(), Command::RunAll);
+    }
+
+    #[test]
+    fn typos_suggest_the_closest_lessons() {
+        let registry = lessons::registry();
+        let error = select(&registry, &Selection::Lesson("ownrship".to_string()));
+        assert!(matches!(
+            error,
+            Err(CliError::UnknownLesson { suggestions, .. }) if suggestions == ["ownership"]
+        ));
+
+        let candidates = [
+            ("borrowing", "Borrowing Basics"),
+            ("iterators", "Iterators"),
+        ];
+        assert_eq!(suggest("Borowing", candidates.into_iter()), ["borrowing"]);
+        assert_eq!(suggest("basics", candidates.into_iter()), ["borrowing"]);
+        assert!(suggest("xyz", candidates.into_iter()).is_empty());
+    }
+
+    #[test]
+    fn edit_distance_counts_single_char_edits() {
+        assert_eq!(edit_distance("ownrship", "ownership"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("é", "e"), 1);
+    }
+}
@@ -0,0 +1,162 @@
+//! ============================================================================
+//! COMPILE-FAIL MODULE - Checks that the lessons' "❌" lines really fail
+//! ============================================================================
+//! Every `compile_fail/*.rs` file is a standalone program that must NOT
+//! compile. A header of `//` comments says why:
+//!
+//!     // expect: E0382                  ← rustc error code that must appear
+//!     // lesson: ownership              ← id of the lesson that explains it
+//!     // from: outln!(out, "{}", s1);   ← optional: the commented-out lesson
+//!                                         line the snippet reproduces
+//!
+//! The tests compile each snippet with `rustc::check()` (the local `rustc`, or
+//! `$RUSTC`) and assert the expected code is among the errors. Every
+//! `// code; // ❌` line in a lesson must be covered by a `from:` header, so new
+//! broken-on-purpose lines cannot slip in unchecked.
+//! ============================================================================
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::rustc;
+
+/// Directory holding the snippets
+pub fn corpus_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("compile_fail")
+}
+
+/// One snippet of the corpus
+#[derive(Debug)]
+pub struct Snippet {
+    pub name: String,
+    pub expect: String,
+    pub lesson: String,
+    pub from: Option<String>,
+    pub source: String,
+}
+
+impl Snippet {
+    /// Reads a snippet and its header; `Err` names what is missing
+    pub fn load(path: &Path) -> Result<Snippet, String> {
+        let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let header = |key: &str| {
+            text.lines()
+                .take_while(|line| line.starts_with("//"))
+                .find_map(|line| line.strip_prefix(&format!("// {}:", key)))
+                .map(|value| value.trim().to_string())
+        };
+        let missing = |key: &str| format!("{}: missing `// {}:` header", path.display(), key);
+
+        Ok(Snippet {
+            name: path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .unwrap_or_default()
+                .to_string(),
+            expect: header("expect").ok_or_else(|| missing("expect"))?,
+            lesson: header("lesson").ok_or_else(|| missing("lesson"))?,
+            from: header("from"),
+            source: text.clone(),
+        })
+    }
+
+    /// Compiles the snippet; `Ok` holds every error code rustc reported
+    pub fn error_codes(&self) -> Result<Vec<String>, String> {
+        let compilation = rustc::check(&self.source, &format!("{}.rs", self.name))
+            .map_err(|e| format!("{}: {}", self.name, e))?;
+        Ok(compilation
+            .error_codes()
+            .into_iter()
+            .map(String::from)
+            .collect())
+    }
+}
+
+/// Every snippet in the corpus, sorted by name
+pub fn corpus() -> Result<Vec<Snippet>, String> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(corpus_dir())
+        .map_err(|e| format!("{}: {}", corpus_dir().display(), e))?
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .collect();
+    paths.sort();
+    paths.iter().map(|path| Snippet::load(path)).collect()
+}
+
+/// The code of every `// code; // ❌ ...` line in a lesson source
+pub fn broken_lines(source: &str) -> Vec<&str> {
+    source
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with("///"))
+        .filter_map(|line| line.strip_prefix("// "))
+        .filter_map(|line| line.split_once("// ❌"))
+        .map(|(code, _)| code.trim())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lessons;
+
+    #[test]
+    fn every_snippet_fails_with_its_expected_code() {
+        let failures: Vec<String> = corpus()
+            .unwrap()
+            .iter()
+            .filter_map(|snippet| match snippet.error_codes() {
+                Err(error) => Some(error),
+                Ok(codes) if codes.is_empty() => Some(format!("{}: compiled", snippet.name)),
+                Ok(codes) if !codes.contains(&snippet.expect) => Some(format!(
+                    "{}: expected {}, rustc reported {}",
+                    snippet.name,
+                    snippet.expect,
+                    codes.join(", ")
+                )),
+                Ok(_) => None,
+            })
+            .collect();
+
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    }
+
+    #[test]
+    fn snippets_point_at_known_lessons() {
+        let registry = lessons::registry();
+        let unknown: Vec<String> = corpus()
+            .unwrap()
+            .into_iter()
+            .filter(|snippet| registry.find(&snippet.lesson).is_none())
+            .map(|snippet| format!("{} → {}", snippet.name, snippet.lesson))
+            .collect();
+
+        assert!(
+            unknown.is_empty(),
+            "snippets for unknown lessons: {:?}",
+            unknown
+        );
+    }
+
+    #[test]
+    fn every_broken_lesson_line_has_a_snippet() {
+        let corpus = corpus().unwrap();
+        let uncovered: Vec<String> = lessons::declared_modules()
+            .into_iter()
+            .filter_map(|module| Some((module, lessons::module_source(module)?)))
+            .flat_map(|(module, source)| {
+                broken_lines(source)
+                    .into_iter()
+                    .map(move |line| (module, line))
+            })
+            .filter(|(_, line)| !corpus.iter().any(|s| s.from.as_deref() == Some(*line)))
+            .map(|(module, line)| format!("{}.rs: {}", module, line))
+            .collect();
+
+        assert!(
+            uncovered.is_empty(),
+            "❌ lines without a compile_fail/ snippet:\n{}",
+            uncovered.join("\n")
+        );
+    }
+}
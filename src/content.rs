@@ -0,0 +1,36 @@
+//! ============================================================================
+//! CONTENT MODULE - Typed building blocks of a lesson
+//! ============================================================================
+//! Lessons emit `Block`s instead of pre-formatted strings, so the same lesson
+//! can be rendered for the terminal, plain text, Markdown or HTML.
+//!
+//! | Block        | Terminal look today                    |
+//! |--------------|----------------------------------------|
+//! | `Banner`     | ╔═══╗ box around the whole guide       |
+//! | `Heading`    | `====` + `📘 LESSON N: Title` + `====` |
+//! | `Summary`    | `====` + `📚 SUMMARY: Title` + `====`  |
+//! | `Section`    | `--- Title ---`                        |
+//! | `Paragraph`  | Prose line                             |
+//! | `List`       | `• item` per line                      |
+//! | `Table`      | Box-drawn table                        |
+//! | `CodeSample` | Rust source shown to the learner       |
+//! | `Warning`    | `⚠️  text`, set apart by blank lines    |
+//! | `Output`     | A line printed by the example code     |
+//! ============================================================================
+
+use crate::table::Table;
+
+/// One piece of lesson content
+#[derive(Debug, Clone, PartialEq)]
+pub enum Block {
+    Banner(String),
+    Heading { number: u32, title: String },
+    Summary(String),
+    Section(String),
+    Paragraph(String),
+    List(Vec<String>),
+    Table(Table),
+    CodeSample(String),
+    Warning(String),
+    Output(String),
+}
@@ -0,0 +1,113 @@
+//! ============================================================================
+//! DIFF MODULE - Line diffs for snapshots and predictions
+//! ============================================================================
+//! A longest-common-subsequence diff over lines:
+//!
+//! - `Same`    → Line present on both sides
+//! - `Removed` → Only in the expected text (`- line`)
+//! - `Added`   → Only in the actual text (`+ line`)
+//!
+//! ============================================================================
+
+/// One line of a diff
+#[derive(Debug, PartialEq)]
+pub enum Edit<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// Line diff based on the longest common subsequence of lines
+pub fn diff_lines<'a>(expected: &'a str, actual: &'a str) -> Vec<Edit<'a>> {
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+
+    // lcs[i][j] = LCS length of old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut edits = Vec::new();
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            edits.push(Edit::Same(old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            edits.push(Edit::Removed(old[i]));
+            i += 1;
+        } else {
+            edits.push(Edit::Added(new[j]));
+            j += 1;
+        }
+    }
+    edits.extend(old[i..].iter().map(|l| Edit::Removed(l)));
+    edits.extend(new[j..].iter().map(|l| Edit::Added(l)));
+    edits
+}
+
+/// Renders a diff, keeping only `CONTEXT` unchanged lines around each change
+#[cfg_attr(not(test), allow(dead_code))] // only the snapshot tests need it
+pub fn line_diff(expected: &str, actual: &str) -> String {
+    /// Unchanged lines shown around each change
+    const CONTEXT: usize = 2;
+
+    let edits = diff_lines(expected, actual);
+    let changed: Vec<usize> = (0..edits.len())
+        .filter(|&i| !matches!(edits[i], Edit::Same(_)))
+        .collect();
+
+    let mut report = String::new();
+    let mut last_shown: Option<usize> = None;
+    for (i, edit) in edits.iter().enumerate() {
+        let near_change = changed.iter().any(|&c| c.abs_diff(i) <= CONTEXT);
+        if !near_change {
+            continue;
+        }
+        if last_shown.is_some_and(|last| last + 1 < i) {
+            report.push_str("  ...\n");
+        }
+        let line = match edit {
+            Edit::Same(l) => format!("  {}\n", l),
+            Edit::Removed(l) => format!("- {}\n", l),
+            Edit::Added(l) => format!("+ {}\n", l),
+        };
+        report.push_str(&line);
+        last_shown = Some(i);
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_marks_removed_and_added_lines() {
+        let edits = diff_lines("a\nb\nc\n", "a\nx\nc\n");
+        assert_eq!(
+            edits,
+            vec![
+                Edit::Same("a"),
+                Edit::Removed("b"),
+                Edit::Added("x"),
+                Edit::Same("c")
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_report_skips_distant_context() {
+        let expected = "1\n2\n3\n4\n5\n6\n7\n8\n";
+        let actual = "1\n2\n3\n4\n5\n6\n7\nchanged\n";
+        assert_eq!(line_diff(expected, actual), "  6\n  7\n- 8\n+ changed\n");
+    }
+}
@@ -0,0 +1,206 @@
+//! ============================================================================
+//! HIDDEN TEST CASES - One `Exercise` per stub
+//! ============================================================================
+//! Cases cover the obvious example plus the edges learners tend to miss:
+//! empty input, negative numbers, no match at all.
+//! ============================================================================
+
+use std::collections::BTreeMap;
+
+use super::{CaseResult, Exercise, ExerciseMeta, case, collections, iterators, option};
+
+/// Every exercise, in lesson order
+pub const ALL: &[&dyn Exercise] = &[&FindFirstEven, &HalfIfEven, &SumEvenSquares, &WordCounts];
+
+pub struct FindFirstEven;
+
+impl Exercise for FindFirstEven {
+    fn meta(&self) -> &'static ExerciseMeta {
+        static META: ExerciseMeta = ExerciseMeta {
+            id: "find-first-even",
+            title: "Return the first even number as an Option",
+            lesson: "option",
+            module: "option",
+            function: "find_first_even",
+        };
+        &META
+    }
+
+    fn cases(&self) -> Vec<CaseResult> {
+        find_first_even_cases(option::find_first_even)
+    }
+}
+
+/// The cases of `find-first-even`, run against `f`
+fn find_first_even_cases(f: fn(&[i32]) -> Option<i32>) -> Vec<CaseResult> {
+    vec![
+        case("find_first_even(&[1, 3, 5, 6, 7])", Some(6), || {
+            f(&[1, 3, 5, 6, 7])
+        }),
+        case("find_first_even(&[2, 4])", Some(2), || f(&[2, 4])),
+        case("find_first_even(&[1, 3, 5])", None, || f(&[1, 3, 5])),
+        case("find_first_even(&[])", None, || f(&[])),
+        case("find_first_even(&[-3, -4])", Some(-4), || f(&[-3, -4])),
+    ]
+}
+
+pub struct HalfIfEven;
+
+impl Exercise for HalfIfEven {
+    fn meta(&self) -> &'static ExerciseMeta {
+        static META: ExerciseMeta = ExerciseMeta {
+            id: "half-if-even",
+            title: "Halve even numbers, chainable with and_then",
+            lesson: "option",
+            module: "option",
+            function: "half_if_even",
+        };
+        &META
+    }
+
+    fn cases(&self) -> Vec<CaseResult> {
+        half_if_even_cases(option::half_if_even)
+    }
+}
+
+/// The cases of `half-if-even`, run against `f`
+fn half_if_even_cases(f: fn(i32) -> Option<i32>) -> Vec<CaseResult> {
+    vec![
+        case("half_if_even(10)", Some(5), || f(10)),
+        case("half_if_even(11)", None, || f(11)),
+        case("half_if_even(0)", Some(0), || f(0)),
+        case("half_if_even(-8)", Some(-4), || f(-8)),
+        case(
+            "Some(40).and_then(half_if_even).and_then(half_if_even)",
+            Some(10),
+            || Some(40).and_then(f).and_then(f),
+        ),
+    ]
+}
+
+pub struct SumEvenSquares;
+
+impl Exercise for SumEvenSquares {
+    fn meta(&self) -> &'static ExerciseMeta {
+        static META: ExerciseMeta = ExerciseMeta {
+            id: "sum-even-squares",
+            title: "Sum the squares of the even numbers with an iterator chain",
+            lesson: "iterators",
+            module: "iterators",
+            function: "sum_even_squares",
+        };
+        &META
+    }
+
+    fn cases(&self) -> Vec<CaseResult> {
+        sum_even_squares_cases(iterators::sum_even_squares)
+    }
+}
+
+/// The cases of `sum-even-squares`, run against `f`
+fn sum_even_squares_cases(f: fn(&[i32]) -> i32) -> Vec<CaseResult> {
+    vec![
+        case("sum_even_squares(&[1, 2, 3, 4])", 20, || f(&[1, 2, 3, 4])),
+        case("sum_even_squares(&[1, 3, 5])", 0, || f(&[1, 3, 5])),
+        case("sum_even_squares(&[])", 0, || f(&[])),
+        case("sum_even_squares(&[-2, 6])", 40, || f(&[-2, 6])),
+    ]
+}
+
+pub struct WordCounts;
+
+impl Exercise for WordCounts {
+    fn meta(&self) -> &'static ExerciseMeta {
+        static META: ExerciseMeta = ExerciseMeta {
+            id: "word-counts",
+            title: "Count words in a text with a map",
+            lesson: "collections",
+            module: "collections",
+            function: "word_counts",
+        };
+        &META
+    }
+
+    fn cases(&self) -> Vec<CaseResult> {
+        word_counts_cases(collections::word_counts)
+    }
+}
+
+/// The cases of `word-counts`, run against `f`
+fn word_counts_cases(f: fn(&str) -> BTreeMap<String, usize>) -> Vec<CaseResult> {
+    let counts = |pairs: &[(&str, usize)]| -> BTreeMap<String, usize> {
+        pairs.iter().map(|&(w, n)| (w.to_string(), n)).collect()
+    };
+    vec![
+        case(
+            "word_counts(\"the cat the\")",
+            counts(&[("cat", 1), ("the", 2)]),
+            || f("the cat the"),
+        ),
+        case(
+            "word_counts(\"Rust rust RUST\")",
+            counts(&[("rust", 3)]),
+            || f("Rust rust RUST"),
+        ),
+        case(
+            "word_counts(\"  spaced \\n  out  \")",
+            counts(&[("out", 1), ("spaced", 1)]),
+            || f("  spaced \n  out  "),
+        ),
+        case("word_counts(\"\")", counts(&[]), || f("")),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercises::{self, Outcome};
+
+    fn find_first_even(numbers: &[i32]) -> Option<i32> {
+        numbers.iter().copied().find(|n| n % 2 == 0)
+    }
+
+    fn half_if_even(n: i32) -> Option<i32> {
+        (n % 2 == 0).then_some(n / 2)
+    }
+
+    fn sum_even_squares(numbers: &[i32]) -> i32 {
+        numbers.iter().filter(|&&n| n % 2 == 0).map(|n| n * n).sum()
+    }
+
+    fn word_counts(text: &str) -> BTreeMap<String, usize> {
+        let mut counts = BTreeMap::new();
+        for word in text.split_whitespace() {
+            *counts.entry(word.to_lowercase()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    fn assert_all_pass(results: Vec<CaseResult>) {
+        for result in results {
+            assert_eq!(result.outcome, Outcome::Passed, "{}", result.call);
+        }
+    }
+
+    #[test]
+    fn reference_solutions_pass_every_case() {
+        assert_all_pass(find_first_even_cases(find_first_even));
+        assert_all_pass(half_if_even_cases(half_if_even));
+        assert_all_pass(sum_even_squares_cases(sum_even_squares));
+        assert_all_pass(word_counts_cases(word_counts));
+    }
+
+    #[test]
+    fn untouched_stubs_panic_with_todo() {
+        for exercise in ALL {
+            for result in exercises::check(*exercise) {
+                assert!(
+                    matches!(&result.outcome, Outcome::Panicked(m) if m.starts_with("not yet implemented")),
+                    "{}: {:?}",
+                    result.call,
+                    result.outcome
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,15 @@
+//! ============================================================================
+//! EXERCISES: Collections (lesson 16)
+//! ============================================================================
+//! Replace each `todo!()` with your own code, then run
+//! `rust-lab check <exercise>` to test it.
+//! ============================================================================
+use std::collections::BTreeMap;
+
+/// Count how often each word appears in `text`. Words are separated by
+/// whitespace and compared in lowercase. A `BTreeMap` keeps the words sorted.
+///
+/// Example: `word_counts("the cat the")` → `{"cat": 1, "the": 2}`
+pub fn word_counts(text: &str) -> BTreeMap<String, usize> {
+    todo!("split on whitespace, lowercase, count with entry().or_insert(0)")
+}
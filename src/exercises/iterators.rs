@@ -0,0 +1,14 @@
+//! ============================================================================
+//! EXERCISES: Iterators (lesson 15)
+//! ============================================================================
+//! Replace each `todo!()` with your own code, then run
+//! `rust-lab check <exercise>` to test it.
+//! ============================================================================
+
+/// Square every even number in `numbers` and add the squares up. Try to do it
+/// with one iterator chain (`filter`, `map`, `sum`) instead of a loop.
+///
+/// Example: `sum_even_squares(&[1, 2, 3, 4])` → `4 + 16 = 20`
+pub fn sum_even_squares(numbers: &[i32]) -> i32 {
+    todo!("filter the even numbers, square them, sum them")
+}
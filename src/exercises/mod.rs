@@ -0,0 +1,214 @@
+//! ============================================================================
+//! EXERCISES - Code for the learner to write, checked by hidden test cases
+//! ============================================================================
+//! Each exercise is a function stub with a `todo!()` body in one of the files
+//! below. The learner replaces the body, then runs `rust-lab check <id>`.
+//!
+//! Submodules:
+//! - option      → Stubs for lesson 13 (Option<T>)
+//! - iterators   → Stubs for lesson 15 (iterators)
+//! - collections → Stubs for lesson 16 (collections)
+//! - checks      → The hidden test cases (`Exercise` implementations)
+//!
+//! Each case runs inside `catch_unwind`, so `todo!()` or a panic in the
+//! learner's code fails that one case instead of the whole check. The panic
+//! hook is silenced while checking, so no backtrace noise appears.
+//! ============================================================================
+
+// Unsolved stubs ignore their parameters
+#[allow(unused_variables)]
+pub mod collections;
+#[allow(unused_variables)]
+pub mod iterators;
+#[allow(unused_variables)]
+pub mod option;
+
+mod checks;
+
+use std::any::Any;
+use std::fmt::Debug;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Mutex, PoisonError};
+
+/// ============================================================================
+/// EXERCISE METADATA
+/// ============================================================================
+/// - `id`       → Stable name used on the command line (`find-first-even`)
+/// - `title`    → One-line description
+/// - `lesson`   → Id of the lesson that teaches what the exercise needs
+/// - `module`   → Submodule of `exercises/` holding the stub
+/// - `function` → Name of the stub function to implement
+///
+/// ============================================================================
+#[derive(Debug)]
+pub struct ExerciseMeta {
+    pub id: &'static str,
+    pub title: &'static str,
+    pub lesson: &'static str,
+    pub module: &'static str,
+    pub function: &'static str,
+}
+
+/// Implemented by every exercise; `cases()` calls the learner's function once
+/// per hidden test case (build each result with `case()`)
+pub trait Exercise {
+    fn meta(&self) -> &'static ExerciseMeta;
+    fn cases(&self) -> Vec<CaseResult>;
+
+    fn id(&self) -> &'static str {
+        self.meta().id
+    }
+}
+
+/// What happened when one test case ran
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Passed,
+    Failed { actual: String },
+    Panicked(String),
+}
+
+/// One test case: the call as the learner would write it, the expected value
+/// and what actually happened
+#[derive(Debug)]
+pub struct CaseResult {
+    pub call: String,
+    pub expected: String,
+    pub outcome: Outcome,
+}
+
+/// Runs `f` and compares its result with `expected`; a panic becomes
+/// `Outcome::Panicked`
+pub fn case<T: Debug + PartialEq>(call: &str, expected: T, f: impl FnOnce() -> T) -> CaseResult {
+    let outcome = match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(actual) if actual == expected => Outcome::Passed,
+        Ok(actual) => Outcome::Failed {
+            actual: format!("{:?}", actual),
+        },
+        Err(payload) => Outcome::Panicked(panic_message(payload.as_ref())),
+    };
+    CaseResult {
+        call: call.to_string(),
+        expected: format!("{:?}", expected),
+        outcome,
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "panicked".to_string()
+    }
+}
+
+/// Runs every case of an exercise with the panic hook silenced. The hook is
+/// process-wide: checks run one at a time, and a panic on another thread
+/// while a check runs is silenced too.
+pub fn check(exercise: &dyn Exercise) -> Vec<CaseResult> {
+    static HOOK: Mutex<()> = Mutex::new(());
+    let _only_one = HOOK.lock().unwrap_or_else(PoisonError::into_inner);
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let results = exercise.cases();
+    panic::set_hook(hook);
+    results
+}
+
+/// Every exercise, in lesson order
+pub fn all() -> Vec<&'static dyn Exercise> {
+    checks::ALL.to_vec()
+}
+
+/// Finds an exercise by id (case-insensitive)
+pub fn find(id: &str) -> Option<&'static dyn Exercise> {
+    let id = id.trim().to_lowercase();
+    all().into_iter().find(|exercise| exercise.id() == id)
+}
+
+/// Source text of a stub module, for showing the exercise prompt
+pub fn module_source(module: &str) -> Option<&'static str> {
+    let source = match module {
+        "option" => include_str!("option.rs"),
+        "iterators" => include_str!("iterators.rs"),
+        "collections" => include_str!("collections.rs"),
+        _ => return None,
+    };
+    Some(source)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lessons;
+
+    #[test]
+    fn exercises_have_unique_ids_known_lessons_and_stubs() {
+        let registry = lessons::registry();
+        let mut problems = Vec::new();
+        let mut seen = Vec::new();
+        for exercise in all() {
+            let meta = exercise.meta();
+            if seen.contains(&meta.id) {
+                problems.push(format!("duplicate exercise id '{}'", meta.id));
+            }
+            seen.push(meta.id);
+
+            if registry.find(meta.lesson).is_none() {
+                problems.push(format!(
+                    "exercise '{}' names unknown lesson '{}'",
+                    meta.id, meta.lesson
+                ));
+            }
+            let signature = format!("pub fn {}(", meta.function);
+            match module_source(meta.module) {
+                Some(code) if code.lines().any(|l| l.starts_with(&signature)) => {}
+                Some(_) => problems.push(format!(
+                    "exercise '{}': no `pub fn {}` in exercises/{}.rs",
+                    meta.id, meta.function, meta.module
+                )),
+                None => problems.push(format!(
+                    "exercise '{}' names unknown module 'exercises/{}.rs'",
+                    meta.id, meta.module
+                )),
+            }
+        }
+        assert_eq!(problems, Vec::<String>::new());
+    }
+
+    #[test]
+    fn case_compares_and_catches_panics() {
+        let results = check_silently(|| {
+            vec![
+                case("two", 2, || 1 + 1),
+                case("three", 3, || 1 + 1),
+                case("boom", 0, || panic!("boom {}", 1)),
+            ]
+        });
+        assert_eq!(results[0].outcome, Outcome::Passed);
+        assert_eq!(
+            results[1].outcome,
+            Outcome::Failed {
+                actual: "2".to_string()
+            }
+        );
+        assert_eq!(results[2].outcome, Outcome::Panicked("boom 1".to_string()));
+        assert_eq!(results[1].expected, "3");
+    }
+
+    /// Runs `cases` through `check`, like a real exercise
+    fn check_silently(cases: fn() -> Vec<CaseResult>) -> Vec<CaseResult> {
+        struct Inline(fn() -> Vec<CaseResult>);
+        impl Exercise for Inline {
+            fn meta(&self) -> &'static ExerciseMeta {
+                unreachable!("only cases() is used")
+            }
+            fn cases(&self) -> Vec<CaseResult> {
+                (self.0)()
+            }
+        }
+        check(&Inline(cases))
+    }
+}
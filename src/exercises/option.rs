@@ -0,0 +1,21 @@
+//! ============================================================================
+//! EXERCISES: Option<T> (lesson 13)
+//! ============================================================================
+//! Replace each `todo!()` with your own code, then run
+//! `rust-lab check <exercise>` to test it.
+//! ============================================================================
+
+/// Return the first even number in `numbers`, or `None` if there is none.
+///
+/// Example: `find_first_even(&[1, 3, 6, 8])` → `Some(6)`
+pub fn find_first_even(numbers: &[i32]) -> Option<i32> {
+    todo!("return the first even number in `numbers`")
+}
+
+/// Return half of `n` when it is even, `None` when it is odd, so that it can
+/// be chained with `.and_then(half_if_even)`.
+///
+/// Example: `half_if_even(10)` → `Some(5)`, `half_if_even(11)` → `None`
+pub fn half_if_even(n: i32) -> Option<i32> {
+    todo!("halve even numbers, reject odd ones")
+}
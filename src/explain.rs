@@ -0,0 +1,376 @@
+//! ============================================================================
+//! EXPLAIN MODULE - Links rustc errors to the lessons that explain them
+//! ============================================================================
+//! `rust-lab explain <file>` (or stdin) reads JSON diagnostics from
+//! `rustc --error-format=json` or `cargo build --message-format=json`, and for
+//! each error prints a short explanation plus the lesson to run:
+//!
+//!     error[E0382]: borrow of moved value: `s1`
+//!       --> src/main.rs:4:20
+//!       💡 A value that is not Copy (String, Vec, ...) moved to a new owner ...
+//!       📖 Lesson 6 (Ownership Deep Dive), section "Move Semantics (String)"
+//!          → rust-lab run ownership
+//!
+//! `TOPICS` is searched in order and the first match wins, so specific entries
+//! (code + message pattern) come before general ones (code only). Patterns are
+//! matched against the message and the primary span's label, where rustc puts
+//! details such as "expected `i32`, found `Option<i32>`".
+//! ============================================================================
+
+use std::io::{self, Write};
+
+use crate::lessons::Registry;
+use crate::rustc::Diagnostic;
+
+/// One kind of error and where it is taught
+#[derive(Debug)]
+pub struct Topic {
+    /// Error codes this topic covers
+    pub codes: &'static [&'static str],
+    /// If not empty, one of these must appear in the message or label
+    pub patterns: &'static [&'static str],
+    pub lesson: &'static str,
+    /// Title of the lesson section (`out.section(..)`), if one fits best
+    pub section: Option<&'static str>,
+    pub explanation: &'static str,
+}
+
+impl Topic {
+    fn matches(&self, diagnostic: &Diagnostic) -> bool {
+        let Some(code) = diagnostic.code.as_deref() else {
+            return false;
+        };
+        let label = diagnostic
+            .primary
+            .as_ref()
+            .and_then(|span| span.label.as_deref())
+            .unwrap_or_default();
+        self.codes.contains(&code)
+            && (self.patterns.is_empty()
+                || self
+                    .patterns
+                    .iter()
+                    .any(|p| diagnostic.message.contains(p) || label.contains(p)))
+    }
+}
+
+/// Every known error, most specific first
+pub const TOPICS: &[Topic] = &[
+    Topic {
+        codes: &["E0382"],
+        patterns: &[],
+        lesson: "ownership",
+        section: Some("Move Semantics (String)"),
+        explanation: "A value that is not Copy (String, Vec, ...) moved to a new owner, so the old \
+                      name is no longer valid. Borrow it with `&` instead, or `.clone()` it.",
+    },
+    Topic {
+        codes: &["E0505"],
+        patterns: &[],
+        lesson: "ownership",
+        section: Some("Move Semantics (String)"),
+        explanation: "The value moved while a reference to it was still in use. Finish using the \
+                      reference before the move, or move a clone.",
+    },
+    Topic {
+        codes: &["E0507"],
+        patterns: &[],
+        lesson: "borrowing-functions",
+        section: Some("Immutable Borrow in Function"),
+        explanation: "You tried to take ownership of something you only borrowed. Keep borrowing \
+                      it (`&x.field`) or `.clone()` it.",
+    },
+    Topic {
+        codes: &["E0499"],
+        patterns: &[],
+        lesson: "borrowing",
+        section: Some("Mutable Borrowing"),
+        explanation: "Two `&mut` borrows of the same value are alive at once. A borrow lasts until \
+                      its last use: finish with the first before taking the second.",
+    },
+    Topic {
+        codes: &["E0502"],
+        patterns: &[],
+        lesson: "borrowing",
+        section: Some("Mutable Borrowing"),
+        explanation: "A `&mut` borrow overlaps a `&` borrow of the same value. Readers and a writer \
+                      cannot coexist: reorder so the last read comes before the write.",
+    },
+    Topic {
+        codes: &["E0506"],
+        patterns: &[],
+        lesson: "borrowing",
+        section: Some("Owner Direct Mutation"),
+        explanation: "The owner wrote to a value while a reference to it was still in use. Use the \
+                      reference first, then modify.",
+    },
+    Topic {
+        codes: &["E0596"],
+        patterns: &[],
+        lesson: "borrowing-functions",
+        section: Some("Mutable Borrow in Function"),
+        explanation: "Changing a value needs a mutable borrow: declare it with `let mut`, pass \
+                      `&mut x`, and take `&mut T` in the function.",
+    },
+    Topic {
+        codes: &["E0384", "E0594"],
+        patterns: &[],
+        lesson: "variables",
+        section: None,
+        explanation: "Variables are immutable by default. Declare it with `let mut` if it has to \
+                      change.",
+    },
+    Topic {
+        codes: &["E0597", "E0716"],
+        patterns: &[],
+        lesson: "borrowing",
+        section: Some("Immutable Borrowing"),
+        explanation: "A reference outlives the value it points to. Keep the owner alive (declare \
+                      it in an outer scope) for as long as the reference is used.",
+    },
+    Topic {
+        codes: &["E0277"],
+        patterns: &["`?` operator"],
+        lesson: "result",
+        section: Some("Error Propagation with ?"),
+        explanation: "`?` only works inside a function that itself returns Result (or Option). \
+                      Change the return type, or handle the error with `match`.",
+    },
+    Topic {
+        codes: &["E0277"],
+        patterns: &["cannot be indexed by"],
+        lesson: "arrays",
+        section: Some("Direct Indexing"),
+        explanation: "Arrays, slices and vectors are indexed with `usize`. Convert the index with \
+                      `as usize` or declare it as usize.",
+    },
+    Topic {
+        codes: &["E0308"],
+        patterns: &["Option<", "`Option`"],
+        lesson: "option",
+        section: Some("Basic Option with Match"),
+        explanation: "An Option<T> is not a T. Handle the None case with `match`, `if let`, \
+                      `.unwrap_or(..)` or `?` to get at the value.",
+    },
+    Topic {
+        codes: &["E0308"],
+        patterns: &["Result<", "`Result`"],
+        lesson: "result",
+        section: Some("Basic Result with Match"),
+        explanation: "A Result<T, E> is not a T. Handle the Err case with `match`, `?` or \
+                      `.unwrap_or(..)` to get at the value.",
+    },
+    Topic {
+        codes: &["E0599"],
+        patterns: &["enum `Option"],
+        lesson: "option",
+        section: Some("Option Combinators"),
+        explanation: "The method belongs to the value inside the Option. Reach it with `.map(..)`, \
+                      `.and_then(..)`, or unwrap the Option first.",
+    },
+    Topic {
+        codes: &["E0599"],
+        patterns: &["enum `Result"],
+        lesson: "result",
+        section: Some("Result Combinators"),
+        explanation: "The method belongs to the value inside the Result. Reach it with `.map(..)`, \
+                      `.and_then(..)`, or handle the error first.",
+    },
+    Topic {
+        codes: &["E0004"],
+        patterns: &[],
+        lesson: "enums",
+        section: Some("Pattern Matching"),
+        explanation: "A `match` must cover every possible value. Add arms for the missing \
+                      variants, or a `_ =>` arm.",
+    },
+];
+
+/// The first topic that matches the diagnostic
+pub fn topic_for(diagnostic: &Diagnostic) -> Option<&'static Topic> {
+    TOPICS.iter().find(|topic| topic.matches(diagnostic))
+}
+
+/// Prints every error with its explanation and lesson
+pub fn report(
+    registry: &Registry,
+    diagnostics: &[Diagnostic],
+    out: &mut dyn Write,
+) -> io::Result<()> {
+    let errors: Vec<&Diagnostic> = diagnostics.iter().filter(|d| d.is_error()).collect();
+    let warnings = diagnostics.iter().filter(|d| d.level == "warning").count();
+    let mut linked = 0;
+
+    for error in &errors {
+        let code = error
+            .code
+            .as_deref()
+            .map_or(String::new(), |c| format!("[{}]", c));
+        writeln!(out, "error{}: {}", code, error.message)?;
+        if let Some(span) = &error.primary {
+            writeln!(out, "  --> {}:{}:{}", span.file, span.line, span.column)?;
+        }
+
+        let topic = topic_for(error);
+        match topic.and_then(|topic| Some((topic, registry.find(topic.lesson)?))) {
+            Some((topic, lesson)) => {
+                linked += 1;
+                let section = topic
+                    .section
+                    .map_or(String::new(), |title| format!(", section \"{}\"", title));
+                writeln!(out, "  💡 {}", topic.explanation)?;
+                writeln!(
+                    out,
+                    "  📖 Lesson {} ({}){}\n     → rust-lab run {}",
+                    lesson.number(),
+                    lesson.title(),
+                    section,
+                    lesson.id()
+                )?;
+            }
+            None => match &error.code {
+                Some(code) => writeln!(
+                    out,
+                    "  No lesson covers this error yet; `rustc --explain {}` describes it.",
+                    code
+                )?,
+                None => writeln!(out, "  No lesson covers this error yet.")?,
+            },
+        }
+        writeln!(out)?;
+    }
+
+    if errors.is_empty() {
+        writeln!(out, "No errors found 🎉")?;
+    } else {
+        writeln!(
+            out,
+            "{} error(s), {} linked to a lesson",
+            errors.len(),
+            linked
+        )?;
+    }
+    if warnings > 0 {
+        writeln!(out, "({} warning(s) not shown)", warnings)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lessons;
+    use crate::rustc::Span;
+    use crate::validate;
+
+    fn error(code: Option<&str>, message: &str, label: Option<&str>) -> Diagnostic {
+        Diagnostic {
+            level: "error".to_string(),
+            code: code.map(String::from),
+            message: message.to_string(),
+            primary: Some(Span {
+                file: "src/main.rs".to_string(),
+                line: 4,
+                column: 9,
+                label: label.map(String::from),
+            }),
+            rendered: None,
+        }
+    }
+
+    fn lesson_for(diagnostic: &Diagnostic) -> Option<&'static str> {
+        topic_for(diagnostic).map(|topic| topic.lesson)
+    }
+
+    #[test]
+    fn e0277_from_question_mark_and_from_indexing_differ() {
+        let question_mark = error(
+            Some("E0277"),
+            "the `?` operator can only be used in a function that returns `Result` or `Option`",
+            Some("cannot use the `?` operator in a function that returns `()`"),
+        );
+        let indexing = error(
+            Some("E0277"),
+            "the type `[i32]` cannot be indexed by `i32`",
+            Some("slice indices are of type `usize` or ranges of `usize`"),
+        );
+        let other = error(Some("E0277"), "`Foo` doesn't implement `Display`", None);
+        assert_eq!(lesson_for(&question_mark), Some("result"));
+        assert_eq!(lesson_for(&indexing), Some("arrays"));
+        assert_eq!(lesson_for(&other), None);
+    }
+
+    #[test]
+    fn e0308_on_option_and_on_result_differ() {
+        let option = error(
+            Some("E0308"),
+            "mismatched types",
+            Some("expected `i32`, found `Option<i32>`"),
+        );
+        let result = error(
+            Some("E0308"),
+            "mismatched types",
+            Some("expected `i32`, found `Result<i32, String>`"),
+        );
+        let enum_name = error(Some("E0308"), "mismatched types", Some("expected `Result`"));
+        assert_eq!(lesson_for(&option), Some("option"));
+        assert_eq!(lesson_for(&result), Some("result"));
+        assert_eq!(lesson_for(&enum_name), Some("result"));
+        assert_eq!(lesson_for(&error(None, "mismatched types", None)), None);
+    }
+
+    #[test]
+    fn report_links_known_errors_and_names_unknown_ones() {
+        let registry = lessons::registry();
+        let diagnostics = [
+            error(
+                Some("E0004"),
+                "non-exhaustive patterns: `Blue` not covered",
+                None,
+            ),
+            error(Some("E9999"), "something new", None),
+            Diagnostic {
+                level: "warning".to_string(),
+                ..error(None, "unused variable: `x`", None)
+            },
+        ];
+        let mut out = Vec::new();
+        report(&registry, &diagnostics, &mut out).unwrap();
+        let printed = String::from_utf8(out).unwrap();
+
+        assert!(printed.contains(
+            "error[E0004]: non-exhaustive patterns: `Blue` not covered\n  --> src/main.rs:4:9\n"
+        ));
+        assert!(printed.contains(
+            "(Enums & Pattern Matching), section \"Pattern Matching\"\n     → rust-lab run enums"
+        ));
+        assert!(printed.contains(
+            "error[E9999]: something new\n  --> src/main.rs:4:9\n  No lesson covers this error yet; `rustc --explain E9999` describes it.\n"
+        ));
+        assert!(printed.ends_with("2 error(s), 1 linked to a lesson\n(1 warning(s) not shown)\n"));
+    }
+
+    #[test]
+    fn report_without_errors_says_so() {
+        let mut out = Vec::new();
+        report(&lessons::registry(), &[], &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "No errors found 🎉\n");
+    }
+
+    #[test]
+    fn topics_name_real_lesson_sections() {
+        let registry = lessons::registry();
+        let mut problems = Vec::new();
+        for topic in TOPICS {
+            let label = format!("explain topic {}", topic.codes.join("/"));
+            validate::check_lesson_section(
+                &registry,
+                &label,
+                topic.lesson,
+                topic.section,
+                &mut problems,
+            );
+        }
+        assert_eq!(problems, Vec::<String>::new());
+    }
+}
@@ -0,0 +1,252 @@
+//! ============================================================================
+//! EXPORT MODULE - Markdown / static HTML pages for every lesson
+//! ============================================================================
+//! `rust-lab export --format md|html --out dir/` writes:
+//! - `index.md|html`         → Every lesson, grouped by phase, with links
+//! - `NN-<id>.md|html`       → One page per lesson
+//!
+//! Each lesson page combines three parts:
+//! - Overview → The Key Concepts doc comment above the `learn_*` function
+//! - Source   → The entry function and the helpers it calls
+//! - Output   → What the lesson actually printed, captured at export time
+//!
+//! ============================================================================
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::content::Block;
+use crate::lessons::{self, Lesson, Phase, Registry};
+use crate::output::CaptureOutput;
+use crate::render::{self, HtmlRenderer, MarkdownRenderer, Renderer, html};
+use crate::source;
+
+/// Output format of an export
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Markdown,
+    Html,
+}
+
+impl Format {
+    /// Parses `md` / `markdown` / `html`
+    pub fn parse(name: &str) -> Option<Format> {
+        match name.to_lowercase().as_str() {
+            "md" | "markdown" => Some(Format::Markdown),
+            "html" | "htm" => Some(Format::Html),
+            _ => None,
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            Format::Markdown => "md",
+            Format::Html => "html",
+        }
+    }
+
+    fn renderer(self) -> Box<dyn Renderer> {
+        match self {
+            Format::Markdown => Box::new(MarkdownRenderer::default()),
+            Format::Html => Box::new(HtmlRenderer::default()),
+        }
+    }
+
+    /// A second-level page heading
+    fn part(self, title: &str) -> String {
+        match self {
+            Format::Markdown => format!("## {}\n\n", title),
+            Format::Html => format!("<h2>{}</h2>\n", html::escape(title)),
+        }
+    }
+
+    /// A link to another exported page
+    fn link(self, target: &str, text: &str) -> String {
+        match self {
+            Format::Markdown => format!("[{}]({})", text, target),
+            Format::Html => format!("<a href=\"{}\">{}</a>", target, html::escape(text)),
+        }
+    }
+
+    /// Wraps a page body into a complete document
+    fn document(self, title: &str, body: &str) -> String {
+        match self {
+            Format::Markdown => body.to_string(),
+            Format::Html => format!(
+                "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+                 <title>{}</title>\n<style>{}</style>\n</head>\n<body>\n{}</body>\n</html>\n",
+                html::escape(title),
+                STYLE,
+                body
+            ),
+        }
+    }
+}
+
+/// Minimal stylesheet embedded in every HTML page
+const STYLE: &str = "body{font-family:sans-serif;max-width:52rem;margin:2rem auto;\
+padding:0 1rem;line-height:1.5}pre{background:#f6f8fa;padding:.75rem;overflow-x:auto}\
+pre.output{background:#1e1e1e;color:#e6e6e6}\
+p.warning{border-left:4px solid #d9a400;padding-left:.75rem}table{border-collapse:collapse}\
+td,th{border:1px solid #ccc;padding:.25rem .5rem}code{font-size:.95em}";
+
+/// File name of a lesson page, e.g. `06-ownership.md`
+fn page_name(lesson: &dyn Lesson, format: Format) -> String {
+    format!(
+        "{:02}-{}.{}",
+        lesson.number(),
+        lesson.id(),
+        format.extension()
+    )
+}
+
+/// Writes the index and one page per lesson; returns every written path
+pub fn export(registry: &Registry, format: Format, out_dir: &Path) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(out_dir)?;
+    let mut written = Vec::new();
+
+    let index = out_dir.join(format!("index.{}", format.extension()));
+    fs::write(&index, index_page(registry, format))?;
+    written.push(index);
+
+    for lesson in registry.lessons() {
+        let path = out_dir.join(page_name(lesson, format));
+        fs::write(&path, lesson_page(lesson, format))?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+fn index_page(registry: &Registry, format: Format) -> String {
+    let mut renderer = format.renderer();
+    let mut body = renderer.render(&Block::Banner("🦀 Rust Learning Guide".to_string()));
+    body.push_str(&renderer.finish());
+
+    for phase in Phase::ALL {
+        body.push_str(&format.part(&format!("Phase {} — {}", phase.number(), phase.title())));
+        let links: Vec<String> = registry
+            .in_phase(phase)
+            .map(|lesson| {
+                let text = format!("Lesson {}: {}", lesson.number(), lesson.title());
+                format.link(&page_name(lesson, format), &text)
+            })
+            .collect();
+        body.push_str(&match format {
+            Format::Markdown => {
+                links
+                    .iter()
+                    .map(|l| format!("- {}\n", l))
+                    .collect::<String>()
+                    + "\n"
+            }
+            Format::Html => format!(
+                "<ul>\n{}</ul>\n",
+                links
+                    .iter()
+                    .map(|l| format!("  <li>{}</li>\n", l))
+                    .collect::<String>()
+            ),
+        });
+    }
+    format.document("Rust Learning Guide", &body)
+}
+
+fn lesson_page(lesson: &dyn Lesson, format: Format) -> String {
+    let meta = lesson.meta();
+    let module_source = lessons::module_source(meta.module).unwrap_or_default();
+
+    let heading = Block::Heading {
+        number: meta.number,
+        title: meta.title.to_string(),
+    };
+    let mut body = render::render_all(&mut *format.renderer(), &[heading]);
+
+    if let Some(doc) = source::doc_comment(module_source, meta.entry) {
+        body.push_str(&format.part("Overview"));
+        body.push_str(&render::render_all(
+            &mut *format.renderer(),
+            &source::doc_blocks(&doc),
+        ));
+    }
+
+    let code: Vec<Block> = source::example_functions(module_source, meta.entry)
+        .into_iter()
+        .filter_map(|name| source::function_source(module_source, name))
+        .map(|code| Block::CodeSample(code.to_string()))
+        .collect();
+    if !code.is_empty() {
+        body.push_str(&format.part("Source"));
+        body.push_str(&render::render_all(&mut *format.renderer(), &code));
+    }
+
+    let mut captured = CaptureOutput::default();
+    lesson.run(&mut captured);
+    body.push_str(&format.part("Output"));
+    body.push_str(&render::render_all(
+        &mut *format.renderer(),
+        &captured.into_blocks(),
+    ));
+
+    body.push_str(&match format {
+        Format::Markdown => format!("---\n\n{}\n", format.link("index.md", "← All lessons")),
+        Format::Html => format!(
+            "<hr>\n<p>{}</p>\n",
+            format.link("index.html", "← All lessons")
+        ),
+    });
+
+    let title = format!("Lesson {}: {}", meta.number, meta.title);
+    format.document(&title, &body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn export_writes_an_index_linking_every_lesson_page() {
+        let registry = lessons::registry();
+        let dir = std::env::temp_dir().join(format!("rust-lab-export-{}", std::process::id()));
+        let written = export(&registry, Format::Markdown, &dir).unwrap();
+
+        let names: Vec<String> = written
+            .iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(names.len(), registry.lessons().count() + 1);
+        assert_eq!(names[0], "index.md");
+        assert!(names.contains(&"04-loops.md".to_string()));
+        assert!(names.contains(&"16-collections.md".to_string()));
+
+        let index = fs::read_to_string(dir.join("index.md")).unwrap();
+        for lesson in registry.lessons() {
+            let name = page_name(lesson, Format::Markdown);
+            assert!(index.contains(&format!("]({})", name)), "{}", name);
+        }
+        assert!(index.contains("- [Lesson 4: Loops (while, loop, for)](04-loops.md)"));
+
+        let loops = fs::read_to_string(dir.join("04-loops.md")).unwrap();
+        for part in ["## Overview", "## Source", "## Output", "### While Loop"] {
+            assert!(loops.contains(part), "{}", part);
+        }
+        assert!(loops.ends_with("[← All lessons](index.md)\n"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn html_pages_are_complete_documents() {
+        let registry = lessons::registry();
+        let lesson = registry.find("loops").unwrap();
+        assert_eq!(page_name(lesson, Format::Html), "04-loops.html");
+
+        let page = lesson_page(lesson, Format::Html);
+        assert!(page.starts_with("<!DOCTYPE html>"));
+        assert!(page.contains("<title>Lesson 4: Loops (while, loop, for)</title>"));
+        assert!(page.contains("<h3>While Loop</h3>"));
+        assert!(page.ends_with("</body>\n</html>\n"));
+
+        let index = index_page(&registry, Format::Html);
+        assert!(index.contains("<a href=\"04-loops.html\">Lesson 4: Loops (while, loop, for)</a>"));
+    }
+}
@@ -0,0 +1,303 @@
+//! ============================================================================
+//! GLOSSARY MODULE - Terms and definitions from the Key Concepts blocks
+//! ============================================================================
+//! The doc comment above each `learn_*` function is read back
+//! (`source::doc_comment`) and split into blocks (`source::doc_blocks`).
+//! Two shapes become glossary entries:
+//!
+//! - List items with an arrow: `.map(f)` → Transform Some(v) to Some(f(v))
+//! - Table rows: the first cell is the term, the second the definition
+//!
+//! A table with more than two columns gives `Header: cell` pairs as the
+//! definition. Each entry keeps the short paragraph it appeared under
+//! (`Key Concepts`, `Common Methods`, ...) as its group; plain list items
+//! under `Key Concepts` are kept as notes for the cheat sheet.
+//!
+//! `lookup()` puts exact names first, and `cheatsheet()` returns blocks, so
+//! the terminal and Markdown renderers both print it.
+//! ============================================================================
+
+use crate::content::Block;
+use crate::lessons::{self, Lesson, Registry};
+use crate::source;
+use crate::table::Table;
+
+/// One glossary entry
+#[derive(Debug, Clone, PartialEq)]
+pub struct Term {
+    pub term: String,
+    pub definition: String,
+    pub lesson: &'static str,
+    /// Heading the entry was listed under (`Key Concepts`, `Common Methods`)
+    pub group: String,
+}
+
+/// What one lesson's doc comment contributes
+struct LessonConcepts {
+    terms: Vec<Term>,
+    /// `Key Concepts` list items that are not `term → definition`
+    notes: Vec<String>,
+}
+
+/// Paragraphs up to this long name the list or table below them
+const MAX_GROUP_LEN: usize = 40;
+
+fn lesson_concepts(lesson: &dyn Lesson) -> LessonConcepts {
+    let meta = lesson.meta();
+    let doc = lessons::module_source(meta.module)
+        .and_then(|module| source::doc_comment(module, meta.entry))
+        .unwrap_or_default();
+    concepts(&doc, meta.id)
+}
+
+/// Terms and notes of one lesson's doc text
+fn concepts(doc: &str, lesson: &'static str) -> LessonConcepts {
+    let mut terms = Vec::new();
+    let mut notes = Vec::new();
+    let mut group = String::from("Key Concepts");
+    let term = |term: &str, definition: &str, group: &str| Term {
+        term: term.trim().to_string(),
+        definition: definition.trim().to_string(),
+        lesson,
+        group: group.to_string(),
+    };
+
+    for block in source::doc_blocks(doc) {
+        match block {
+            Block::Paragraph(text) if text.len() <= MAX_GROUP_LEN => {
+                group = text.trim_end_matches([':', '?']).to_string();
+            }
+            Block::List(items) => {
+                for item in items {
+                    match item.split_once('→') {
+                        Some((name, definition)) => terms.push(term(name, definition, &group)),
+                        None if group == "Key Concepts" => notes.push(item),
+                        None => {}
+                    }
+                }
+            }
+            Block::Table(table) => {
+                for row in &table.rows {
+                    let Some((name, cells)) =
+                        row.split_first().filter(|(name, _)| !name.is_empty())
+                    else {
+                        continue;
+                    };
+                    let definition = if cells.len() == 1 {
+                        cells[0].clone()
+                    } else {
+                        table.headers[1..]
+                            .iter()
+                            .zip(cells)
+                            .map(|(header, cell)| format!("{}: {}", header, cell))
+                            .collect::<Vec<_>>()
+                            .join("; ")
+                    };
+                    terms.push(term(name, &definition, &group));
+                }
+            }
+            _ => {}
+        }
+    }
+    LessonConcepts { terms, notes }
+}
+
+/// Every glossary entry, lesson by lesson in teaching order
+pub fn terms(registry: &Registry) -> Vec<Term> {
+    registry
+        .lessons()
+        .flat_map(|lesson| lesson_concepts(lesson).terms)
+        .collect()
+}
+
+/// All entries sorted by term, ignoring case, backticks and a leading `.`
+pub fn alphabetical(terms: &[Term]) -> Vec<&Term> {
+    let mut sorted: Vec<&Term> = terms.iter().collect();
+    sorted.sort_by_cached_key(|term| normalize(&term.term));
+    sorted
+}
+
+/// Lowercase, without backticks or a leading `.` (`.unwrap()` → `unwrap()`)
+fn normalize(text: &str) -> String {
+    text.replace('`', "")
+        .trim()
+        .trim_start_matches('.')
+        .to_lowercase()
+}
+
+/// The name part of a normalized term: `unwrap_or(x)` → `unwrap_or`
+fn name(normalized: &str) -> &str {
+    normalized
+        .split(['(', ' ', '<'])
+        .next()
+        .unwrap_or(normalized)
+}
+
+/// Entries matching `query`: exact names first, then names starting with
+/// it, then terms containing it; definitions are searched only when no term
+/// matches
+pub fn lookup<'t>(terms: &'t [Term], query: &str) -> Vec<&'t Term> {
+    let query = normalize(query);
+    let rank = |term: &Term| {
+        let normalized = normalize(&term.term);
+        if normalized == query || name(&normalized) == query {
+            Some(0)
+        } else if name(&normalized).starts_with(&query) {
+            Some(1)
+        } else if normalized.contains(&query) {
+            Some(2)
+        } else {
+            None
+        }
+    };
+
+    let mut found: Vec<(usize, &Term)> = terms
+        .iter()
+        .filter_map(|term| Some((rank(term)?, term)))
+        .collect();
+    if found.is_empty() {
+        found = terms
+            .iter()
+            .filter(|term| term.definition.to_lowercase().contains(&query))
+            .map(|term| (3, term))
+            .collect();
+    }
+    // Stable sort: within a rank, teaching order is kept
+    found.sort_by_key(|&(rank, _)| rank);
+    found.into_iter().map(|(_, term)| term).collect()
+}
+
+/// A condensed reference of every lesson: its terms as a table and its
+/// remaining Key Concepts as a list
+pub fn cheatsheet(registry: &Registry) -> Vec<Block> {
+    let mut blocks = vec![Block::Banner("🦀 RUST CHEAT SHEET 🦀".to_string())];
+    for lesson in registry.lessons() {
+        let concepts = lesson_concepts(lesson);
+        if concepts.terms.is_empty() && concepts.notes.is_empty() {
+            continue;
+        }
+        blocks.push(Block::Section(format!(
+            "{}. {}",
+            lesson.number(),
+            lesson.title()
+        )));
+        if !concepts.terms.is_empty() {
+            let mut table = Table::new(&["Term", "Meaning"]);
+            for term in &concepts.terms {
+                table = table.row(&[&term.term, &term.definition]);
+            }
+            blocks.push(Block::Table(table));
+        }
+        if !concepts.notes.is_empty() {
+            blocks.push(Block::List(concepts.notes));
+        }
+    }
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOC: &str = "\
+Key Concepts:
+- Ownership moves values
+- `.map(f)` → Transform Some(v) to Some(f(v))
+
+Common Methods:
+- `.unwrap()` → Get value or panic
+- plain item, not a note
+
+| Type     | Meaning          |
+|----------|------------------|
+| `String` | Owned text       |
+|          |                  |
+| `&str`   | Borrowed text    |
+
+| Collection | Mutable? | Heap/Stack |
+|------------|----------|------------|
+| `Vec<T>`   | Yes      | Heap       |
+";
+
+    fn term(term: &str, definition: &str, group: &str) -> Term {
+        Term {
+            term: term.to_string(),
+            definition: definition.to_string(),
+            lesson: "demo",
+            group: group.to_string(),
+        }
+    }
+
+    #[test]
+    fn arrows_and_tables_become_terms() {
+        let concepts = concepts(DOC, "demo");
+        assert_eq!(
+            concepts.terms,
+            [
+                term(
+                    "`.map(f)`",
+                    "Transform Some(v) to Some(f(v))",
+                    "Key Concepts"
+                ),
+                term("`.unwrap()`", "Get value or panic", "Common Methods"),
+                term("`String`", "Owned text", "Common Methods"),
+                term("`&str`", "Borrowed text", "Common Methods"),
+                term(
+                    "`Vec<T>`",
+                    "Mutable?: Yes; Heap/Stack: Heap",
+                    "Common Methods"
+                ),
+            ]
+        );
+        assert_eq!(concepts.notes, ["Ownership moves values"]);
+    }
+
+    #[test]
+    fn empty_table_rows_are_skipped() {
+        let concepts = concepts("| Term |\n|------|\n|      |\n| `x`  |\n", "demo");
+        assert_eq!(concepts.terms, [term("`x`", "", "Key Concepts")]);
+    }
+
+    fn looked_up(terms: &[Term], query: &str) -> Vec<String> {
+        lookup(terms, query)
+            .iter()
+            .map(|term| term.term.clone())
+            .collect()
+    }
+
+    #[test]
+    fn lookup_ranks_exact_then_prefix_then_contains() {
+        let terms = [
+            term("`.unwrap_or_else(f)`", "Lazy default", "Methods"),
+            term("`.map_or(d, f)`", "Map or default", "Methods"),
+            term("`.unwrap_or(x)`", "Value or default", "Methods"),
+            term("`.unwrap()`", "Value or panic", "Methods"),
+        ];
+        assert_eq!(
+            looked_up(&terms, "unwrap_or"),
+            ["`.unwrap_or(x)`", "`.unwrap_or_else(f)`"]
+        );
+        assert_eq!(
+            looked_up(&terms, ".UNWRAP"),
+            ["`.unwrap()`", "`.unwrap_or_else(f)`", "`.unwrap_or(x)`"]
+        );
+        assert_eq!(
+            looked_up(&terms, "or"),
+            ["`.unwrap_or_else(f)`", "`.map_or(d, f)`", "`.unwrap_or(x)`"]
+        );
+    }
+
+    #[test]
+    fn lookup_falls_back_to_definitions() {
+        let terms = [
+            term("`.unwrap()`", "Value or panic", "Methods"),
+            term("`.expect(msg)`", "Value or panic with a message", "Methods"),
+            term("`.unwrap_or(x)`", "Value or default", "Methods"),
+        ];
+        assert_eq!(
+            looked_up(&terms, "Panic"),
+            ["`.unwrap()`", "`.expect(msg)`"]
+        );
+        assert!(looked_up(&terms, "lifetime").is_empty());
+    }
+}
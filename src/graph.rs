@@ -0,0 +1,322 @@
+//! ============================================================================
+//! GRAPH MODULE - Lesson prerequisites as a dependency graph
+//! ============================================================================
+//! Every lesson lists the lessons it builds on in `LessonMeta::requires`
+//! (`vectors` requires `borrowing`, `option` requires `enums`, ...). Together
+//! they form a directed graph that must have no cycles.
+//!
+//!     variables ──► ownership ──► borrowing ──► vectors ──► iterators
+//!                                     ▲
+//!     loops ──► arrays ───────────────┘
+//!
+//! `order()` sorts the lessons topologically; among the lessons that are
+//! ready, the lowest number goes first, so a graph that agrees with the
+//! numbering gives back the numbering. `missing()` lists the direct
+//! prerequisites a learner has not completed yet. `to_text()` and `to_dot()`
+//! draw the graph for people and for Graphviz
+//! (`rust-lab graph --dot | dot -Tsvg > lessons.svg`).
+//! ============================================================================
+
+use std::fmt;
+
+use crate::lessons::{Lesson, Phase, Registry};
+
+/// Why the prerequisites cannot be put in order
+#[derive(Debug, PartialEq)]
+pub enum GraphError {
+    /// `lesson` requires an id that no lesson has
+    UnknownPrerequisite {
+        lesson: &'static str,
+        requires: &'static str,
+    },
+    /// Lessons that require each other in a loop; the first id is repeated
+    /// at the end (`a → b → a`)
+    Cycle(Vec<&'static str>),
+}
+
+impl fmt::Display for GraphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GraphError::UnknownPrerequisite { lesson, requires } => write!(
+                f,
+                "lesson '{}' requires unknown lesson '{}'",
+                lesson, requires
+            ),
+            GraphError::Cycle(ids) => write!(
+                f,
+                "lessons require each other in a cycle: {}",
+                ids.join(" → ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GraphError {}
+
+/// The registry's lessons in topological order: each lesson comes after
+/// everything it requires, and ties go to the lower lesson number
+pub fn order(registry: &Registry) -> Result<Vec<&dyn Lesson>, GraphError> {
+    let lessons: Vec<&dyn Lesson> = registry.lessons().collect();
+
+    // requires[i] → indexes of the lessons that lesson i builds on
+    let mut requires = Vec::with_capacity(lessons.len());
+    for lesson in &lessons {
+        let mut edges = Vec::new();
+        for &id in lesson.meta().requires {
+            let index = lessons.iter().position(|l| l.id() == id).ok_or(
+                GraphError::UnknownPrerequisite {
+                    lesson: lesson.id(),
+                    requires: id,
+                },
+            )?;
+            edges.push(index);
+        }
+        requires.push(edges);
+    }
+
+    // The registry is sorted by number, so the first ready lesson is the lowest
+    let mut placed = vec![false; lessons.len()];
+    let mut ordered = Vec::with_capacity(lessons.len());
+    while let Some(next) =
+        (0..lessons.len()).find(|&i| !placed[i] && requires[i].iter().all(|&j| placed[j]))
+    {
+        placed[next] = true;
+        ordered.push(lessons[next]);
+    }
+
+    match placed.iter().position(|&p| !p) {
+        None => Ok(ordered),
+        Some(start) => {
+            let cycle = find_cycle(&requires, &placed, start);
+            Err(GraphError::Cycle(
+                cycle.into_iter().map(|i| lessons[i].id()).collect(),
+            ))
+        }
+    }
+}
+
+/// A loop among the lessons left unplaced, as indexes (first one repeated)
+///
+/// Every unplaced lesson still waits on an unplaced prerequisite, so
+/// following those from `start` must eventually come back to a lesson
+/// already on the path.
+fn find_cycle(requires: &[Vec<usize>], placed: &[bool], start: usize) -> Vec<usize> {
+    let mut path = vec![start];
+    loop {
+        let current = *path.last().expect("path starts non-empty");
+        let Some(&next) = requires[current].iter().find(|&&j| !placed[j]) else {
+            return path;
+        };
+        if let Some(at) = path.iter().position(|&i| i == next) {
+            let mut cycle = path.split_off(at);
+            cycle.push(next);
+            return cycle;
+        }
+        path.push(next);
+    }
+}
+
+/// Direct prerequisites of `lesson` that `done` rejects, in declaration order
+pub fn missing<'r>(
+    registry: &'r Registry,
+    lesson: &dyn Lesson,
+    done: impl Fn(&dyn Lesson) -> bool,
+) -> Vec<&'r dyn Lesson> {
+    lesson
+        .meta()
+        .requires
+        .iter()
+        .filter_map(|id| registry.find(id))
+        .filter(|&required| !done(required))
+        .collect()
+}
+
+/// Lessons grouped by phase, each with the ids it builds on
+pub fn to_text(registry: &Registry) -> String {
+    let mut text = String::from("Lesson prerequisites (lesson ← lessons it builds on)\n");
+    for phase in Phase::ALL {
+        text.push_str(&format!("\nPhase {} — {}\n", phase.number(), phase.title()));
+        for lesson in registry.in_phase(phase) {
+            let requires = lesson.meta().requires;
+            let line = if requires.is_empty() {
+                format!("  {:>3}  {}", lesson.number(), lesson.id())
+            } else {
+                format!(
+                    "  {:>3}  {:<22} ← {}",
+                    lesson.number(),
+                    lesson.id(),
+                    requires.join(", ")
+                )
+            };
+            text.push_str(&line);
+            text.push('\n');
+        }
+    }
+    text
+}
+
+/// The graph in Graphviz DOT, one cluster per phase; edges point from a
+/// prerequisite to the lesson that builds on it
+pub fn to_dot(registry: &Registry) -> String {
+    let quote = |text: &str| format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""));
+
+    let mut dot = String::from("digraph lessons {\n    rankdir=LR;\n    node [shape=box];\n");
+    for phase in Phase::ALL {
+        dot.push_str(&format!(
+            "\n    subgraph cluster_phase{} {{\n        label={};\n",
+            phase.number(),
+            quote(&format!("Phase {}: {}", phase.number(), phase.title()))
+        ));
+        for lesson in registry.in_phase(phase) {
+            dot.push_str(&format!(
+                "        {} [label={}];\n",
+                quote(lesson.id()),
+                quote(&format!("{}. {}", lesson.number(), lesson.title()))
+            ));
+        }
+        dot.push_str("    }\n");
+    }
+
+    dot.push('\n');
+    for lesson in registry.lessons() {
+        for required in lesson.meta().requires {
+            dot.push_str(&format!(
+                "    {} -> {};\n",
+                quote(required),
+                quote(lesson.id())
+            ));
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lessons::LessonMeta;
+    use crate::output::Output;
+
+    struct Fake(&'static LessonMeta);
+
+    impl Lesson for Fake {
+        fn meta(&self) -> &'static LessonMeta {
+            self.0
+        }
+
+        fn run(&self, _out: &mut dyn Output) {}
+    }
+
+    const fn meta(id: &'static str, number: u32, requires: &'static [&'static str]) -> LessonMeta {
+        LessonMeta {
+            id,
+            number,
+            title: id,
+            phase: Phase::Basics,
+            module: "basics",
+            entry: "learn",
+            requires,
+        }
+    }
+
+    fn registry(metas: &'static [LessonMeta]) -> Registry {
+        let mut registry = Registry::default();
+        for meta in metas {
+            registry.register(Fake(meta));
+        }
+        registry
+    }
+
+    fn order_error(metas: &'static [LessonMeta]) -> GraphError {
+        match order(&registry(metas)) {
+            Ok(lessons) => panic!("expected an error, got {:?}", ids(&lessons)),
+            Err(error) => error,
+        }
+    }
+
+    fn ids(lessons: &[&dyn Lesson]) -> Vec<&'static str> {
+        lessons.iter().map(|lesson| lesson.id()).collect()
+    }
+
+    #[test]
+    fn ready_lessons_go_in_number_order() {
+        static METAS: [LessonMeta; 4] = [
+            meta("a", 1, &[]),
+            meta("b", 2, &["c"]),
+            meta("c", 3, &[]),
+            meta("d", 4, &["a"]),
+        ];
+        let registry = registry(&METAS);
+        assert_eq!(ids(&order(&registry).unwrap()), ["a", "c", "b", "d"]);
+    }
+
+    #[test]
+    fn numbering_that_agrees_with_the_graph_is_kept() {
+        static METAS: [LessonMeta; 3] = [
+            meta("a", 1, &[]),
+            meta("b", 2, &["a"]),
+            meta("c", 3, &["a", "b"]),
+        ];
+        let registry = registry(&METAS);
+        assert_eq!(ids(&order(&registry).unwrap()), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn unknown_prerequisite_is_named() {
+        static METAS: [LessonMeta; 2] = [meta("a", 1, &[]), meta("b", 2, &["a", "zzz"])];
+        let error = order_error(&METAS);
+        assert_eq!(
+            error,
+            GraphError::UnknownPrerequisite {
+                lesson: "b",
+                requires: "zzz"
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "lesson 'b' requires unknown lesson 'zzz'"
+        );
+    }
+
+    #[test]
+    fn cycle_is_reported_as_a_closed_path() {
+        static METAS: [LessonMeta; 4] = [
+            meta("a", 1, &["c"]),
+            meta("b", 2, &["a"]),
+            meta("c", 3, &["b"]),
+            meta("d", 4, &[]),
+        ];
+        let error = order_error(&METAS);
+        assert_eq!(error, GraphError::Cycle(vec!["a", "c", "b", "a"]));
+        assert_eq!(
+            error.to_string(),
+            "lessons require each other in a cycle: a → c → b → a"
+        );
+    }
+
+    #[test]
+    fn cycle_path_leaves_out_the_lessons_leading_into_it() {
+        // `a` waits on the loop b ⇄ c without being part of it
+        static METAS: [LessonMeta; 3] = [
+            meta("a", 1, &["b"]),
+            meta("b", 2, &["c"]),
+            meta("c", 3, &["b"]),
+        ];
+        let error = order_error(&METAS);
+        assert_eq!(error, GraphError::Cycle(vec!["b", "c", "b"]));
+    }
+
+    #[test]
+    fn missing_lists_direct_prerequisites_not_done() {
+        static METAS: [LessonMeta; 3] = [
+            meta("a", 1, &[]),
+            meta("b", 2, &["a"]),
+            meta("c", 3, &["b", "a"]),
+        ];
+        let registry = registry(&METAS);
+        let c = registry.find("c").unwrap();
+        assert_eq!(ids(&missing(&registry, c, |_| false)), ["b", "a"]);
+        assert_eq!(ids(&missing(&registry, c, |l| l.id() == "a")), ["b"]);
+        assert!(missing(&registry, c, |_| true).is_empty());
+    }
+}
@@ -0,0 +1,191 @@
+//! ============================================================================
+//! HIGHLIGHT MODULE - ANSI syntax highlighting for Rust code in the terminal
+//! ============================================================================
+//! Code is split with `lexer::tokenize` and each token is wrapped in an ANSI
+//! color. The text between tokens (whitespace) is copied unchanged, so
+//! removing the escape codes gives back exactly the original code.
+//!
+//! | Class      | Tokens                                | Color          |
+//! |------------|---------------------------------------|----------------|
+//! | Keyword    | `fn`, `let`, `mut`, `match`, `Self`   | Magenta, bold  |
+//! | Macro      | `println!`, `vec!`, `outln!`          | Blue           |
+//! | Type       | Identifiers starting upper case       | Cyan           |
+//! | Lifetime   | `'a`, `'static`                       | Yellow, bold   |
+//! | String     | `"hi"`, `r#"raw"#`, `'x'`             | Green          |
+//! | Number     | `42`, `2.5e-3`                        | Yellow         |
+//! | Comment    | `// note`                             | Gray           |
+//! | DocComment | `/// docs`, `//! docs`                | Green, dim     |
+//! | Error      | Unterminated string or comment        | Red, underline |
+//!
+//! `Colors::for_stdout()` picks `Plain` (the code itself, no escapes) when
+//! stdout is not a terminal, `NO_COLOR` is set or `TERM=dumb`. Colors are
+//! reset before every line break, so each line can be indented or printed on
+//! its own (`indented()`).
+//! ============================================================================
+
+use std::io::IsTerminal;
+
+use crate::lexer::{self, Token, TokenKind};
+
+const RESET: &str = "\x1b[0m";
+
+/// Whether code is printed with ANSI colors
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Colors {
+    Ansi,
+    #[default]
+    Plain,
+}
+
+impl Colors {
+    /// `Ansi` for an interactive terminal, unless the user opted out
+    pub fn for_stdout() -> Colors {
+        let no_color = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+        let dumb = std::env::var("TERM").is_ok_and(|term| term == "dumb");
+        if no_color || dumb || !std::io::stdout().is_terminal() {
+            Colors::Plain
+        } else {
+            Colors::Ansi
+        }
+    }
+}
+
+/// The ANSI color of `tokens[i]`, `None` for uncolored text
+fn color(tokens: &[Token], i: usize) -> Option<&'static str> {
+    let token = &tokens[i];
+    let is_macro_name = |i: usize| {
+        tokens[i].kind == TokenKind::Ident
+            && !tokens[i].is_keyword()
+            && tokens.get(i + 1).is_some_and(|t| t.is("!"))
+            && tokens
+                .get(i + 2)
+                .is_some_and(|t| ["(", "[", "{"].iter().any(|open| t.is(open)))
+    };
+
+    match token.kind {
+        _ if token.is_keyword() => Some("\x1b[1;35m"),
+        TokenKind::Ident if is_macro_name(i) => Some("\x1b[34m"),
+        TokenKind::Punct if token.is("!") && i > 0 && is_macro_name(i - 1) => Some("\x1b[34m"),
+        TokenKind::Ident if token.text.starts_with(char::is_uppercase) => Some("\x1b[36m"),
+        TokenKind::Lifetime => Some("\x1b[1;33m"),
+        TokenKind::Str | TokenKind::Char => Some("\x1b[32m"),
+        TokenKind::Number => Some("\x1b[33m"),
+        TokenKind::Comment => Some("\x1b[90m"),
+        TokenKind::DocComment => Some("\x1b[2;32m"),
+        TokenKind::Error => Some("\x1b[4;31m"),
+        TokenKind::Ident | TokenKind::Punct => None,
+    }
+}
+
+/// `code` with ANSI colors, or unchanged for `Colors::Plain`
+pub fn highlight(code: &str, colors: Colors) -> String {
+    if colors == Colors::Plain {
+        return code.to_string();
+    }
+
+    let tokens = lexer::tokenize(code);
+    let mut text = String::with_capacity(code.len() * 2);
+    let mut end = 0;
+    for (i, token) in tokens.iter().enumerate() {
+        text.push_str(&code[end..token.start]);
+        end = token.start + token.text.len();
+        match color(&tokens, i) {
+            Some(color) => {
+                // One colored run per line, so lines stand on their own
+                let lines: Vec<&str> = token.text.split('\n').collect();
+                for (n, line) in lines.iter().enumerate() {
+                    if n > 0 {
+                        text.push('\n');
+                    }
+                    if !line.is_empty() {
+                        text.push_str(color);
+                        text.push_str(line);
+                        text.push_str(RESET);
+                    }
+                }
+            }
+            None => text.push_str(token.text),
+        }
+    }
+    text.push_str(&code[end..]);
+    text
+}
+
+/// `code` highlighted and indented by four spaces, one `\n` after every
+/// line; blank lines stay empty
+pub fn indented(code: &str, colors: Colors) -> String {
+    highlight(code.trim_end(), colors)
+        .lines()
+        .map(|line| match line {
+            "" => "\n".to_string(),
+            _ => format!("    {}\n", line),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::tests::lesson_files;
+
+    /// `text` without ANSI escape sequences
+    fn strip_ansi(text: &str) -> String {
+        let mut plain = String::new();
+        let mut chars = text.chars();
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                chars.by_ref().find(|&c| c == 'm');
+            } else {
+                plain.push(c);
+            }
+        }
+        plain
+    }
+
+    #[test]
+    fn highlighting_keeps_every_lesson_file_intact() {
+        for (path, source) in lesson_files() {
+            assert_eq!(
+                strip_ansi(&highlight(&source, Colors::Ansi)),
+                source,
+                "{}",
+                path.display()
+            );
+            assert_eq!(highlight(&source, Colors::Plain), source);
+        }
+    }
+
+    #[test]
+    fn tokens_get_their_class_colors() {
+        let code = "let s: String = vec![1]; // note\nfn f<'a>() {}";
+        let colored = highlight(code, Colors::Ansi);
+        for expected in [
+            "\x1b[1;35mlet\x1b[0m",
+            "\x1b[36mString\x1b[0m",
+            "\x1b[34mvec\x1b[0m\x1b[34m!\x1b[0m",
+            "\x1b[33m1\x1b[0m",
+            "\x1b[90m// note\x1b[0m",
+            "\x1b[1;33m'a\x1b[0m",
+        ] {
+            assert!(
+                colored.contains(expected),
+                "{:?} in {:?}",
+                expected,
+                colored
+            );
+        }
+        // `!` as negation is not part of a macro
+        assert!(!highlight("!done", Colors::Ansi).contains("\x1b[34m"));
+    }
+
+    #[test]
+    fn multi_line_tokens_are_colored_line_by_line() {
+        let colored = highlight("let s = \"a\nb\";", Colors::Ansi);
+        assert!(colored.contains("\x1b[32m\"a\x1b[0m\n\x1b[32mb\"\x1b[0m"));
+        let lines = indented("/* one\n\ntwo */", Colors::Ansi);
+        assert_eq!(
+            lines,
+            "    \x1b[90m/* one\x1b[0m\n\n    \x1b[90mtwo */\x1b[0m\n"
+        );
+    }
+}
@@ -0,0 +1,472 @@
+//! ============================================================================
+//! JSON MODULE - Just enough JSON to read rustc / cargo diagnostics
+//! ============================================================================
+//! The crate has no dependencies, so this is a small recursive-descent parser
+//! for RFC 8259 JSON. Values are read-only: there is no serializer.
+//!
+//! | JSON            | `Json` variant                |
+//! |-----------------|-------------------------------|
+//! | `null`          | `Null`                        |
+//! | `true`, `false` | `Bool(bool)`                  |
+//! | `12`, `-1.5e3`  | `Number(f64)`                 |
+//! | `"text"`        | `String(String)`              |
+//! | `[1, 2]`        | `Array(Vec<Json>)`            |
+//! | `{"k": 1}`      | `Object(Vec<(String, Json)>)` |
+//!
+//! Objects keep their keys in document order and `get()` finds the first
+//! match. `\uXXXX` escapes, including surrogate pairs, decode to `char`s.
+//! Arrays and objects nest at most `MAX_DEPTH` deep, so hostile input cannot
+//! overflow the stack.
+//! ============================================================================
+
+use std::fmt;
+
+/// Deepest nesting of arrays and objects that `parse()` accepts
+pub const MAX_DEPTH: usize = 128;
+
+/// A parsed JSON value
+#[derive(Debug, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    /// The value of `key` when `self` is an object
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(members) => members.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> &[Json] {
+        match self {
+            Json::Array(items) => items,
+            _ => &[],
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Json::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// Whole, non-negative numbers only (line and column numbers)
+    pub fn as_usize(&self) -> Option<usize> {
+        match self {
+            Json::Number(n) if *n >= 0.0 && n.fract() == 0.0 => Some(*n as usize),
+            _ => None,
+        }
+    }
+}
+
+/// Where and why parsing failed
+#[derive(Debug, PartialEq)]
+pub struct JsonError {
+    /// Byte offset into the input
+    pub offset: usize,
+    pub message: &'static str,
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid JSON at byte {}: {}", self.offset, self.message)
+    }
+}
+
+impl std::error::Error for JsonError {}
+
+/// Parses one JSON document; only whitespace may follow it
+pub fn parse(text: &str) -> Result<Json, JsonError> {
+    let mut parser = Parser {
+        text,
+        pos: 0,
+        depth: 0,
+    };
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    if parser.pos < text.len() {
+        return Err(parser.error("unexpected text after the value"));
+    }
+    Ok(value)
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+    /// Arrays and objects currently open
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: &'static str) -> JsonError {
+        JsonError {
+            offset: self.pos,
+            message,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8, message: &'static str) -> Result<(), JsonError> {
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(message))
+        }
+    }
+
+    fn literal(&mut self, word: &str, value: Json) -> Result<Json, JsonError> {
+        if self.text[self.pos..].starts_with(word) {
+            self.pos += word.len();
+            Ok(value)
+        } else {
+            Err(self.error("unknown literal"))
+        }
+    }
+
+    fn value(&mut self) -> Result<Json, JsonError> {
+        self.skip_whitespace();
+        match self.peek() {
+            None => Err(self.error("unexpected end of input")),
+            Some(b'n') => self.literal("null", Json::Null),
+            Some(b't') => self.literal("true", Json::Bool(true)),
+            Some(b'f') => self.literal("false", Json::Bool(false)),
+            Some(b'"') => self.string().map(Json::String),
+            Some(b'[' | b'{') => {
+                if self.depth == MAX_DEPTH {
+                    return Err(self.error("nesting too deep"));
+                }
+                self.depth += 1;
+                let value = if self.peek() == Some(b'[') {
+                    self.array()
+                } else {
+                    self.object()
+                };
+                self.depth -= 1;
+                value
+            }
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            Some(_) => Err(self.error("expected a value")),
+        }
+    }
+
+    fn array(&mut self) -> Result<Json, JsonError> {
+        self.pos += 1;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(Json::Array(items));
+        }
+        loop {
+            items.push(self.value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(Json::Array(items));
+                }
+                _ => return Err(self.error("expected ',' or ']'")),
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Json, JsonError> {
+        self.pos += 1;
+        let mut members = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(Json::Object(members));
+        }
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some(b'"') {
+                return Err(self.error("expected a string key"));
+            }
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(b':', "expected ':'")?;
+            members.push((key, self.value()?));
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(Json::Object(members));
+                }
+                _ => return Err(self.error("expected ',' or '}'")),
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<Json, JsonError> {
+        let start = self.pos;
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        let digits = |parser: &mut Parser| {
+            let from = parser.pos;
+            while matches!(parser.peek(), Some(b'0'..=b'9')) {
+                parser.pos += 1;
+            }
+            parser.pos > from
+        };
+        if !digits(self) {
+            return Err(self.error("expected digits"));
+        }
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            if !digits(self) {
+                return Err(self.error("expected digits after '.'"));
+            }
+        }
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            self.pos += 1;
+            if matches!(self.peek(), Some(b'+' | b'-')) {
+                self.pos += 1;
+            }
+            if !digits(self) {
+                return Err(self.error("expected exponent digits"));
+            }
+        }
+        self.text[start..self.pos]
+            .parse()
+            .map(Json::Number)
+            .map_err(|_| self.error("invalid number"))
+    }
+
+    /// Four hex digits of a `\u` escape
+    fn hex4(&mut self) -> Result<u32, JsonError> {
+        let hex = self
+            .text
+            .as_bytes()
+            .get(self.pos..self.pos + 4)
+            .ok_or_else(|| self.error("short \\u escape"))?;
+        if !hex.iter().all(u8::is_ascii_hexdigit) {
+            return Err(self.error("invalid \\u escape"));
+        }
+        let value =
+            u32::from_str_radix(&self.text[self.pos..self.pos + 4], 16).expect("four hex digits");
+        self.pos += 4;
+        Ok(value)
+    }
+
+    /// The `\uDC00`-`\uDFFF` escape completing the pair after a high surrogate;
+    /// anything else is left unread
+    fn low_surrogate(&mut self, high: u32) -> Result<Option<u32>, JsonError> {
+        if !(0xD800..0xDC00).contains(&high) || !self.text[self.pos..].starts_with("\\u") {
+            return Ok(None);
+        }
+        let start = self.pos;
+        self.pos += 2;
+        let low = self.hex4()?;
+        if (0xDC00..0xE000).contains(&low) {
+            Ok(Some(low))
+        } else {
+            self.pos = start;
+            Ok(None)
+        }
+    }
+
+    fn string(&mut self) -> Result<String, JsonError> {
+        self.pos += 1;
+        let mut result = String::new();
+        loop {
+            let rest = &self.text[self.pos..];
+            // Copy the run of plain characters in one go
+            let plain = rest
+                .find(|c: char| c == '"' || c == '\\' || c.is_control())
+                .ok_or_else(|| self.error("unterminated string"))?;
+            result.push_str(&rest[..plain]);
+            self.pos += plain;
+
+            match self.peek() {
+                Some(b'"') => {
+                    self.pos += 1;
+                    return Ok(result);
+                }
+                Some(b'\\') => {
+                    self.pos += 1;
+                    let escape = self
+                        .peek()
+                        .ok_or_else(|| self.error("unterminated string"))?;
+                    self.pos += 1;
+                    let c = match escape {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => {
+                            let high = self.hex4()?;
+                            let low = self.low_surrogate(high)?;
+                            let code = match low {
+                                Some(low) => 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00),
+                                None => high,
+                            };
+                            // A lone surrogate is not a `char`
+                            char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER)
+                        }
+                        _ => return Err(self.error("unknown escape")),
+                    };
+                    result.push(c);
+                }
+                _ => return Err(self.error("control character in string")),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn string(text: &str) -> Result<String, JsonError> {
+        parse(text).map(|value| value.as_str().expect("a string").to_string())
+    }
+
+    fn error(text: &str) -> &'static str {
+        parse(text).unwrap_err().message
+    }
+
+    #[test]
+    fn parses_every_kind_of_value() {
+        let value = parse(r#" {"a": [null, true, false], "b": {"c": "d"}, "a": 1} "#).unwrap();
+        assert_eq!(
+            value.get("a"),
+            Some(&Json::Array(vec![
+                Json::Null,
+                Json::Bool(true),
+                Json::Bool(false)
+            ]))
+        );
+        assert_eq!(
+            value
+                .get("b")
+                .and_then(|b| b.get("c"))
+                .and_then(Json::as_str),
+            Some("d")
+        );
+        assert_eq!(value.get("missing"), None);
+        assert_eq!(parse("[]").unwrap(), Json::Array(Vec::new()));
+        assert_eq!(parse("{ }").unwrap(), Json::Object(Vec::new()));
+    }
+
+    #[test]
+    fn numbers() {
+        for (text, number) in [
+            ("0", 0.0),
+            ("-12", -12.0),
+            ("1.5", 1.5),
+            ("-1.5e3", -1500.0),
+            ("2E-2", 0.02),
+        ] {
+            assert_eq!(parse(text), Ok(Json::Number(number)), "{}", text);
+        }
+        assert_eq!(parse("7").unwrap().as_usize(), Some(7));
+        assert_eq!(parse("7.5").unwrap().as_usize(), None);
+        assert_eq!(parse("-7").unwrap().as_usize(), None);
+        assert_eq!(error("-"), "expected digits");
+        assert_eq!(error("1."), "expected digits after '.'");
+        assert_eq!(error("1e+"), "expected exponent digits");
+    }
+
+    #[test]
+    fn string_escapes() {
+        assert_eq!(
+            string(r#""a\"b\\c\/d\b\f\n\r\t""#).unwrap(),
+            "a\"b\\c/d\u{8}\u{c}\n\r\t"
+        );
+        assert_eq!(string(r#""\u00e9\u4E2D""#).unwrap(), "é中");
+        assert_eq!(string("\"plain ✅ text\"").unwrap(), "plain ✅ text");
+        assert_eq!(error(r#""\x""#), "unknown escape");
+        assert_eq!(error("\"tab\there\""), "control character in string");
+        assert_eq!(error(r#""open"#), "unterminated string");
+    }
+
+    #[test]
+    fn u_escapes_need_four_hex_digits() {
+        assert_eq!(error(r#"["\u12", 1]"#), "invalid \\u escape");
+        assert_eq!(error(r#""\u+1AB""#), "invalid \\u escape");
+        assert_eq!(error(r#""\u 1AB""#), "invalid \\u escape");
+        assert_eq!(error(r#""\u12"#), "short \\u escape");
+        assert_eq!(error("\"\\u00é\""), "invalid \\u escape");
+    }
+
+    #[test]
+    fn surrogate_pairs() {
+        assert_eq!(string(r#""\ud83e\udd80""#).unwrap(), "🦀");
+        // Lone surrogates become U+FFFD; the character after them is kept
+        assert_eq!(string(r#""\ud83e!""#).unwrap(), "\u{FFFD}!");
+        assert_eq!(string(r#""\udd80""#).unwrap(), "\u{FFFD}");
+        assert_eq!(string(r#""\ud83e\u0041""#).unwrap(), "\u{FFFD}A");
+    }
+
+    #[test]
+    fn only_whitespace_may_follow_the_value() {
+        assert!(parse("[1]\n\t ").is_ok());
+        assert_eq!(
+            parse("[1] x"),
+            Err(JsonError {
+                offset: 4,
+                message: "unexpected text after the value"
+            })
+        );
+        assert_eq!(error("{} {}"), "unexpected text after the value");
+        assert_eq!(error("nul"), "unknown literal");
+        assert_eq!(error(""), "unexpected end of input");
+        assert_eq!(error("[1 2]"), "expected ',' or ']'");
+        assert_eq!(error(r#"{"a" 1}"#), "expected ':'");
+        assert_eq!(error("{1: 2}"), "expected a string key");
+    }
+
+    #[test]
+    fn nesting_is_limited() {
+        let nested = |depth| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(parse(&nested(MAX_DEPTH)).is_ok());
+        assert_eq!(
+            parse(&nested(MAX_DEPTH + 1)),
+            Err(JsonError {
+                offset: MAX_DEPTH,
+                message: "nesting too deep"
+            })
+        );
+        let objects = format!(
+            "{}1{}",
+            r#"{"a":"#.repeat(MAX_DEPTH + 1),
+            "}".repeat(MAX_DEPTH + 1)
+        );
+        assert_eq!(error(&objects), "nesting too deep");
+        // Far too deep to recurse into: still an error, not a stack overflow
+        assert_eq!(error(&"[".repeat(1_000_000)), "nesting too deep");
+    }
+}
@@ -2,6 +2,10 @@
 /// ARRAYS MODULE - Arrays & Indexing
 /// ============================================================================
 
+use super::{Lesson, LessonMeta, Phase, Registry};
+use crate::output::{Output, outln};
+use crate::quiz::{Answer, Question};
+
 /// ============================================================================
 /// 5. ARRAYS & INDEXING
 /// ============================================================================
@@ -12,36 +16,90 @@
 /// - `for n in array` → Iterate by value (moves for non-Copy)
 /// - `for n in array.iter()` → Iterate by reference (borrows)
 /// ============================================================================
-pub fn learn_arrays_and_indexing() {
-    println!("\n============================================================");
-    println!("📘 LESSON 5: Arrays & Indexing");
-    println!("============================================================\n");
-
+pub fn learn_arrays_and_indexing(out: &mut dyn Output) {
     let numbers = [10, 20, 30, 40, 50];
 
     // Direct indexing
-    println!("--- Direct Indexing ---");
-    println!("First number: {}", numbers[0]);
-    println!("Second number: {}", numbers[1]);
-    println!("Third number: {}", numbers[2]);
-    println!("Fourth number: {}", numbers[3]);
-    println!("Fifth number: {}", numbers[4]);
+    out.section("Direct Indexing");
+    outln!(out, "First number: {}", numbers[0]);
+    outln!(out, "Second number: {}", numbers[1]);
+    outln!(out, "Third number: {}", numbers[2]);
+    outln!(out, "Fourth number: {}", numbers[3]);
+    outln!(out, "Fifth number: {}", numbers[4]);
 
     // Iterating by value (works for Copy types like i32)
-    println!("\n--- Iterating by Value ---");
+    out.section("Iterating by Value");
     for n in numbers {
-        println!("number = {}", n);
+        outln!(out, "number = {}", n);
     }
 
     // Copying an element and modifying the copy
     let mut m = numbers[0];
     m = m + 1;
-    println!("\nCopied and modified: m = {}", m);
-    println!("Original unchanged: numbers[0] = {}", numbers[0]);
+    outln!(out, "\nCopied and modified: m = {}", m);
+    outln!(out, "Original unchanged: numbers[0] = {}", numbers[0]);
 
     // Iterating by reference using .iter()
-    println!("\n--- Iterating by Reference (.iter()) ---");
+    out.section("Iterating by Reference (.iter())");
     for k in numbers.iter() {
-        println!("k = {}", k);
+        outln!(out, "k = {}", k);
+    }
+}
+
+/// ============================================================================
+/// QUIZ QUESTIONS
+/// ============================================================================
+const ARRAYS_QUIZ: &[Question] = &[
+    Question {
+        prompt: "With `let numbers = [10, 20, 30, 40, 50];`, what is `numbers[1]`?",
+        answer: Answer::Text(&["20"]),
+        explanation: "Arrays are zero-indexed: index 0 is the first element.",
+        section: Some("Direct Indexing"),
+    },
+    Question {
+        prompt: "After `let mut m = numbers[0]; m = m + 1;`, what is `numbers[0]`?",
+        answer: Answer::Choice {
+            options: &["10", "11", "0", "It does not compile"],
+            correct: 0,
+        },
+        explanation: "`m` is a copy of the element; changing it leaves the array alone.",
+        section: Some("Iterating by Value"),
+    },
+    Question {
+        prompt: "An array's length can grow with `.push()`.",
+        answer: Answer::TrueFalse(false),
+        explanation: "Arrays have a fixed size known at compile time; use `Vec<T>` to grow.",
+        section: None,
+    },
+];
+
+/// ============================================================================
+/// LESSON REGISTRATION
+/// ============================================================================
+pub fn register(registry: &mut Registry) {
+    registry.register(ArraysLesson);
+}
+
+pub struct ArraysLesson;
+
+impl Lesson for ArraysLesson {
+    fn meta(&self) -> &'static LessonMeta {
+        &LessonMeta {
+            id: "arrays",
+            number: 5,
+            title: "Arrays & Indexing",
+            phase: Phase::DataStructures,
+            module: "arrays",
+            entry: "learn_arrays_and_indexing",
+            requires: &["loops"],
+        }
+    }
+
+    fn run(&self, out: &mut dyn Output) {
+        learn_arrays_and_indexing(out);
+    }
+
+    fn questions(&self) -> &'static [Question] {
+        ARRAYS_QUIZ
     }
 }
@@ -2,6 +2,10 @@
 /// BASICS MODULE - Variables, Arithmetic, Conditionals, Loops
 /// ============================================================================
 
+use super::{Lesson, LessonMeta, Phase, Registry};
+use crate::output::{Output, outln};
+use crate::quiz::{Answer, Question};
+
 /// ============================================================================
 /// 1. VARIABLES & MUTABILITY
 /// ============================================================================
@@ -12,32 +16,28 @@
 /// - `{}`              → Placeholder in formatted output
 /// - `println!`        → Macro (not a function), indicated by `!`
 /// ============================================================================
-pub fn learn_variables_and_mutability() {
-    println!("\n============================================================");
-    println!("📘 LESSON 1: Variables & Mutability");
-    println!("============================================================\n");
-
+pub fn learn_variables_and_mutability(out: &mut dyn Output) {
     // Immutable variable - integers are Copy types
     // You cannot change the value of x because it is immutable
     let x = 5;
     // x += 3; // ❌ This will cause a compile-time error
-    println!("Immutable x = {}", x);
+    outln!(out, "Immutable x = {}", x);
 
     // Mutable variable - you can change the value
     let mut y = 10;
-    println!("Mutable y (before) = {}", y);
+    outln!(out, "Mutable y (before) = {}", y);
     y += 3;
-    println!("Mutable y (after += 3) = {}", y);
+    outln!(out, "Mutable y (after += 3) = {}", y);
 
     // Explicit type annotation
     let z: i32 = 20;
-    println!("Explicitly typed z: i32 = {}", z);
+    outln!(out, "Explicitly typed z: i32 = {}", z);
 
     // Practical example: temperature that changes
     let mut temperature = 30;
-    println!("Temperature is {} degrees Celsius.", temperature);
+    outln!(out, "Temperature is {} degrees Celsius.", temperature);
     temperature += 5;
-    println!("Temperature is now {} degrees Celsius.", temperature);
+    outln!(out, "Temperature is now {} degrees Celsius.", temperature);
 }
 
 /// ============================================================================
@@ -48,11 +48,7 @@ pub fn learn_variables_and_mutability() {
 /// - Arithmetic operations create new values with new owners
 /// - For Copy types (integers), the original values remain valid
 /// ============================================================================
-pub fn learn_arithmetic_operations() {
-    println!("\n============================================================");
-    println!("📘 LESSON 2: Arithmetic Operations & Ownership Intro");
-    println!("============================================================\n");
-
+pub fn learn_arithmetic_operations(out: &mut dyn Output) {
     // Owner is the variable that holds the value
     let a = 10;  // a owns the value 10
     let b = 3;   // b owns the value 3
@@ -65,12 +61,12 @@ pub fn learn_arithmetic_operations() {
     let div = a / b;        // div owns the result (integer division)
     let remainder = a % b;  // remainder owns the result
 
-    println!("a = {}, b = {}", a, b);
-    println!("sum (a + b) = {}", sum);
-    println!("difference (a - b) = {}", diff);
-    println!("product (a * b) = {}", prod);
-    println!("division (a / b) = {}", div);
-    println!("remainder (a % b) = {}", remainder);
+    outln!(out, "a = {}, b = {}", a, b);
+    outln!(out, "sum (a + b) = {}", sum);
+    outln!(out, "difference (a - b) = {}", diff);
+    outln!(out, "product (a * b) = {}", prod);
+    outln!(out, "division (a / b) = {}", div);
+    outln!(out, "remainder (a % b) = {}", remainder);
 }
 
 /// ============================================================================
@@ -81,20 +77,16 @@ pub fn learn_arithmetic_operations() {
 /// - Conditions must be boolean expressions
 /// - No parentheses required around conditions (unlike C/Java)
 /// ============================================================================
-pub fn learn_conditionals() {
-    println!("\n============================================================");
-    println!("📘 LESSON 3: Conditional Expressions");
-    println!("============================================================\n");
-
+pub fn learn_conditionals(out: &mut dyn Output) {
     let temperature = 40;
-    println!("Temperature is {} degrees", temperature);
+    outln!(out, "Temperature is {} degrees", temperature);
 
     if temperature > 30 {
-        println!("It's a hot day! 🔥");
+        outln!(out, "It's a hot day! 🔥");
     } else if temperature < 15 {
-        println!("It's a cold day! ❄️");
+        outln!(out, "It's a cold day! ❄️");
     } else {
-        println!("The weather is nice. 🌤️");
+        outln!(out, "The weather is nice. 🌤️");
     }
 }
 
@@ -108,43 +100,240 @@ pub fn learn_conditionals() {
 /// - `1..5`  → Range from 1 to 4 (exclusive end)
 /// - `1..=5` → Range from 1 to 5 (inclusive end)
 /// ============================================================================
-pub fn learn_loops() {
-    println!("\n============================================================");
-    println!("📘 LESSON 4: Loops (while, loop, for)");
-    println!("============================================================\n");
-
+pub fn learn_loops(out: &mut dyn Output) {
     // While loop - runs while condition is true
-    println!("--- While Loop ---");
+    out.section("While Loop");
     let mut count = 0;
     while count < 5 {
-        println!("Count is: {}", count);
+        outln!(out, "Count is: {}", count);
         count += 1;
     }
 
     // Infinite loop with break
-    println!("\n--- Infinite Loop with Break ---");
+    out.section("Infinite Loop with Break");
     let mut infinite_loop = 0;
     loop {
         if infinite_loop >= 2 {
-            println!("Breaking the loop now.");
+            outln!(out, "Breaking the loop now.");
             break;
         } else {
-            println!("This will run forever unless we break.");
+            outln!(out, "This will run forever unless we break.");
             infinite_loop += 1;
         }
     }
 
     // For loop with inclusive range (1..=6 means 1 to 6)
-    println!("\n--- For Loop (inclusive range 1..=6) ---");
+    out.section("For Loop (inclusive range 1..=6)");
     for i in 1..=6 {
-        println!("i = {}", i);
+        outln!(out, "i = {}", i);
     }
 
     // For loop with exclusive range and condition
-    println!("\n--- For Loop (even numbers in 1..10) ---");
+    out.section("For Loop (even numbers in 1..10)");
     for i in 1..10 {
         if i % 2 == 0 {
-            println!("even i = {}", i);
+            outln!(out, "even i = {}", i);
+        }
+    }
+}
+
+/// ============================================================================
+/// QUIZ QUESTIONS
+/// ============================================================================
+const VARIABLES_QUIZ: &[Question] = &[
+    Question {
+        prompt: "What happens when you compile `let x = 5; x += 3;`?",
+        answer: Answer::Choice {
+            options: &[
+                "x becomes 8",
+                "Compile error: cannot assign twice to immutable variable",
+                "x stays 5 and the line is ignored",
+                "It panics at runtime",
+            ],
+            correct: 1,
+        },
+        explanation: "Variables are immutable by default; declare `let mut x` to change it.",
+        section: None,
+    },
+    Question {
+        prompt: "Which keyword makes a variable changeable?",
+        answer: Answer::Text(&["mut", "let mut"]),
+        explanation: "`let mut y = 10;` declares a mutable variable.",
+        section: None,
+    },
+    Question {
+        prompt: "`println!` is a function.",
+        answer: Answer::TrueFalse(false),
+        explanation: "The `!` marks a macro invocation, not a function call.",
+        section: None,
+    },
+];
+
+const ARITHMETIC_QUIZ: &[Question] = &[
+    Question {
+        prompt: "With `let a = 10; let b = 3;`, what is `a / b`?",
+        answer: Answer::Text(&["3"]),
+        explanation: "Integer division truncates toward zero: 10 / 3 == 3.",
+        section: None,
+    },
+    Question {
+        prompt: "With `let a = 10; let b = 3;`, what is `a % b`?",
+        answer: Answer::Text(&["1"]),
+        explanation: "`%` is the remainder: 10 == 3 * 3 + 1.",
+        section: None,
+    },
+    Question {
+        prompt: "After `let sum = a + b;` with integers, `a` can no longer be used.",
+        answer: Answer::TrueFalse(false),
+        explanation: "Integers are Copy types, so `a` and `b` stay valid after the addition.",
+        section: None,
+    },
+];
+
+const CONDITIONALS_QUIZ: &[Question] = &[
+    Question {
+        prompt: "Does `if temperature { ... }` compile when `temperature` is an i32?",
+        answer: Answer::TrueFalse(false),
+        explanation: "Conditions must be `bool`; Rust never converts numbers to booleans.",
+        section: None,
+    },
+    Question {
+        prompt: "With `temperature = 40`, which branch runs?\n  if temperature > 30 { hot } else if temperature < 15 { cold } else { nice }",
+        answer: Answer::Choice {
+            options: &["hot", "cold", "nice", "hot and nice"],
+            correct: 0,
+        },
+        explanation: "Only the first branch whose condition is true runs.",
+        section: None,
+    },
+];
+
+const LOOPS_QUIZ: &[Question] = &[
+    Question {
+        prompt: "How many times does `for i in 1..5` run its body?",
+        answer: Answer::Text(&["4", "four"]),
+        explanation: "`1..5` excludes its end: 1, 2, 3, 4.",
+        section: Some("For Loop (even numbers in 1..10)"),
+    },
+    Question {
+        prompt: "Which range includes both 1 and 6?",
+        answer: Answer::Choice {
+            options: &["1..6", "1..=6", "0..6", "1..7 excluding 7 and 1"],
+            correct: 1,
+        },
+        explanation: "`..=` makes the end inclusive.",
+        section: Some("For Loop (inclusive range 1..=6)"),
+    },
+    Question {
+        prompt: "Which keyword leaves a `loop { }`?",
+        answer: Answer::Text(&["break"]),
+        explanation: "`loop` repeats forever until `break` (or `return`) exits it.",
+        section: Some("Infinite Loop with Break"),
+    },
+];
+
+/// ============================================================================
+/// LESSON REGISTRATION
+/// ============================================================================
+pub fn register(registry: &mut Registry) {
+    registry.register(VariablesLesson);
+    registry.register(ArithmeticLesson);
+    registry.register(ConditionalsLesson);
+    registry.register(LoopsLesson);
+}
+
+pub struct VariablesLesson;
+
+impl Lesson for VariablesLesson {
+    fn meta(&self) -> &'static LessonMeta {
+        &LessonMeta {
+            id: "variables",
+            number: 1,
+            title: "Variables & Mutability",
+            phase: Phase::Basics,
+            module: "basics",
+            entry: "learn_variables_and_mutability",
+            requires: &[],
         }
     }
+
+    fn run(&self, out: &mut dyn Output) {
+        learn_variables_and_mutability(out);
+    }
+
+    fn questions(&self) -> &'static [Question] {
+        VARIABLES_QUIZ
+    }
+}
+
+pub struct ArithmeticLesson;
+
+impl Lesson for ArithmeticLesson {
+    fn meta(&self) -> &'static LessonMeta {
+        &LessonMeta {
+            id: "arithmetic",
+            number: 2,
+            title: "Arithmetic Operations & Ownership Intro",
+            phase: Phase::Basics,
+            module: "basics",
+            entry: "learn_arithmetic_operations",
+            requires: &["variables"],
+        }
+    }
+
+    fn run(&self, out: &mut dyn Output) {
+        learn_arithmetic_operations(out);
+    }
+
+    fn questions(&self) -> &'static [Question] {
+        ARITHMETIC_QUIZ
+    }
+}
+
+pub struct ConditionalsLesson;
+
+impl Lesson for ConditionalsLesson {
+    fn meta(&self) -> &'static LessonMeta {
+        &LessonMeta {
+            id: "conditionals",
+            number: 3,
+            title: "Conditional Expressions",
+            phase: Phase::Basics,
+            module: "basics",
+            entry: "learn_conditionals",
+            requires: &["variables"],
+        }
+    }
+
+    fn run(&self, out: &mut dyn Output) {
+        learn_conditionals(out);
+    }
+
+    fn questions(&self) -> &'static [Question] {
+        CONDITIONALS_QUIZ
+    }
+}
+
+pub struct LoopsLesson;
+
+impl Lesson for LoopsLesson {
+    fn meta(&self) -> &'static LessonMeta {
+        &LessonMeta {
+            id: "loops",
+            number: 4,
+            title: "Loops (while, loop, for)",
+            phase: Phase::Basics,
+            module: "basics",
+            entry: "learn_loops",
+            requires: &["conditionals"],
+        }
+    }
+
+    fn run(&self, out: &mut dyn Output) {
+        learn_loops(out);
+    }
+
+    fn questions(&self) -> &'static [Question] {
+        LOOPS_QUIZ
+    }
 }
@@ -2,6 +2,10 @@
 /// BORROWING MODULE - Borrowing Basics & Borrowing with Functions
 /// ============================================================================
 
+use super::{Lesson, LessonMeta, Phase, Registry};
+use crate::output::{Output, outln};
+use crate::quiz::{Answer, Question};
+
 /// ============================================================================
 /// 7. BORROWING BASICS
 /// ============================================================================
@@ -18,54 +22,50 @@
 /// - Mutable borrow = friend who edits it
 /// - Rust ensures no friend can edit while others read
 ///
-/// | Active borrows   | Can owner read? | Can owner write? |
-/// |------------------|-----------------|------------------|
-/// | None             | ✅              | ✅               |
-/// | One or more &T   | ✅              | ❌               |
-/// | One &mut T       | ❌              | ❌               |
+/// | Active borrows | Can owner read? | Can owner write? |
+/// |----------------|-----------------|------------------|
+/// | None           | ✅              | ✅               |
+/// | One or more &T | ✅              | ❌               |
+/// | One &mut T     | ❌              | ❌               |
 /// ============================================================================
-pub fn learn_borrowing() {
-    println!("\n============================================================");
-    println!("📘 LESSON 7: Borrowing Basics");
-    println!("============================================================\n");
-
+pub fn learn_borrowing(out: &mut dyn Output) {
     // Immutable borrowing: multiple references allowed
-    println!("--- Immutable Borrowing ---");
+    out.section("Immutable Borrowing");
     let original = String::from("Hello, Rust!");
     let borrow1 = &original; // First immutable borrow
     let borrow2 = &original; // Second immutable borrow - OK!
-    println!("original = {}", original);
-    println!("borrow1 = {}", borrow1);
-    println!("borrow2 = {}", borrow2);
+    outln!(out, "original = {}", original);
+    outln!(out, "borrow1 = {}", borrow1);
+    outln!(out, "borrow2 = {}", borrow2);
 
     // Mutable borrowing: only one mutable reference at a time
-    println!("\n--- Mutable Borrowing ---");
+    out.section("Mutable Borrowing");
     let mut mutable_string = String::from("Hello");
     let mutable_borrow = &mut mutable_string;
     mutable_borrow.push_str(", world!");
-    println!("After first mutable borrow: {}", mutable_string);
+    outln!(out, "After first mutable borrow: {}", mutable_string);
 
     // Second mutable borrow works because first is no longer used (NLL)
     let mutable_borrow2 = &mut mutable_string;
     mutable_borrow2.push_str(" Welcome to Rust!");
-    println!("After second mutable borrow: {}", mutable_string);
-    // println!("{}", mutable_borrow); // ❌ Error: first borrow invalidated
-    println!("Owner still valid: {}", mutable_string);
+    outln!(out, "After second mutable borrow: {}", mutable_string);
+    // outln!(out, "{}", mutable_borrow); // ❌ Error: first borrow invalidated
+    outln!(out, "Owner still valid: {}", mutable_string);
 
     // Mutable borrow of array
-    println!("\n--- Mutable Borrow of Array ---");
+    out.section("Mutable Borrow of Array");
     let mut arr = [1, 2, 3, 4, 5];
     let arr_borrow = &mut arr;
     for i in 0..arr_borrow.len() {
         arr_borrow[i] += 10;
     }
-    println!("Modified array: {:?}", arr);
+    outln!(out, "Modified array: {:?}", arr);
 
     // Direct mutation by owner
-    println!("\n--- Owner Direct Mutation ---");
+    out.section("Owner Direct Mutation");
     let mut arr_test = [12, 23, 34, 45, 56];
     arr_test[0] += 1; // Valid: owner can modify when no active borrows
-    println!("arr_test[0] = {}", arr_test[0]);
+    outln!(out, "arr_test[0] = {}", arr_test[0]);
 }
 
 /// ============================================================================
@@ -76,37 +76,147 @@ pub fn learn_borrowing() {
 /// - Pass &mut T for modifying data without taking ownership
 /// - Function can borrow multiple times (non-overlapping lifetimes)
 /// ============================================================================
-pub fn learn_borrowing_with_functions() {
-    println!("\n============================================================");
-    println!("📘 LESSON 9: Borrowing with Functions");
-    println!("============================================================\n");
-
+pub fn learn_borrowing_with_functions(out: &mut dyn Output) {
     // Immutable borrow: function reads but doesn't take ownership
-    println!("--- Immutable Borrow in Function ---");
+    out.section("Immutable Borrow in Function");
     let original = String::from("Hello, Rust!");
-    print_length(&original); // Pass reference, not ownership
-    println!("Original still valid: {}", original);
+    print_length(out, &original); // Pass reference, not ownership
+    outln!(out, "Original still valid: {}", original);
 
     // Mutable borrow: function modifies without taking ownership
-    println!("\n--- Mutable Borrow in Function ---");
+    out.section("Mutable Borrow in Function");
     let mut s2 = String::from("Hi");
     append_world(&mut s2);
-    println!("After first append: {}", s2);
+    outln!(out, "After first append: {}", s2);
 
     append_world(&mut s2); // Can borrow again (previous borrow ended)
-    println!("After second append: {}", s2);
+    outln!(out, "After second append: {}", s2);
 
     // Creating another mutable reference
     let second_borrow = &mut s2;
-    println!("Through second_borrow: {}", second_borrow);
+    outln!(out, "Through second_borrow: {}", second_borrow);
 }
 
 /// Prints the length of a string (immutable borrow - read only)
-fn print_length(s: &String) {
-    println!("Length = {}", s.len());
+fn print_length(out: &mut dyn Output, s: &String) {
+    outln!(out, "Length = {}", s.len());
 }
 
 /// Appends ", world!" to a string (mutable borrow - can modify)
 fn append_world(s: &mut String) {
     s.push_str(", world!");
 }
+
+/// ============================================================================
+/// QUIZ QUESTIONS
+/// ============================================================================
+const BORROWING_QUIZ: &[Question] = &[
+    Question {
+        prompt: "Which of these compile?\n  A: let r1 = &s; let r2 = &s; println!(\"{} {}\", r1, r2);\n  B: let r1 = &mut s; let r2 = &mut s; println!(\"{} {}\", r1, r2);",
+        answer: Answer::Choice {
+            options: &["Only A", "Only B", "Both", "Neither"],
+            correct: 0,
+        },
+        explanation: "Any number of &T may coexist, but only one &mut T may be in use at a time.",
+        section: Some("Immutable Borrowing"),
+    },
+    Question {
+        prompt: "While an immutable borrow `&v` is still in use, can the owner write to `v`?",
+        answer: Answer::TrueFalse(false),
+        explanation: "With one or more &T active the owner may read, but not write.",
+        section: Some("Owner Direct Mutation"),
+    },
+    Question {
+        prompt: "Why may `mutable_borrow2 = &mut s` follow `mutable_borrow = &mut s` in the same function?",
+        answer: Answer::Choice {
+            options: &[
+                "The first borrow is no longer used (non-lexical lifetimes)",
+                "Strings allow two mutable borrows",
+                "The second borrow copies the String",
+                "It only compiles in release mode",
+            ],
+            correct: 0,
+        },
+        explanation: "A borrow ends at its last use, so the two mutable borrows never overlap.",
+        section: Some("Mutable Borrowing"),
+    },
+];
+
+const BORROWING_FUNCTIONS_QUIZ: &[Question] = &[
+    Question {
+        prompt: "Which call lets `append_world` change `s2`?",
+        answer: Answer::Choice {
+            options: &[
+                "append_world(s2)",
+                "append_world(&s2)",
+                "append_world(&mut s2)",
+                "append_world(*s2)",
+            ],
+            correct: 2,
+        },
+        explanation: "The function takes `&mut String`, so the caller passes `&mut s2` (and `s2` must be `mut`).",
+        section: Some("Mutable Borrow in Function"),
+    },
+    Question {
+        prompt: "After `print_length(&original)`, `original` is still valid.",
+        answer: Answer::TrueFalse(true),
+        explanation: "Passing `&original` lends the String; ownership never leaves the caller.",
+        section: Some("Immutable Borrow in Function"),
+    },
+];
+
+/// ============================================================================
+/// LESSON REGISTRATION
+/// ============================================================================
+pub fn register(registry: &mut Registry) {
+    registry.register(BorrowingLesson);
+    registry.register(BorrowingFunctionsLesson);
+}
+
+pub struct BorrowingLesson;
+
+impl Lesson for BorrowingLesson {
+    fn meta(&self) -> &'static LessonMeta {
+        &LessonMeta {
+            id: "borrowing",
+            number: 7,
+            title: "Borrowing Basics",
+            phase: Phase::OwnershipAndBorrowing,
+            module: "borrowing",
+            entry: "learn_borrowing",
+            requires: &["ownership", "arrays"],
+        }
+    }
+
+    fn run(&self, out: &mut dyn Output) {
+        learn_borrowing(out);
+    }
+
+    fn questions(&self) -> &'static [Question] {
+        BORROWING_QUIZ
+    }
+}
+
+pub struct BorrowingFunctionsLesson;
+
+impl Lesson for BorrowingFunctionsLesson {
+    fn meta(&self) -> &'static LessonMeta {
+        &LessonMeta {
+            id: "borrowing-functions",
+            number: 9,
+            title: "Borrowing with Functions",
+            phase: Phase::OwnershipAndBorrowing,
+            module: "borrowing",
+            entry: "learn_borrowing_with_functions",
+            requires: &["borrowing", "functions-ownership"],
+        }
+    }
+
+    fn run(&self, out: &mut dyn Output) {
+        learn_borrowing_with_functions(out);
+    }
+
+    fn questions(&self) -> &'static [Question] {
+        BORROWING_FUNCTIONS_QUIZ
+    }
+}
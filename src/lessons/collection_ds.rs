@@ -1,6 +1,18 @@
-//. rust collection and data structures lesson
 /// ============================================================================
-/// 10. COLLECTIONS AND DATA STRUCTURES
+/// COLLECTIONS MODULE - Collections & Data Structures
+/// ============================================================================
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::{BTreeMap, BTreeSet};
+use std::collections::VecDeque;
+
+use super::{Lesson, LessonMeta, Phase, Registry};
+use crate::output::{Output, outln};
+use crate::quiz::{Answer, Question};
+
+/// ============================================================================
+/// 16. COLLECTIONS AND DATA STRUCTURES
 /// ============================================================================
 /// Key Concepts:
 /// - Common collections: Vec, String, HashMap
@@ -9,7 +21,7 @@
 /// ============================================================================
 /// This lesson covers common collections in Rust, including vectors, strings, and hash maps.
 /// It demonstrates how to use these collections while adhering to Rust's ownership and borrowing rules.
-///     
+///
 /// Examples:
 /// - Creating and modifying a vector
 /// - Creating and modifying a string
@@ -17,70 +29,63 @@
 /// - Iterating over collections
 /// ============================================================================
 /// Mental Note
-/// | Collection   | Mutable? | Fixed Size? | Heap/Stack | Use Case           |
-/// |--------------|----------|-------------|------------|--------------------|
-/// | `[T; N]`     | Optional | Yes         | Stack      | small, fixed data  |
-/// | `Vec<T>`     | Yes      | No          | Heap       | growable arrays    |
-/// | `&[T]`       | Optional | No          | Borrow     | partial view       |
-/// | `String`     | Yes      | No          | Heap       | mutable text       |
-/// | `&str`       | No       | No          | Borrow     | immutable view     |
-/// | `HashMap<K,V>` | Yes    | No          | Heap       | key-value store    |
-/// | `HashSet<T>` | Yes      | No          | Heap       | unique items       |
-/// | `VecDeque<T>`| Yes      | No          | Heap       | queue operations   |
-/// | `(T1, T2)`   | Optional | Yes         | Stack      | multi-value returns|
-/// 
-/// 
-use std::collections::HashMap;
-use std::collections::HashSet;
-
-use std::collections::VecDeque;
-
-pub fn learn_collections_and_data_structures() {
-    println!("\n============================================================");
-    println!("📘 LESSON 10: Collections and Data Structures");
-    println!("============================================================\n");
-
+/// | Collection     | Mutable? | Fixed Size? | Heap/Stack | Use Case            |
+/// |----------------|----------|-------------|------------|---------------------|
+/// | `[T; N]`       | Optional | Yes         | Stack      | small, fixed data   |
+/// | `Vec<T>`       | Yes      | No          | Heap       | growable arrays     |
+/// | `&[T]`         | Optional | No          | Borrow     | partial view        |
+/// | `String`       | Yes      | No          | Heap       | mutable text        |
+/// | `&str`         | No       | No          | Borrow     | immutable view      |
+/// | `HashMap<K,V>` | Yes      | No          | Heap       | key-value store     |
+/// | `HashSet<T>`   | Yes      | No          | Heap       | unique items        |
+/// | `VecDeque<T>`  | Yes      | No          | Heap       | queue operations    |
+/// | `(T1, T2)`     | Optional | Yes         | Stack      | multi-value returns |
+/// ============================================================================
+pub fn learn_collections_and_data_structures(out: &mut dyn Output) {
     // Vector example
-    println!("--- Vector Example ---");
+    out.section("Vector Example");
     let mut numbers: Vec<i32> = Vec::new();
     numbers.push(10);
     numbers.push(20);
     numbers.push(30);
-    println!("Vector: {:?}", numbers);
+    outln!(out, "Vector: {:?}", numbers);
 
     // Iterating over vector
-    println!("Iterating over vector:");
+    outln!(out, "Iterating over vector:");
     for num in &numbers {
-        println!("Number: {}", num);
+        outln!(out, "Number: {}", num);
     }
 
     for n in numbers.iter_mut() {
         *n += 5;
     }
-    println!("Modified Vector: {:?}", numbers);
+    outln!(out, "Modified Vector: {:?}", numbers);
 
     // String example
-    println!("\n--- String Example ---");
+    out.section("String Example");
     let mut greeting = String::from("Hello");
     greeting.push_str(", world!");
-    println!("String: {}", greeting);
+    outln!(out, "String: {}", greeting);
 
     // HashMap example
 
-    println!("\n--- HashMap Example ---");
+    out.section("HashMap Example");
     let mut scores: HashMap<String, i32> = HashMap::new();
     scores.insert(String::from("Alice"), 50);
     scores.insert(String::from("Bob"), 60);
-    println!("HashMap: {:?}", scores);
+    // HashMap iteration order is unspecified (it changes between runs),
+    // so copy into a BTreeMap whenever we want sorted, repeatable output
+    let sorted_scores: BTreeMap<&String, &i32> = scores.iter().collect();
+    outln!(out, "HashMap: {:?}", sorted_scores);
     // get value
     if let Some(score) = scores.get("Alice") {
-        println!("Alice's score: {}", score);
+        outln!(out, "Alice's score: {}", score);
     }
 
-    // Iterating over HashMap
-    println!("Iterating over HashMap:");
-    for (name, score) in &scores {
-        println!("{}: {}", name, score);
+    // Iterating over HashMap (through the sorted copy, for stable order)
+    outln!(out, "Iterating over HashMap:");
+    for (name, score) in &sorted_scores {
+        outln!(out, "{}: {}", name, score);
     }
 
     let mut set = HashSet::new();
@@ -88,12 +93,71 @@ pub fn learn_collections_and_data_structures() {
     set.insert(2);
     set.insert(1); // duplicate ignored
 
-    println!("{:?}", set); // {1,2}
+    let sorted_set: BTreeSet<&i32> = set.iter().collect(); // same reason as above
+    outln!(out, "{:?}", sorted_set); // {1,2}
 
     let mut deque: VecDeque<i32> = VecDeque::new();
     deque.push_back(1);
     deque.push_back(2);
     deque.push_front(0);
-    println!("{:?}", deque); // [0,1,2]
+    outln!(out, "{:?}", deque); // [0,1,2]
+
+}
+
+/// ============================================================================
+/// QUIZ QUESTIONS
+/// ============================================================================
+const COLLECTIONS_QUIZ: &[Question] = &[
+    Question {
+        prompt: "A HashSet after inserting 1, 2 and 1 again holds how many elements?",
+        answer: Answer::Text(&["2", "two"]),
+        explanation: "Sets keep unique values; the duplicate insert is ignored.",
+        section: None,
+    },
+    Question {
+        prompt: "HashMap iteration order is the same on every run.",
+        answer: Answer::TrueFalse(false),
+        explanation: "HashMap order is unspecified; copy into a BTreeMap for sorted output.",
+        section: Some("HashMap Example"),
+    },
+    Question {
+        prompt: "Which collection is best for adding at both the front and the back?",
+        answer: Answer::Choice {
+            options: &["VecDeque<T>", "Vec<T>", "[T; N]", "HashSet<T>"],
+            correct: 0,
+        },
+        explanation: "VecDeque is a ring buffer with cheap `push_front` and `push_back`.",
+        section: None,
+    },
+];
+
+/// ============================================================================
+/// LESSON REGISTRATION
+/// ============================================================================
+pub fn register(registry: &mut Registry) {
+    registry.register(CollectionsLesson);
+}
+
+pub struct CollectionsLesson;
+
+impl Lesson for CollectionsLesson {
+    fn meta(&self) -> &'static LessonMeta {
+        &LessonMeta {
+            id: "collections",
+            number: 16,
+            title: "Collections and Data Structures",
+            phase: Phase::FunctionalAndErrors,
+            module: "collection_ds",
+            entry: "learn_collections_and_data_structures",
+            requires: &["vectors", "iterators"],
+        }
+    }
 
-}
\ No newline at end of file
+    fn run(&self, out: &mut dyn Output) {
+        learn_collections_and_data_structures(out);
+    }
+
+    fn questions(&self) -> &'static [Question] {
+        COLLECTIONS_QUIZ
+    }
+}
@@ -2,6 +2,10 @@
 /// ENUMS MODULE - Enums & Pattern Matching
 /// ============================================================================
 
+use super::{Lesson, LessonMeta, Phase, Registry};
+use crate::output::{Output, outln};
+use crate::quiz::{Answer, Question};
+
 /// Direction enum to demonstrate basic enum usage
 #[derive(Debug)]
 pub enum Direction {
@@ -32,25 +36,21 @@ impl Direction {
 /// - `match` ensures all variants are handled (exhaustive)
 /// - Enums can hold data (shown in advanced examples)
 ///
-/// | Aspect          | Enum              | String            |
-/// |-----------------|-------------------|-------------------|
-/// | Storage         | Integer (efficient)| Heap-allocated   |
-/// | Type safety     | Compiler checks   | Any text allowed  |
-/// | Pattern match   | Exhaustive        | No compile checks |
+/// | Aspect        | Enum                | String            |
+/// |---------------|---------------------|-------------------|
+/// | Storage       | Integer (efficient) | Heap-allocated    |
+/// | Type safety   | Compiler checks     | Any text allowed  |
+/// | Pattern match | Exhaustive          | No compile checks |
 /// ============================================================================
-pub fn learn_enums_and_pattern_matching() {
-    println!("\n============================================================");
-    println!("📘 LESSON 12: Enums & Pattern Matching");
-    println!("============================================================\n");
-
+pub fn learn_enums_and_pattern_matching(out: &mut dyn Output) {
     // Basic enum usage
-    println!("--- Basic Enum Usage ---");
+    out.section("Basic Enum Usage");
     let my_direction = Direction::East;
     let message = my_direction.message();
-    println!("{}", message);
+    outln!(out, "{}", message);
 
     // Pattern matching with match
-    println!("\n--- Pattern Matching ---");
+    out.section("Pattern Matching");
     let directions = [
         Direction::North,
         Direction::South,
@@ -60,20 +60,20 @@ pub fn learn_enums_and_pattern_matching() {
 
     for dir in directions {
         match dir {
-            Direction::North => println!("⬆️  North - Cold regions ahead"),
-            Direction::South => println!("⬇️  South - Warm weather coming"),
-            Direction::East => println!("➡️  East - Sunrise direction"),
-            Direction::West => println!("⬅️  West - Sunset direction"),
+            Direction::North => outln!(out, "⬆️  North - Cold regions ahead"),
+            Direction::South => outln!(out, "⬇️  South - Warm weather coming"),
+            Direction::East => outln!(out, "➡️  East - Sunrise direction"),
+            Direction::West => outln!(out, "⬅️  West - Sunset direction"),
         }
     }
 
     // Enum with data
-    println!("\n--- Enums with Data ---");
-    learn_enums_with_data();
+    out.section("Enums with Data");
+    learn_enums_with_data(out);
 }
 
 /// Demonstrates enums that can hold different types of data
-fn learn_enums_with_data() {
+fn learn_enums_with_data(out: &mut dyn Output) {
     // Enum variants can hold different data types
     #[derive(Debug)]
     enum Message {
@@ -93,17 +93,88 @@ fn learn_enums_with_data() {
     for msg in messages {
         match msg {
             Message::Quit => {
-                println!("Quit: No data, just a signal to exit");
+                outln!(out, "Quit: No data, just a signal to exit");
             }
             Message::Move { x, y } => {
-                println!("Move: to position ({}, {})", x, y);
+                outln!(out, "Move: to position ({}, {})", x, y);
             }
             Message::Write(text) => {
-                println!("Write: message = '{}'", text);
+                outln!(out, "Write: message = '{}'", text);
             }
             Message::ChangeColor(r, g, b) => {
-                println!("ChangeColor: RGB({}, {}, {})", r, g, b);
+                outln!(out, "ChangeColor: RGB({}, {}, {})", r, g, b);
             }
         }
     }
 }
+
+/// ============================================================================
+/// QUIZ QUESTIONS
+/// ============================================================================
+const ENUMS_QUIZ: &[Question] = &[
+    Question {
+        prompt: "A `match` on `Direction` that forgets `West` ...",
+        answer: Answer::Choice {
+            options: &[
+                "does not compile (non-exhaustive patterns)",
+                "compiles and ignores West",
+                "panics when given West",
+                "compiles with a warning only",
+            ],
+            correct: 0,
+        },
+        explanation: "`match` must be exhaustive; add the arm or a `_ =>` catch-all.",
+        section: Some("Pattern Matching"),
+    },
+    Question {
+        prompt: "Enum variants can carry data, like `Write(String)` or `Move { x: i32, y: i32 }`.",
+        answer: Answer::TrueFalse(true),
+        explanation: "Variants may be unit-like, tuple-like or struct-like.",
+        section: Some("Enums with Data"),
+    },
+    Question {
+        prompt: "In `Message::Move { x, y } => ...`, what do `x` and `y` become inside the arm?",
+        answer: Answer::Choice {
+            options: &[
+                "The variant's field values",
+                "New uninitialised variables",
+                "References to Message",
+                "Strings",
+            ],
+            correct: 0,
+        },
+        explanation: "Patterns destructure the variant and bind its fields to names.",
+        section: Some("Enums with Data"),
+    },
+];
+
+/// ============================================================================
+/// LESSON REGISTRATION
+/// ============================================================================
+pub fn register(registry: &mut Registry) {
+    registry.register(EnumsLesson);
+}
+
+pub struct EnumsLesson;
+
+impl Lesson for EnumsLesson {
+    fn meta(&self) -> &'static LessonMeta {
+        &LessonMeta {
+            id: "enums",
+            number: 12,
+            title: "Enums & Pattern Matching",
+            phase: Phase::CustomTypes,
+            module: "enums",
+            entry: "learn_enums_and_pattern_matching",
+            requires: &["structs"],
+        }
+    }
+
+    fn run(&self, out: &mut dyn Output) {
+        learn_enums_and_pattern_matching(out);
+    }
+
+    fn questions(&self) -> &'static [Question] {
+        ENUMS_QUIZ
+    }
+}
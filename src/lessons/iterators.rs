@@ -2,6 +2,10 @@
 /// ITERATORS MODULE - Iterators & Functional Combinators
 /// ============================================================================
 
+use super::{Lesson, LessonMeta, Phase, Registry};
+use crate::output::{Output, outln};
+use crate::quiz::{Answer, Question};
+
 /// ============================================================================
 /// 15. ITERATORS & FUNCTIONAL COMBINATORS
 /// ============================================================================
@@ -15,103 +19,99 @@
 /// - `.enumerate()` → Get (index, value) pairs
 /// - `.chain()`     → Concatenate iterators
 /// ============================================================================
-pub fn learn_iterators_and_functional_combinators() {
-    println!("\n============================================================");
-    println!("📘 LESSON 15: Iterators & Functional Combinators");
-    println!("============================================================\n");
-
+pub fn learn_iterators_and_functional_combinators(out: &mut dyn Output) {
     // Three ways to iterate
-    println!("--- Three Ways to Iterate ---");
-    iterate_examples();
+    out.section("Three Ways to Iterate");
+    iterate_examples(out);
 
     // Map: transform elements
-    println!("\n--- map(): Transform Elements ---");
-    map_examples();
+    out.section("map(): Transform Elements");
+    map_examples(out);
 
     // Filter: select elements
-    println!("\n--- filter(): Select Elements ---");
-    filter_examples();
+    out.section("filter(): Select Elements");
+    filter_examples(out);
 
     // Chain: concatenate iterators
-    println!("\n--- chain(): Concatenate Iterators ---");
-    chain_examples();
+    out.section("chain(): Concatenate Iterators");
+    chain_examples(out);
 
     // Chaining multiple operations
-    println!("\n--- Chaining Multiple Operations ---");
-    chaining_examples();
+    out.section("Chaining Multiple Operations");
+    chaining_examples(out);
 
     // Enumerate: index + value
-    println!("\n--- enumerate(): Index + Value ---");
-    enumerate_examples();
+    out.section("enumerate(): Index + Value");
+    enumerate_examples(out);
 }
 
-fn iterate_examples() {
+fn iterate_examples(out: &mut dyn Output) {
     let v = vec![1, 2, 3];
 
     // Immutable borrow - v stays valid
-    println!(".iter() - Immutable borrow:");
+    outln!(out, ".iter() - Immutable borrow:");
     for x in v.iter() {
-        println!("  x = {}", x);
+        outln!(out, "  x = {}", x);
     }
-    println!("  v still valid: {:?}", v);
+    outln!(out, "  v still valid: {:?}", v);
 
     // Mutable borrow - can modify elements
-    println!("\n.iter_mut() - Mutable borrow:");
+    outln!(out, "\n.iter_mut() - Mutable borrow:");
     let mut v2 = vec![1, 2, 3];
     for x in v2.iter_mut() {
         *x += 10;
     }
-    println!("  v2 after modification: {:?}", v2);
+    outln!(out, "  v2 after modification: {:?}", v2);
 
     // Takes ownership - v consumed
-    println!("\n.into_iter() - Takes ownership:");
+    outln!(out, "\n.into_iter() - Takes ownership:");
     let v3 = vec![1, 2, 3];
     for x in v3.into_iter() {
-        println!("  x = {}", x);
+        outln!(out, "  x = {}", x);
     }
-    // println!("{:?}", v3); // ❌ v3 is consumed
+    // outln!(out, "{:?}", v3); // ❌ v3 is consumed
 }
 
-fn map_examples() {
+fn map_examples(out: &mut dyn Output) {
     let v = vec![1, 2, 3];
 
     // .map() transforms each element
     // Returns a lazy iterator, must .collect() to materialize
     let squared: Vec<i32> = v.iter().map(|x| x * x).collect();
-    println!("Original: {:?}", v);
-    println!("Squared:  {:?}", squared);
+    outln!(out, "Original: {:?}", v);
+    outln!(out, "Squared:  {:?}", squared);
 
     // Map with ownership
     let strings: Vec<String> = v.iter().map(|x| format!("num_{}", x)).collect();
-    println!("As strings: {:?}", strings);
+    outln!(out, "As strings: {:?}", strings);
 }
 
-fn filter_examples() {
+fn filter_examples(out: &mut dyn Output) {
     let v = vec![1, 2, 3, 4, 5, 6];
 
     // .filter() keeps elements matching the predicate
     // Note: filter gives &T, so we use *x to dereference
     let evens: Vec<i32> = v.iter().filter(|x| *x % 2 == 0).cloned().collect();
-    println!("Original: {:?}", v);
-    println!("Evens:    {:?}", evens);
+    outln!(out, "Original: {:?}", v);
+    outln!(out, "Evens:    {:?}", evens);
 
     // Filter with different condition
     let greater_than_3: Vec<&i32> = v.iter().filter(|x| **x > 3).collect();
-    println!("Greater than 3: {:?}", greater_than_3);
+    outln!(out, "Greater than 3: {:?}", greater_than_3);
 }
 
-fn chain_examples() {
+fn chain_examples(out: &mut dyn Output) {
     let v1 = vec![1, 2, 3];
     let v2 = vec![4, 5, 6];
 
     // .chain() concatenates two iterators
     let chained: Vec<i32> = v1.iter().chain(v2.iter()).cloned().collect();
-    println!("v1: {:?}", v1);
-    println!("v2: {:?}", v2);
-    println!("Chained: {:?}", chained);
+    outln!(out, "v1: {:?}", v1);
+    outln!(out, "v2: {:?}", v2);
+    outln!(out, "Chained: {:?}", chained);
 }
 
-fn chaining_examples() {
+fn chaining_examples(out: &mut dyn Output) {
     let numbers = vec![1, 2, 3, 4, 5, 6];
 
     // Chain multiple operations together
@@ -121,8 +121,8 @@ fn chaining_examples() {
         .map(|x| x * 10)         // Multiply each by 10
         .collect();
 
-    println!("Original: {:?}", numbers);
-    println!("Evens × 10: {:?}", result);
+    outln!(out, "Original: {:?}", numbers);
+    outln!(out, "Evens × 10: {:?}", result);
 
     // More complex pipeline
     let complex: Vec<String> = numbers
@@ -133,16 +133,16 @@ fn chaining_examples() {
         .map(|x| format!("val={}", x))      // Convert to string
         .collect();
 
-    println!("Complex pipeline: {:?}", complex);
+    outln!(out, "Complex pipeline: {:?}", complex);
 }
 
-fn enumerate_examples() {
+fn enumerate_examples(out: &mut dyn Output) {
     let v = vec!["apple", "banana", "cherry"];
 
     // .enumerate() gives (index, value) pairs
-    println!("Fruits with indices:");
+    outln!(out, "Fruits with indices:");
     for (index, value) in v.iter().enumerate() {
-        println!("  [{}] = {}", index, value);
+        outln!(out, "  [{}] = {}", index, value);
     }
 
     // Using enumerate in a pipeline
@@ -152,8 +152,66 @@ fn enumerate_examples() {
         .map(|(i, val)| format!("{}. {}", i + 1, val))
         .collect();
 
-    println!("\nNumbered list:");
+    outln!(out, "\nNumbered list:");
     for item in indexed {
-        println!("  {}", item);
+        outln!(out, "  {}", item);
+    }
+}
+
+/// ============================================================================
+/// QUIZ QUESTIONS
+/// ============================================================================
+const ITERATORS_QUIZ: &[Question] = &[
+    Question {
+        prompt: "Which call consumes the vector so it cannot be used afterwards?",
+        answer: Answer::Choice {
+            options: &["v.iter()", "v.iter_mut()", "v.into_iter()", "v.len()"],
+            correct: 2,
+        },
+        explanation: "`into_iter()` takes ownership and yields owned elements.",
+        section: Some("Three Ways to Iterate"),
+    },
+    Question {
+        prompt: "`v.iter().map(|x| x * x)` computes nothing until it is consumed (e.g. by `.collect()`).",
+        answer: Answer::TrueFalse(true),
+        explanation: "Iterator adapters are lazy; a consumer like `collect` or `sum` drives them.",
+        section: Some("map(): Transform Elements"),
+    },
+    Question {
+        prompt: "What is `[1, 2, 3, 4, 5, 6].iter().filter(|x| *x % 2 == 0).map(|x| x * 10)` collected into a Vec?",
+        answer: Answer::Text(&["[20, 40, 60]", "[20,40,60]"]),
+        explanation: "filter keeps 2, 4, 6; map multiplies each by 10.",
+        section: Some("Chaining Multiple Operations"),
+    },
+];
+
+/// ============================================================================
+/// LESSON REGISTRATION
+/// ============================================================================
+pub fn register(registry: &mut Registry) {
+    registry.register(IteratorsLesson);
+}
+
+pub struct IteratorsLesson;
+
+impl Lesson for IteratorsLesson {
+    fn meta(&self) -> &'static LessonMeta {
+        &LessonMeta {
+            id: "iterators",
+            number: 15,
+            title: "Iterators & Functional Combinators",
+            phase: Phase::FunctionalAndErrors,
+            module: "iterators",
+            entry: "learn_iterators_and_functional_combinators",
+            requires: &["vectors"],
+        }
+    }
+
+    fn run(&self, out: &mut dyn Output) {
+        learn_iterators_and_functional_combinators(out);
+    }
+
+    fn questions(&self) -> &'static [Question] {
+        ITERATORS_QUIZ
     }
 }
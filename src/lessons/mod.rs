@@ -13,6 +13,12 @@
 /// - enums         → Enums and pattern matching
 /// - iterators     → Iterators and functional combinators
 /// - option_result → Option<T> and Result<T, E> types
+/// - collection_ds → Collections and data structures
+///
+/// Every submodule exposes a `register()` function that adds its lessons to
+/// the `Registry`. Each lesson declares its `LessonMeta` once; the registry is
+/// the single source of truth for ordering, numbering and banners, and
+/// `validate.rs` checks it against this file at startup.
 /// ============================================================================
 
 pub mod basics;
@@ -26,39 +32,220 @@ pub mod iterators;
 pub mod option_result;
 pub mod collection_ds;
 
-/// Run all lessons in order
-pub fn run_all_lessons() {
-    println!("╔════════════════════════════════════════════════════════════╗");
-    println!("║          🦀 RUST LEARNING GUIDE - All Lessons 🦀            ║");
-    println!("╚════════════════════════════════════════════════════════════╝");
-
-    // Phase 1: Basics
-    basics::learn_variables_and_mutability();
-    basics::learn_arithmetic_operations();
-    basics::learn_conditionals();
-    basics::learn_loops();
-
-    // Phase 2: Data Structures
-    arrays::learn_arrays_and_indexing();
-
-    // Phase 3: Ownership & Borrowing
-    ownership::learn_ownership();
-    borrowing::learn_borrowing();
-    ownership::learn_functions_and_ownership();
-    borrowing::learn_borrowing_with_functions();
-
-    // Phase 4: Custom Types
-    structs::learn_structs_with_ownership();
-    vectors::learn_vectors_with_ownership();
-    enums::learn_enums_and_pattern_matching();
-
-    // Phase 5: Functional Programming & Error Handling
-    option_result::learn_option_type();
-    option_result::learn_error_handling_with_result();
-    iterators::learn_iterators_and_functional_combinators();
-    collection_ds::learn_collections_and_data_structures();
-
-    println!("\n╔════════════════════════════════════════════════════════════╗");
-    println!("║         ✅ All Lessons Completed Successfully! ✅          ║");
-    println!("╚════════════════════════════════════════════════════════════╝");
+use std::ops::RangeInclusive;
+
+use crate::content::Block;
+use crate::graph;
+use crate::output::Output;
+use crate::quiz::Question;
+
+/// ============================================================================
+/// PHASES - How lessons are grouped
+/// ============================================================================
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    Basics,
+    DataStructures,
+    OwnershipAndBorrowing,
+    CustomTypes,
+    FunctionalAndErrors,
+}
+
+impl Phase {
+    /// All phases in teaching order
+    pub const ALL: [Phase; 5] = [
+        Phase::Basics,
+        Phase::DataStructures,
+        Phase::OwnershipAndBorrowing,
+        Phase::CustomTypes,
+        Phase::FunctionalAndErrors,
+    ];
+
+    /// 1-based phase number
+    pub fn number(self) -> u32 {
+        match self {
+            Phase::Basics => 1,
+            Phase::DataStructures => 2,
+            Phase::OwnershipAndBorrowing => 3,
+            Phase::CustomTypes => 4,
+            Phase::FunctionalAndErrors => 5,
+        }
+    }
+
+    /// Human-readable phase title
+    pub fn title(self) -> &'static str {
+        match self {
+            Phase::Basics => "Basics",
+            Phase::DataStructures => "Data Structures",
+            Phase::OwnershipAndBorrowing => "Ownership & Borrowing",
+            Phase::CustomTypes => "Custom Types",
+            Phase::FunctionalAndErrors => "Functional Programming & Error Handling",
+        }
+    }
+
+    /// Looks up a phase by its 1-based number
+    pub fn from_number(number: u32) -> Option<Phase> {
+        Phase::ALL.into_iter().find(|p| p.number() == number)
+    }
+}
+
+/// ============================================================================
+/// LESSON METADATA - Declared once per lesson, validated at startup
+/// ============================================================================
+/// - `id`       → Short, stable name used on the command line (e.g. "ownership")
+/// - `number`   → Lesson number shown in the banner (1..=N, in teaching order)
+/// - `title`    → Lesson title shown in the banner
+/// - `phase`    → Phase the lesson belongs to
+/// - `module`   → Name of the `lessons/` submodule that defines the lesson
+/// - `entry`    → Name of the `learn_*` function that runs the lesson
+/// - `requires` → Ids of the lessons this one builds on (see `graph.rs`)
+/// ============================================================================
+#[derive(Debug)]
+pub struct LessonMeta {
+    pub id: &'static str,
+    pub number: u32,
+    pub title: &'static str,
+    pub phase: Phase,
+    pub module: &'static str,
+    pub entry: &'static str,
+    pub requires: &'static [&'static str],
+}
+
+/// ============================================================================
+/// LESSON TRAIT - Implemented by every lesson
+/// ============================================================================
+/// Implementors provide `meta()` and `run()`; the accessors below read the
+/// metadata so numbering and titles are never repeated in code.
+/// `run()` writes the lesson body to `out` (the banner is written by the runner).
+/// `questions()` is the lesson's quiz bank (empty unless overridden).
+/// ============================================================================
+pub trait Lesson {
+    fn meta(&self) -> &'static LessonMeta;
+    fn run(&self, out: &mut dyn Output);
+
+    fn id(&self) -> &'static str {
+        self.meta().id
+    }
+
+    fn number(&self) -> u32 {
+        self.meta().number
+    }
+
+    fn title(&self) -> &'static str {
+        self.meta().title
+    }
+
+    fn phase(&self) -> Phase {
+        self.meta().phase
+    }
+
+    fn questions(&self) -> &'static [Question] {
+        &[]
+    }
+}
+
+/// Collection of all registered lessons, kept sorted by lesson number
+#[derive(Default)]
+pub struct Registry {
+    lessons: Vec<Box<dyn Lesson>>,
+}
+
+impl Registry {
+    /// Adds a lesson, keeping the registry ordered by lesson number
+    pub fn register<L: Lesson + 'static>(&mut self, lesson: L) {
+        self.lessons.push(Box::new(lesson));
+        self.lessons.sort_by_key(|l| l.number());
+    }
+
+    /// All lessons in teaching order
+    pub fn lessons(&self) -> impl Iterator<Item = &dyn Lesson> {
+        self.lessons.iter().map(|l| l.as_ref())
+    }
+
+    /// Finds a lesson by id (case-insensitive) or by lesson number
+    pub fn find(&self, key: &str) -> Option<&dyn Lesson> {
+        let key = key.trim().to_lowercase();
+        match key.parse::<u32>() {
+            Ok(number) => self.lessons().find(|l| l.number() == number),
+            Err(_) => self.lessons().find(|l| l.id() == key),
+        }
+    }
+
+    /// Lessons belonging to one phase, in teaching order
+    pub fn in_phase(&self, phase: Phase) -> impl Iterator<Item = &dyn Lesson> {
+        self.lessons().filter(move |l| l.phase() == phase)
+    }
+
+    /// Lessons whose number falls within `range`, in teaching order
+    pub fn in_range(&self, range: RangeInclusive<u32>) -> impl Iterator<Item = &dyn Lesson> {
+        self.lessons().filter(move |l| range.contains(&l.number()))
+    }
+}
+
+/// Builds the registry from every lesson module
+pub fn registry() -> Registry {
+    let mut registry = Registry::default();
+    basics::register(&mut registry);
+    arrays::register(&mut registry);
+    ownership::register(&mut registry);
+    borrowing::register(&mut registry);
+    structs::register(&mut registry);
+    vectors::register(&mut registry);
+    enums::register(&mut registry);
+    option_result::register(&mut registry);
+    iterators::register(&mut registry);
+    collection_ds::register(&mut registry);
+    registry
+}
+
+/// Source text of a lesson module, used to cross-check metadata against code
+pub fn module_source(module: &str) -> Option<&'static str> {
+    let source = match module {
+        "basics" => include_str!("basics.rs"),
+        "arrays" => include_str!("arrays.rs"),
+        "ownership" => include_str!("ownership.rs"),
+        "borrowing" => include_str!("borrowing.rs"),
+        "structs" => include_str!("structs.rs"),
+        "vectors" => include_str!("vectors.rs"),
+        "enums" => include_str!("enums.rs"),
+        "iterators" => include_str!("iterators.rs"),
+        "option_result" => include_str!("option_result.rs"),
+        "collection_ds" => include_str!("collection_ds.rs"),
+        _ => return None,
+    };
+    Some(source)
+}
+
+/// Names of the submodules declared at the top of this file (`pub mod x;`)
+pub fn declared_modules() -> Vec<&'static str> {
+    include_str!("mod.rs")
+        .lines()
+        .filter_map(|line| line.strip_prefix("pub mod ")?.strip_suffix(';'))
+        .collect()
+}
+
+/// Writes the lesson banner and runs the lesson body
+pub fn run_lesson(lesson: &dyn Lesson, out: &mut dyn Output) {
+    out.emit(Block::Heading {
+        number: lesson.number(),
+        title: lesson.title().to_string(),
+    });
+    lesson.run(out);
+}
+
+/// Every lesson, each after the lessons it requires (see `graph::order`)
+pub fn prerequisite_order(registry: &Registry) -> Vec<&dyn Lesson> {
+    // Startup validation rejects cycles, so the fallback is never used
+    graph::order(registry).unwrap_or_else(|_| registry.lessons().collect())
+}
+
+/// Run all lessons in prerequisite order
+pub fn run_all_lessons(registry: &Registry, out: &mut dyn Output) {
+    out.emit(Block::Banner("🦀 RUST LEARNING GUIDE - All Lessons 🦀".to_string()));
+
+    for lesson in prerequisite_order(registry) {
+        run_lesson(lesson, out);
+    }
+
+    out.emit(Block::Banner("✅ All Lessons Completed Successfully! ✅".to_string()));
 }
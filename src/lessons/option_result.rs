@@ -2,6 +2,10 @@
 /// OPTION & RESULT MODULE - Rust's Safe Error Handling
 /// ============================================================================
 
+use super::{Lesson, LessonMeta, Phase, Registry};
+use crate::output::{Output, outln};
+use crate::quiz::{Answer, Question};
+
 /// ============================================================================
 /// 13. OPTION<T> TYPE - Rust Without Null
 /// ============================================================================
@@ -23,25 +27,21 @@
 /// - `.is_some()`     → Returns true if Some
 /// - `.is_none()`     → Returns true if None
 /// ============================================================================
-pub fn learn_option_type() {
-    println!("\n============================================================");
-    println!("📘 LESSON 13: Option<T> Type");
-    println!("============================================================\n");
-
+pub fn learn_option_type(out: &mut dyn Output) {
     // Basic Option usage with match
-    println!("--- Basic Option with Match ---");
-    basic_option_examples();
+    out.section("Basic Option with Match");
+    basic_option_examples(out);
 
     // Option combinators
-    println!("\n--- Option Combinators ---");
-    option_combinator_examples();
+    out.section("Option Combinators");
+    option_combinator_examples(out);
 
     // Real-world example: safe division
-    println!("\n--- Real-World: Safe Division ---");
-    safe_division_examples();
+    out.section("Real-World: Safe Division");
+    safe_division_examples(out);
 }
 
-fn basic_option_examples() {
+fn basic_option_examples(out: &mut dyn Output) {
     // Function that might not return a value
     fn find_first_even(numbers: &[i32]) -> Option<i32> {
         for &n in numbers {
@@ -57,36 +57,36 @@ fn basic_option_examples() {
 
     // Handle with match
     match find_first_even(&nums1) {
-        Some(n) => println!("Found even number: {}", n),
-        None => println!("No even number found"),
+        Some(n) => outln!(out, "Found even number: {}", n),
+        None => outln!(out, "No even number found"),
     }
 
     match find_first_even(&nums2) {
-        Some(n) => println!("Found even number: {}", n),
-        None => println!("No even number found"),
+        Some(n) => outln!(out, "Found even number: {}", n),
+        None => outln!(out, "No even number found"),
     }
 }
 
-fn option_combinator_examples() {
+fn option_combinator_examples(out: &mut dyn Output) {
     let some_value: Option<i32> = Some(5);
     let no_value: Option<i32> = None;
 
     // .unwrap_or() - provide default
-    println!("some_value.unwrap_or(0) = {}", some_value.unwrap_or(0));
-    println!("no_value.unwrap_or(0) = {}", no_value.unwrap_or(0));
+    outln!(out, "some_value.unwrap_or(0) = {}", some_value.unwrap_or(0));
+    outln!(out, "no_value.unwrap_or(0) = {}", no_value.unwrap_or(0));
 
     // .map() - transform the value if present
     let doubled = some_value.map(|x| x * 2);
     let doubled_none = no_value.map(|x| x * 2);
-    println!("some_value.map(|x| x * 2) = {:?}", doubled);
-    println!("no_value.map(|x| x * 2) = {:?}", doubled_none);
+    outln!(out, "some_value.map(|x| x * 2) = {:?}", doubled);
+    outln!(out, "no_value.map(|x| x * 2) = {:?}", doubled_none);
 
     // Chaining with .map()
     let result = Some(10)
         .map(|x| x * 2)      // Some(20)
         .map(|x| x + 5)      // Some(25)
         .map(|x| x.to_string()); // Some("25")
-    println!("Chained maps: {:?}", result);
+    outln!(out, "Chained maps: {:?}", result);
 
     // .and_then() for operations that return Option
     fn half_if_even(n: i32) -> Option<i32> {
@@ -101,12 +101,12 @@ fn option_combinator_examples() {
     let result2 = Some(11).and_then(half_if_even); // None (11 is odd)
     let result3 = None.and_then(half_if_even);     // None
 
-    println!("Some(10).and_then(half_if_even) = {:?}", result1);
-    println!("Some(11).and_then(half_if_even) = {:?}", result2);
-    println!("None.and_then(half_if_even) = {:?}", result3);
+    outln!(out, "Some(10).and_then(half_if_even) = {:?}", result1);
+    outln!(out, "Some(11).and_then(half_if_even) = {:?}", result2);
+    outln!(out, "None.and_then(half_if_even) = {:?}", result3);
 }
 
-fn safe_division_examples() {
+fn safe_division_examples(out: &mut dyn Output) {
     fn divide(numerator: f64, denominator: f64) -> Option<f64> {
         if denominator == 0.0 {
             None
@@ -120,20 +120,20 @@ fn safe_division_examples() {
     let result2 = divide(10.0, 0.0);
 
     match result1 {
-        Some(v) => println!("10.0 / 2.0 = {}", v),
-        None => println!("Cannot divide by zero!"),
+        Some(v) => outln!(out, "10.0 / 2.0 = {}", v),
+        None => outln!(out, "Cannot divide by zero!"),
     }
 
     match result2 {
-        Some(v) => println!("10.0 / 0.0 = {}", v),
-        None => println!("Cannot divide by zero!"),
+        Some(v) => outln!(out, "10.0 / 0.0 = {}", v),
+        None => outln!(out, "Cannot divide by zero!"),
     }
 
     // Using combinators for pipelines
     let doubled = divide(10.0, 2.0)
         .map(|v| v * 2.0)
         .unwrap_or(0.0);
-    println!("(10.0 / 2.0) * 2 = {}", doubled);
+    outln!(out, "(10.0 / 2.0) * 2 = {}", doubled);
 }
 
 /// ============================================================================
@@ -157,25 +157,21 @@ fn safe_division_examples() {
 /// - `.and_then(f)`   → Chain operations returning Result
 /// - `?`              → Propagate error to caller
 /// ============================================================================
-pub fn learn_error_handling_with_result() {
-    println!("\n============================================================");
-    println!("📘 LESSON 14: Result<T, E> Type");
-    println!("============================================================\n");
-
+pub fn learn_error_handling_with_result(out: &mut dyn Output) {
     // Basic Result usage
-    println!("--- Basic Result with Match ---");
-    basic_result_examples();
+    out.section("Basic Result with Match");
+    basic_result_examples(out);
 
     // Result combinators
-    println!("\n--- Result Combinators ---");
-    result_combinator_examples();
+    out.section("Result Combinators");
+    result_combinator_examples(out);
 
     // Error propagation with ?
-    println!("\n--- Error Propagation with ? ---");
-    error_propagation_examples();
+    out.section("Error Propagation with ?");
+    error_propagation_examples(out);
 }
 
-fn basic_result_examples() {
+fn basic_result_examples(out: &mut dyn Output) {
     fn divide(a: i32, b: i32) -> Result<i32, String> {
         if b == 0 {
             Err(String::from("Cannot divide by zero"))
@@ -186,25 +182,25 @@ fn basic_result_examples() {
 
     // Handle with match
     match divide(10, 2) {
-        Ok(value) => println!("10 / 2 = {}", value),
-        Err(e) => println!("Error: {}", e),
+        Ok(value) => outln!(out, "10 / 2 = {}", value),
+        Err(e) => outln!(out, "Error: {}", e),
     }
 
     match divide(10, 0) {
-        Ok(value) => println!("10 / 0 = {}", value),
-        Err(e) => println!("Error: {}", e),
+        Ok(value) => outln!(out, "10 / 0 = {}", value),
+        Err(e) => outln!(out, "Error: {}", e),
     }
 }
 
-fn result_combinator_examples() {
+fn result_combinator_examples(out: &mut dyn Output) {
     fn parse_and_double(s: &str) -> Result<i32, String> {
         s.parse::<i32>()
             .map_err(|_| format!("Failed to parse '{}'", s))
             .map(|n| n * 2)
     }
 
-    println!("parse_and_double(\"5\") = {:?}", parse_and_double("5"));
-    println!("parse_and_double(\"abc\") = {:?}", parse_and_double("abc"));
+    outln!(out, "parse_and_double(\"5\") = {:?}", parse_and_double("5"));
+    outln!(out, "parse_and_double(\"abc\") = {:?}", parse_and_double("abc"));
 
     // Chaining with and_then
     fn safe_sqrt(n: f64) -> Result<f64, String> {
@@ -225,13 +221,13 @@ fn result_combinator_examples() {
 
     // Chain: divide then sqrt
     let result = safe_divide(16.0, 2.0).and_then(safe_sqrt);
-    println!("safe_divide(16, 2).and_then(safe_sqrt) = {:?}", result);
+    outln!(out, "safe_divide(16, 2).and_then(safe_sqrt) = {:?}", result);
 
     let result2 = safe_divide(16.0, 0.0).and_then(safe_sqrt);
-    println!("safe_divide(16, 0).and_then(safe_sqrt) = {:?}", result2);
+    outln!(out, "safe_divide(16, 0).and_then(safe_sqrt) = {:?}", result2);
 }
 
-fn error_propagation_examples() {
+fn error_propagation_examples(out: &mut dyn Output) {
     // The ? operator propagates errors automatically
     fn process_number(s: &str) -> Result<i32, String> {
         let n: i32 = s
@@ -245,9 +241,9 @@ fn error_propagation_examples() {
         Ok(n * 10)
     }
 
-    println!("process_number(\"5\") = {:?}", process_number("5"));
-    println!("process_number(\"-3\") = {:?}", process_number("-3"));
-    println!("process_number(\"abc\") = {:?}", process_number("abc"));
+    outln!(out, "process_number(\"5\") = {:?}", process_number("5"));
+    outln!(out, "process_number(\"-3\") = {:?}", process_number("-3"));
+    outln!(out, "process_number(\"abc\") = {:?}", process_number("abc"));
 
     // Pipeline with multiple ? operations
     fn complex_pipeline(input: &str) -> Result<String, String> {
@@ -266,7 +262,127 @@ fn error_propagation_examples() {
         Ok(format!("Result: {:.2}", sqrt))
     }
 
-    println!("\nComplex pipeline:");
-    println!("  \"8\" → {:?}", complex_pipeline("8"));
-    println!("  \"abc\" → {:?}", complex_pipeline("abc"));
+    outln!(out, "\nComplex pipeline:");
+    outln!(out, "  \"8\" → {:?}", complex_pipeline("8"));
+    outln!(out, "  \"abc\" → {:?}", complex_pipeline("abc"));
+}
+
+/// ============================================================================
+/// QUIZ QUESTIONS
+/// ============================================================================
+const OPTION_QUIZ: &[Question] = &[
+    Question {
+        prompt: "What does `Some(11).and_then(half_if_even)` return?",
+        answer: Answer::Choice {
+            options: &["Some(5)", "Some(5.5)", "None", "Some(11)"],
+            correct: 2,
+        },
+        explanation: "11 is odd, so `half_if_even` returns None, and `and_then` passes that on.",
+        section: Some("Option Combinators"),
+    },
+    Question {
+        prompt: "What is `None.unwrap_or(0)` for an `Option<i32>`?",
+        answer: Answer::Text(&["0"]),
+        explanation: "`unwrap_or` returns the default when there is no value.",
+        section: Some("Option Combinators"),
+    },
+    Question {
+        prompt: "`no_value.map(|x| x * 2)` on a None panics.",
+        answer: Answer::TrueFalse(false),
+        explanation: "`map` on None does nothing and returns None.",
+        section: Some("Option Combinators"),
+    },
+];
+
+const RESULT_QUIZ: &[Question] = &[
+    Question {
+        prompt: "What does the `?` operator do with an `Err(e)`?",
+        answer: Answer::Choice {
+            options: &[
+                "Returns Err(e) from the current function",
+                "Panics with e",
+                "Replaces it with a default value",
+                "Ignores the error and continues",
+            ],
+            correct: 0,
+        },
+        explanation: "`?` unwraps Ok values and returns errors early to the caller.",
+        section: Some("Error Propagation with ?"),
+    },
+    Question {
+        prompt: "Which method transforms only the error of a Result?",
+        answer: Answer::Text(&["map_err", ".map_err()", "map_err()"]),
+        explanation: "`map` transforms Ok values, `map_err` transforms Err values.",
+        section: Some("Result Combinators"),
+    },
+    Question {
+        prompt: "What does `process_number(\"-3\")` return?",
+        answer: Answer::Choice {
+            options: &[
+                "Err(\"Number must be positive\")",
+                "Ok(-30)",
+                "Err(\"Invalid number: -3\")",
+                "It panics",
+            ],
+            correct: 0,
+        },
+        explanation: "\"-3\" parses fine, then the explicit `n < 0` check returns an error.",
+        section: Some("Error Propagation with ?"),
+    },
+];
+
+/// ============================================================================
+/// LESSON REGISTRATION
+/// ============================================================================
+pub fn register(registry: &mut Registry) {
+    registry.register(OptionLesson);
+    registry.register(ResultLesson);
+}
+
+pub struct OptionLesson;
+
+impl Lesson for OptionLesson {
+    fn meta(&self) -> &'static LessonMeta {
+        &LessonMeta {
+            id: "option",
+            number: 13,
+            title: "Option<T> Type",
+            phase: Phase::FunctionalAndErrors,
+            module: "option_result",
+            entry: "learn_option_type",
+            requires: &["enums"],
+        }
+    }
+
+    fn run(&self, out: &mut dyn Output) {
+        learn_option_type(out);
+    }
+
+    fn questions(&self) -> &'static [Question] {
+        OPTION_QUIZ
+    }
+}
+
+pub struct ResultLesson;
+
+impl Lesson for ResultLesson {
+    fn meta(&self) -> &'static LessonMeta {
+        &LessonMeta {
+            id: "result",
+            number: 14,
+            title: "Result<T, E> Type",
+            phase: Phase::FunctionalAndErrors,
+            module: "option_result",
+            entry: "learn_error_handling_with_result",
+            requires: &["option"],
+        }
+    }
+
+    fn run(&self, out: &mut dyn Output) {
+        learn_error_handling_with_result(out);
+    }
+
+    fn questions(&self) -> &'static [Question] {
+        RESULT_QUIZ
+    }
 }
@@ -2,82 +2,82 @@
 /// OWNERSHIP MODULE - Ownership Deep Dive
 /// ============================================================================
 
+use super::{Lesson, LessonMeta, Phase, Registry};
+use crate::output::{Output, outln};
+use crate::quiz::{Answer, Question};
+
 /// ============================================================================
 /// 6. OWNERSHIP DEEP DIVE
 /// ============================================================================
 /// Key Concepts:
-/// | Type                    | Behavior                    |
-/// |-------------------------|------------------------------|
-/// | Copy type (i32, bool)   | Duplicated bit-for-bit       |
-/// | Heap type (String, Vec) | Ownership moves              |
-/// | &T                      | Borrowed reference           |
+/// | Type                    | Behavior               |
+/// |-------------------------|------------------------|
+/// | Copy type (i32, bool)   | Duplicated bit-for-bit |
+/// | Heap type (String, Vec) | Ownership moves        |
+/// | &T                      | Borrowed reference     |
 ///
 /// - Each value has a single owner
 /// - When owner goes out of scope, value is dropped (freed)
 /// - Assignment moves ownership for heap types
 /// - Assignment copies for stack types that implement Copy
 /// ============================================================================
-pub fn learn_ownership() {
-    println!("\n============================================================");
-    println!("📘 LESSON 6: Ownership Deep Dive");
-    println!("============================================================\n");
-
+pub fn learn_ownership(out: &mut dyn Output) {
     // Copy types: integers are copied, not moved
     // a ──copy──▶ b
-    println!("--- Copy Types (Integers) ---");
+    out.section("Copy Types (Integers)");
     let a = 5;
     let b = a; // Copy occurs, both a and b are valid
-    println!("a = {}, b = {} (both valid, copy occurred)", a, b);
+    outln!(out, "a = {}, b = {} (both valid, copy occurred)", a, b);
 
     // Heap types: String ownership moves
     // s1 ──move──▶ s2
-    println!("\n--- Move Semantics (String) ---");
+    out.section("Move Semantics (String)");
     let s1 = String::from("test ownership");
     let s2 = s1; // Ownership moves, s1 is no longer valid
-    println!("s2 = {}", s2);
-    // println!("s1 = {}", s1); // ❌ Compile error: value borrowed after move
+    outln!(out, "s2 = {}", s2);
+    // outln!(out, "s1 = {}", s1); // ❌ Compile error: value borrowed after move
 
     // Arrays of Copy types: array is copied
-    println!("\n--- Arrays of Copy Types ---");
+    out.section("Arrays of Copy Types");
     let arr1 = [1, 2, 3, 4, 5];
     let arr2 = arr1; // Array copied (elements implement Copy)
-    println!("arr1 = {:?}, arr2 = {:?}", arr1, arr2);
+    outln!(out, "arr1 = {:?}, arr2 = {:?}", arr1, arr2);
 
     // Two ways to iterate: by value vs by reference
-    println!("\n--- Iteration: by value vs by reference ---");
-    println!("for n in arr1 (by value):");
+    out.section("Iteration: by value vs by reference");
+    outln!(out, "for n in arr1 (by value):");
     for n in arr1 {
-        println!("  n = {}", n);
+        outln!(out, "  n = {}", n);
     }
 
-    println!("for val in arr1.iter() (by reference):");
+    outln!(out, "for val in arr1.iter() (by reference):");
     for val in arr1.iter() {
-        println!("  val = {}", val);
+        outln!(out, "  val = {}", val);
     }
 
     // Arrays of heap types: ownership moves!
-    println!("\n--- Arrays of Heap Types (String) ---");
+    out.section("Arrays of Heap Types (String)");
     let str_arr1 = [
         String::from("one"),
         String::from("two"),
         String::from("three"),
     ];
     let str_arr2 = str_arr1; // Ownership of entire array moves
-    // println!("{:?}", str_arr1); // ❌ Compile error
+    // outln!(out, "{:?}", str_arr1); // ❌ Compile error
 
     // Using .iter() borrows, so array stays valid
-    println!("Iterating with .iter() (borrows):");
+    outln!(out, "Iterating with .iter() (borrows):");
     for s in str_arr2.iter() {
-        println!("  s = {}", s);
+        outln!(out, "  s = {}", s);
     }
-    println!("Array still valid: {:?}", str_arr2);
+    outln!(out, "Array still valid: {:?}", str_arr2);
 
     // Direct iteration moves each element
-    println!("\nDirect iteration (moves each element):");
+    outln!(out, "\nDirect iteration (moves each element):");
     for st in str_arr2 {
-        println!("  st = {}", st);
+        outln!(out, "  st = {}", st);
     }
-    // println!("{:?}", str_arr2); // ❌ Array no longer valid after moving elements
+    // outln!(out, "{:?}", str_arr2); // ❌ Array no longer valid after moving elements
 }
 
 /// ============================================================================
@@ -88,29 +88,25 @@ pub fn learn_ownership() {
 /// - Passing heap types to functions: ownership moves
 /// - To keep ownership, return the value or pass by reference
 ///
-/// | Parameter Type | What Happens                    |
-/// |----------------|----------------------------------|
-/// | T (owned)      | Ownership moves into function    |
-/// | &T             | Immutable borrow (read-only)     |
-/// | &mut T         | Mutable borrow (can modify)      |
+/// | Parameter Type | What Happens                  |
+/// |----------------|-------------------------------|
+/// | T (owned)      | Ownership moves into function |
+/// | &T             | Immutable borrow (read-only)  |
+/// | &mut T         | Mutable borrow (can modify)   |
 /// ============================================================================
-pub fn learn_functions_and_ownership() {
-    println!("\n============================================================");
-    println!("📘 LESSON 8: Functions & Ownership");
-    println!("============================================================\n");
-
+pub fn learn_functions_and_ownership(out: &mut dyn Output) {
     // Copy type: value is copied into function
-    println!("--- Copy Types in Functions ---");
+    out.section("Copy Types in Functions");
     let x = 5;
     let y = add_one(x);
-    println!("x = {}, y = {} (x still usable - Copy type)", x, y);
+    outln!(out, "x = {}, y = {} (x still usable - Copy type)", x, y);
 
     // Non-Copy type: ownership moves into function
-    println!("\n--- Move Semantics in Functions ---");
+    out.section("Move Semantics in Functions");
     let s = String::from("Hello");
-    let s2 = take_ownership(s); // s moves into function
-    println!("s2 = {}", s2);
-    // println!("{}", s); // ❌ s is no longer valid
+    let s2 = take_ownership(out, s); // s moves into function
+    outln!(out, "s2 = {}", s2);
+    // outln!(out, "{}", s); // ❌ s is no longer valid
 }
 
 /// Adds one to the given integer (Copy type - original value unaffected)
@@ -119,7 +115,114 @@ fn add_one(n: i32) -> i32 {
 }
 
 /// Takes ownership of a String and returns it back to caller
-fn take_ownership(str_val: String) -> String {
-    println!("Inside function: {}", str_val);
+fn take_ownership(out: &mut dyn Output, str_val: String) -> String {
+    outln!(out, "Inside function: {}", str_val);
     str_val // Return ownership to caller
 }
+
+/// ============================================================================
+/// QUIZ QUESTIONS
+/// ============================================================================
+const OWNERSHIP_QUIZ: &[Question] = &[
+    Question {
+        prompt: "Which of these compile?\n  A: let a = 5; let b = a; println!(\"{}\", a);\n  B: let s1 = String::from(\"x\"); let s2 = s1; println!(\"{}\", s1);",
+        answer: Answer::Choice {
+            options: &["Only A", "Only B", "Both", "Neither"],
+            correct: 0,
+        },
+        explanation: "Integers are copied, but assigning a String moves it: s1 is unusable afterwards.",
+        section: Some("Move Semantics (String)"),
+    },
+    Question {
+        prompt: "`let arr2 = arr1;` where `arr1: [i32; 5]` leaves `arr1` usable.",
+        answer: Answer::TrueFalse(true),
+        explanation: "An array of Copy elements is itself Copy, so it is duplicated.",
+        section: Some("Arrays of Copy Types"),
+    },
+    Question {
+        prompt: "After `for st in str_arr2 { .. }` with `str_arr2: [String; 3]`, can `str_arr2` still be used?",
+        answer: Answer::TrueFalse(false),
+        explanation: "Iterating by value moves each String out; use `.iter()` to borrow instead.",
+        section: Some("Arrays of Heap Types (String)"),
+    },
+];
+
+const FUNCTIONS_OWNERSHIP_QUIZ: &[Question] = &[
+    Question {
+        prompt: "After `let s2 = take_ownership(s);`, which variable owns the String?",
+        answer: Answer::Text(&["s2"]),
+        explanation: "`s` moved into the function, which returned ownership into `s2`.",
+        section: Some("Move Semantics in Functions"),
+    },
+    Question {
+        prompt: "Which parameter type lets a function modify a String without taking ownership?",
+        answer: Answer::Choice {
+            options: &["String", "&String", "&mut String", "mut String"],
+            correct: 2,
+        },
+        explanation: "`&mut T` is a mutable borrow; `T` would move the value in.",
+        section: None,
+    },
+    Question {
+        prompt: "After `let y = add_one(x);` with `x: i32`, `x` is still usable.",
+        answer: Answer::TrueFalse(true),
+        explanation: "i32 is Copy: the function receives a copy of `x`.",
+        section: Some("Copy Types in Functions"),
+    },
+];
+
+/// ============================================================================
+/// LESSON REGISTRATION
+/// ============================================================================
+pub fn register(registry: &mut Registry) {
+    registry.register(OwnershipLesson);
+    registry.register(FunctionsOwnershipLesson);
+}
+
+pub struct OwnershipLesson;
+
+impl Lesson for OwnershipLesson {
+    fn meta(&self) -> &'static LessonMeta {
+        &LessonMeta {
+            id: "ownership",
+            number: 6,
+            title: "Ownership Deep Dive",
+            phase: Phase::OwnershipAndBorrowing,
+            module: "ownership",
+            entry: "learn_ownership",
+            requires: &["variables"],
+        }
+    }
+
+    fn run(&self, out: &mut dyn Output) {
+        learn_ownership(out);
+    }
+
+    fn questions(&self) -> &'static [Question] {
+        OWNERSHIP_QUIZ
+    }
+}
+
+pub struct FunctionsOwnershipLesson;
+
+impl Lesson for FunctionsOwnershipLesson {
+    fn meta(&self) -> &'static LessonMeta {
+        &LessonMeta {
+            id: "functions-ownership",
+            number: 8,
+            title: "Functions & Ownership",
+            phase: Phase::OwnershipAndBorrowing,
+            module: "ownership",
+            entry: "learn_functions_and_ownership",
+            requires: &["ownership"],
+        }
+    }
+
+    fn run(&self, out: &mut dyn Output) {
+        learn_functions_and_ownership(out);
+    }
+
+    fn questions(&self) -> &'static [Question] {
+        FUNCTIONS_OWNERSHIP_QUIZ
+    }
+}
@@ -2,6 +2,10 @@
 /// STRUCTS MODULE - Structs with Ownership & Borrowing
 /// ============================================================================
 
+use super::{Lesson, LessonMeta, Phase, Registry};
+use crate::output::{Output, outln};
+use crate::quiz::{Answer, Question};
+
 /// A simple Person struct to demonstrate ownership with custom types
 pub struct Person {
     pub name: String,
@@ -17,30 +21,88 @@ pub struct Person {
 /// - Borrow struct with &mut T for modifying fields
 /// - Same borrowing rules apply to custom types
 /// ============================================================================
-pub fn learn_structs_with_ownership() {
-    println!("\n============================================================");
-    println!("📘 LESSON 10: Structs with Ownership & Borrowing");
-    println!("============================================================\n");
-
+pub fn learn_structs_with_ownership(out: &mut dyn Output) {
     let mut person = Person {
         name: String::from("Alice"),
         age: 30,
     };
 
-    println!("--- Reading Struct (Immutable Borrow) ---");
-    print_person(&person);
+    out.section("Reading Struct (Immutable Borrow)");
+    print_person(out, &person);
 
-    println!("\n--- Modifying Struct (Mutable Borrow) ---");
+    out.section("Modifying Struct (Mutable Borrow)");
     update_age(&mut person, 31);
-    print_person(&person);
+    print_person(out, &person);
 }
 
 /// Prints person details (immutable borrow)
-pub fn print_person(p: &Person) {
-    println!("Name: {}, Age: {}", p.name, p.age);
+pub fn print_person(out: &mut dyn Output, p: &Person) {
+    outln!(out, "Name: {}, Age: {}", p.name, p.age);
 }
 
 /// Updates person's age (mutable borrow)
 pub fn update_age(p: &mut Person, new_age: u32) {
     p.age = new_age;
 }
+
+/// ============================================================================
+/// QUIZ QUESTIONS
+/// ============================================================================
+const STRUCTS_QUIZ: &[Question] = &[
+    Question {
+        prompt: "What parameter type does `update_age` need to change `person.age`?",
+        answer: Answer::Text(&["&mut Person"]),
+        explanation: "Modifying a field through a function needs a mutable borrow of the struct.",
+        section: Some("Modifying Struct (Mutable Borrow)"),
+    },
+    Question {
+        prompt: "A `Person { name: String, .. }` owns the heap data of its `name`.",
+        answer: Answer::TrueFalse(true),
+        explanation: "Struct fields are owned by the struct; dropping it frees the String.",
+        section: None,
+    },
+    Question {
+        prompt: "Which call only reads a person?",
+        answer: Answer::Choice {
+            options: &[
+                "print_person(out, &person)",
+                "print_person(out, person)",
+                "update_age(&mut person, 31)",
+            ],
+            correct: 0,
+        },
+        explanation: "`&person` is an immutable borrow: read-only, and `person` stays usable.",
+        section: Some("Reading Struct (Immutable Borrow)"),
+    },
+];
+
+/// ============================================================================
+/// LESSON REGISTRATION
+/// ============================================================================
+pub fn register(registry: &mut Registry) {
+    registry.register(StructsLesson);
+}
+
+pub struct StructsLesson;
+
+impl Lesson for StructsLesson {
+    fn meta(&self) -> &'static LessonMeta {
+        &LessonMeta {
+            id: "structs",
+            number: 10,
+            title: "Structs with Ownership & Borrowing",
+            phase: Phase::CustomTypes,
+            module: "structs",
+            entry: "learn_structs_with_ownership",
+            requires: &["borrowing-functions"],
+        }
+    }
+
+    fn run(&self, out: &mut dyn Output) {
+        learn_structs_with_ownership(out);
+    }
+
+    fn questions(&self) -> &'static [Question] {
+        STRUCTS_QUIZ
+    }
+}
@@ -2,6 +2,12 @@
 /// VECTORS MODULE - Vectors with Ownership & Borrowing
 /// ============================================================================
 
+use super::{Lesson, LessonMeta, Phase, Registry};
+use crate::content::Block;
+use crate::table::{Align, Table};
+use crate::output::{Output, outln};
+use crate::quiz::{Answer, Question};
+
 /// ============================================================================
 /// 11. VECTORS WITH OWNERSHIP & BORROWING
 /// ============================================================================
@@ -12,19 +18,15 @@
 /// - `for item in vec` → moves/consumes the vector
 /// - Slices `&vec[a..b]` borrow a portion of the vector
 ///
-/// | Active borrows   | Can owner read? | Can owner write? |
-/// |------------------|-----------------|------------------|
-/// | None             | ✅              | ✅               |
-/// | One or more &T   | ✅              | ❌               |
-/// | One &mut T       | ❌              | ❌               |
+/// | Active borrows | Can owner read? | Can owner write? |
+/// |----------------|-----------------|------------------|
+/// | None           | ✅              | ✅               |
+/// | One or more &T | ✅              | ❌               |
+/// | One &mut T     | ❌              | ❌               |
 /// ============================================================================
-pub fn learn_vectors_with_ownership() {
-    println!("\n============================================================");
-    println!("📘 LESSON 11: Vectors with Ownership & Borrowing");
-    println!("============================================================\n");
-
+pub fn learn_vectors_with_ownership(out: &mut dyn Output) {
     // Vector of Strings (heap types)
-    println!("--- Vector of Strings ---");
+    out.section("Vector of Strings");
     let mut vec = vec![
         String::from("one"),
         String::from("two"),
@@ -32,85 +34,143 @@ pub fn learn_vectors_with_ownership() {
     ];
 
     // Immutable iteration with .iter()
-    println!("Immutable iteration:");
+    outln!(out, "Immutable iteration:");
     for s in vec.iter() {
-        println!("  {}", s);
+        outln!(out, "  {}", s);
     }
 
     // Mutable iteration with .iter_mut()
-    println!("\nMutable iteration (appending ' modified'):");
+    outln!(out, "\nMutable iteration (appending ' modified'):");
     for s in vec.iter_mut() {
         s.push_str(" modified");
     }
 
     // Verify modifications
-    println!("\nAfter modification:");
+    outln!(out, "\nAfter modification:");
     for s in vec.iter() {
-        println!("  {}", s);
+        outln!(out, "  {}", s);
     }
 
     // Owner can modify when no active borrows
     vec.push(String::from("four"));
-    println!("\nAfter push: {:?}", vec);
+    outln!(out, "\nAfter push: {:?}", vec);
 
     // Vector of integers (Copy types)
-    println!("\n--- Vector of Integers ---");
+    out.section("Vector of Integers");
     let mut int_vec = vec![1, 2, 3, 4, 5];
-    println!("Before modifications: {:?}", int_vec);
+    outln!(out, "Before modifications: {:?}", int_vec);
 
     int_vec.push(6);
     int_vec[0] = 10;
-    println!("After push and modify: {:?}", int_vec);
+    outln!(out, "After push and modify: {:?}", int_vec);
 
     // Slices: borrowing a portion of the vector
-    println!("\n--- Slices (Borrowing a Portion) ---");
+    out.section("Slices (Borrowing a Portion)");
     let slice = &int_vec[1..4]; // Immutable borrow of indices 1, 2, 3
-    println!("Slice [1..4]: {:?}", slice);
+    outln!(out, "Slice [1..4]: {:?}", slice);
 
     // Owner can modify after slice is no longer used
     int_vec[2] = 100;
-    println!("After owner modification: {:?}", int_vec);
+    outln!(out, "After owner modification: {:?}", int_vec);
 
     // Mutable slice
-    println!("\n--- Mutable Slice ---");
+    out.section("Mutable Slice");
     let slice_mut = &mut int_vec[0..3];
     slice_mut[1] = 20;
-    println!("Mutable slice [0..3]: {:?}", slice_mut);
-    println!("Full vector after slice modification: {:?}", int_vec);
+    outln!(out, "Mutable slice [0..3]: {:?}", slice_mut);
+    outln!(out, "Full vector after slice modification: {:?}", int_vec);
 
     // Print borrowing law summary
-    print_borrowing_law_summary();
+    print_borrowing_law_summary(out);
+}
+
+fn print_borrowing_law_summary(out: &mut dyn Output) {
+    out.emit(Block::Summary("The Borrowing Law".to_string()));
+
+    out.emit(Block::Paragraph("At any moment, ONE of these is allowed:".to_string()));
+    out.emit(Block::Table(
+        Table::new(&["Situation", "Allowed"])
+            .min_width(0, 34)
+            .align(1, Align::Center)
+            .row(&["Any number of &T (immutable)", "✅"])
+            .row(&["Exactly one &mut T (mutable)", "✅"])
+            .row(&["Both at the same time", "❌"]),
+    ));
+
+    out.emit(Block::Paragraph("Owner access during active borrows:".to_string()));
+    out.emit(Block::Table(
+        Table::new(&["Active Borrows", "Owner Read?", "Owner Write?"])
+            .min_width(0, 16)
+            .align(1, Align::Center)
+            .align(2, Align::Center)
+            .row(&["None", "✅", "✅"])
+            .row(&["One or more &T", "✅", "❌"])
+            .row(&["One &mut T", "❌", "❌"]),
+    ));
+
+    out.emit(Block::Paragraph("Why? To guarantee:".to_string()));
+    out.emit(Block::List(vec![
+        "No data races".to_string(),
+        "No dangling references".to_string(),
+        "No memory corruption".to_string(),
+        "Thread safety without garbage collection".to_string(),
+    ]));
+}
+
+/// ============================================================================
+/// QUIZ QUESTIONS
+/// ============================================================================
+const VECTORS_QUIZ: &[Question] = &[
+    Question {
+        prompt: "Which method gives mutable references to each element of a Vec?",
+        answer: Answer::Text(&["iter_mut", ".iter_mut()", "iter_mut()"]),
+        explanation: "`.iter()` yields &T, `.iter_mut()` yields &mut T, `.into_iter()` yields T.",
+        section: Some("Vector of Strings"),
+    },
+    Question {
+        prompt: "With `int_vec = [10, 2, 3, 4, 5, 6]`, what does `&int_vec[1..4]` contain?",
+        answer: Answer::Choice {
+            options: &["[2, 3, 4]", "[10, 2, 3, 4]", "[2, 3, 4, 5]", "[10, 2, 3]"],
+            correct: 0,
+        },
+        explanation: "A slice `[1..4]` borrows indices 1, 2 and 3.",
+        section: Some("Slices (Borrowing a Portion)"),
+    },
+    Question {
+        prompt: "You can `push` to a Vec while a slice of it is still in use.",
+        answer: Answer::TrueFalse(false),
+        explanation: "`push` needs `&mut self`, which conflicts with the live immutable slice.",
+        section: Some("Slices (Borrowing a Portion)"),
+    },
+];
+
+/// ============================================================================
+/// LESSON REGISTRATION
+/// ============================================================================
+pub fn register(registry: &mut Registry) {
+    registry.register(VectorsLesson);
 }
 
-fn print_borrowing_law_summary() {
-    println!("\n============================================================");
-    println!("📚 SUMMARY: The Borrowing Law");
-    println!("============================================================");
-    println!(
-        "
-At any moment, ONE of these is allowed:
-┌────────────────────────────────────┬─────────┐
-│ Situation                          │ Allowed │
-├────────────────────────────────────┼─────────┤
-│ Any number of &T (immutable)       │   ✅    │
-│ Exactly one &mut T (mutable)       │   ✅    │
-│ Both at the same time              │   ❌    │
-└────────────────────────────────────┴─────────┘
-
-Owner access during active borrows:
-┌──────────────────┬─────────────┬──────────────┐
-│ Active Borrows   │ Owner Read? │ Owner Write? │
-├──────────────────┼─────────────┼──────────────┤
-│ None             │     ✅      │      ✅      │
-│ One or more &T   │     ✅      │      ❌      │
-│ One &mut T       │     ❌      │      ❌      │
-└──────────────────┴─────────────┴──────────────┘
-
-Why? To guarantee:
-• No data races
-• No dangling references
-• No memory corruption
-• Thread safety without garbage collection
-"
-    );
+pub struct VectorsLesson;
+
+impl Lesson for VectorsLesson {
+    fn meta(&self) -> &'static LessonMeta {
+        &LessonMeta {
+            id: "vectors",
+            number: 11,
+            title: "Vectors with Ownership & Borrowing",
+            phase: Phase::CustomTypes,
+            module: "vectors",
+            entry: "learn_vectors_with_ownership",
+            requires: &["borrowing"],
+        }
+    }
+
+    fn run(&self, out: &mut dyn Output) {
+        learn_vectors_with_ownership(out);
+    }
+
+    fn questions(&self) -> &'static [Question] {
+        VECTORS_QUIZ
+    }
 }
@@ -0,0 +1,498 @@
+//! ============================================================================
+//! LEXER MODULE - A small hand-written Rust tokenizer
+//! ============================================================================
+//! Splits Rust source into tokens with their line and column. Whitespace is
+//! skipped; comments are kept as tokens.
+//!
+//! | Kind         | Examples                                  |
+//! |--------------|-------------------------------------------|
+//! | `Ident`      | `let`, `String`, `r#type`, `_`            |
+//! | `Lifetime`   | `'a`, `'static`                           |
+//! | `Str`        | `"hi"`, `r#"raw"#`, `b"bytes"`, `c"text"` |
+//! | `Char`       | `'x'`, `'\n'`, `b'a'`                     |
+//! | `Number`     | `42`, `0xff`, `1_000u64`, `2.5e-3`        |
+//! | `Comment`    | `// line`, `/* block */`, `//// rule`     |
+//! | `DocComment` | `/// outer`, `//! inner`, `/** block */`  |
+//! | `Punct`      | `&`, `::`, `->`, `..=`, `+=`              |
+//! | `Error`      | An unterminated string or block comment   |
+//!
+//! Multi-character operators are matched longest first (`..=` before `..`).
+//! `'a'` is a char but `'a` is a lifetime: the closing quote decides. The
+//! lexer never fails; malformed input becomes an `Error` token. Keywords are
+//! plain `Ident` tokens, and `is_keyword()` tells them apart.
+//! ============================================================================
+
+/// What kind of token this is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Ident,
+    Lifetime,
+    Str,
+    Char,
+    Number,
+    Comment,
+    DocComment,
+    Punct,
+    Error,
+}
+
+/// One token and where it starts
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Token<'a> {
+    pub kind: TokenKind,
+    pub text: &'a str,
+    /// Byte offset of the first character in the source
+    pub start: usize,
+    /// 1-based line and column (in characters)
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Token<'_> {
+    /// `self` is the punctuation or identifier `text`
+    pub fn is(&self, text: &str) -> bool {
+        matches!(self.kind, TokenKind::Punct | TokenKind::Ident) && self.text == text
+    }
+
+    /// A strict or reserved keyword (`fn`, `let`, `match`, ...)
+    pub fn is_keyword(&self) -> bool {
+        self.kind == TokenKind::Ident && KEYWORDS.contains(&self.text)
+    }
+
+    /// A comment of either kind
+    pub fn is_comment(&self) -> bool {
+        matches!(self.kind, TokenKind::Comment | TokenKind::DocComment)
+    }
+}
+
+/// Keywords of the 2024 edition, strict and reserved
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "gen", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut",
+    "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while", "abstract", "become", "box", "do", "final", "macro",
+    "override", "priv", "try", "typeof", "unsized", "virtual", "yield",
+];
+
+/// Operators of two or three characters, longest first
+const OPERATORS: &[&str] = &[
+    "..=", "...", "<<=", ">>=", "::", "->", "=>", "==", "!=", "<=", ">=", "&&", "||", "+=", "-=",
+    "*=", "/=", "%=", "^=", "&=", "|=", "<<", ">>", "..",
+];
+
+/// Splits `source` into tokens
+pub fn tokenize(source: &str) -> Vec<Token<'_>> {
+    let mut lexer = Lexer {
+        source,
+        pos: 0,
+        line: 1,
+        column: 1,
+    };
+    let mut tokens = Vec::new();
+    while let Some(token) = lexer.next_token() {
+        tokens.push(token);
+    }
+    tokens
+}
+
+struct Lexer<'a> {
+    source: &'a str,
+    pos: usize,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Lexer<'a> {
+    fn rest(&self) -> &'a str {
+        &self.source[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<char> {
+        self.rest().chars().nth(n)
+    }
+
+    /// Moves past one character, keeping line and column up to date
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn bump_while(&mut self, keep: impl Fn(char) -> bool) {
+        while self.peek().is_some_and(&keep) {
+            self.bump();
+        }
+    }
+
+    fn next_token(&mut self) -> Option<Token<'a>> {
+        self.bump_while(char::is_whitespace);
+        let (start, line, column) = (self.pos, self.line, self.column);
+        let first = self.peek()?;
+
+        let kind = match first {
+            '/' if self.rest().starts_with("//") => {
+                let doc = (self.rest().starts_with("///") && !self.rest().starts_with("////"))
+                    || self.rest().starts_with("//!");
+                self.bump_while(|c| c != '\n');
+                if doc {
+                    TokenKind::DocComment
+                } else {
+                    TokenKind::Comment
+                }
+            }
+            '/' if self.rest().starts_with("/*") => self.block_comment(),
+            '"' => self.string(),
+            'r' if self.raw_string_ahead(1) => self.raw_string(1),
+            'b' | 'c' if self.peek_nth(1) == Some('"') => {
+                self.bump();
+                self.string()
+            }
+            'b' | 'c' if self.peek_nth(1) == Some('r') && self.raw_string_ahead(2) => {
+                self.raw_string(2)
+            }
+            'b' if self.peek_nth(1) == Some('\'') => {
+                self.bump();
+                self.quote()
+            }
+            'r' if self.peek_nth(1) == Some('#')
+                && self.peek_nth(2).is_some_and(is_ident_start) =>
+            {
+                self.bump();
+                self.bump();
+                self.bump_while(is_ident_continue);
+                TokenKind::Ident
+            }
+            c if is_ident_start(c) => {
+                self.bump_while(is_ident_continue);
+                TokenKind::Ident
+            }
+            '0'..='9' => self.number(),
+            '\'' => self.quote(),
+            _ => {
+                let operator = OPERATORS.iter().find(|op| self.rest().starts_with(*op));
+                match operator {
+                    Some(op) => op.chars().for_each(|_| {
+                        self.bump();
+                    }),
+                    None => {
+                        self.bump();
+                    }
+                }
+                TokenKind::Punct
+            }
+        };
+
+        Some(Token {
+            kind,
+            text: &self.source[start..self.pos],
+            start,
+            line,
+            column,
+        })
+    }
+
+    /// `/* ... */`, which may nest; `/** ... */` and `/*! ... */` are doc
+    /// comments (but `/**/` and `/*** ...` are not)
+    fn block_comment(&mut self) -> TokenKind {
+        let rest = self.rest();
+        let doc =
+            (rest.starts_with("/**") && !rest.starts_with("/***") && !rest.starts_with("/**/"))
+                || rest.starts_with("/*!");
+        self.bump();
+        self.bump();
+        let mut depth = 1;
+        while depth > 0 {
+            if self.rest().starts_with("/*") {
+                self.bump();
+                self.bump();
+                depth += 1;
+            } else if self.rest().starts_with("*/") {
+                self.bump();
+                self.bump();
+                depth -= 1;
+            } else if self.bump().is_none() {
+                return TokenKind::Error;
+            }
+        }
+        if doc {
+            TokenKind::DocComment
+        } else {
+            TokenKind::Comment
+        }
+    }
+
+    /// A `"` string with escapes; the opening quote is next
+    fn string(&mut self) -> TokenKind {
+        self.bump();
+        while let Some(c) = self.bump() {
+            match c {
+                '\\' => {
+                    self.bump();
+                }
+                '"' => return TokenKind::Str,
+                _ => {}
+            }
+        }
+        TokenKind::Error
+    }
+
+    /// `r"`, `r#"`, ... starting `offset` characters ahead
+    fn raw_string_ahead(&self, offset: usize) -> bool {
+        let after: String = self
+            .rest()
+            .chars()
+            .skip(offset)
+            .take_while(|&c| c == '#')
+            .collect();
+        self.peek_nth(offset + after.len()) == Some('"')
+    }
+
+    /// A raw string starting with `prefix` characters (`r`, or `br` / `cr`)
+    fn raw_string(&mut self, prefix: usize) -> TokenKind {
+        for _ in 0..prefix {
+            self.bump();
+        }
+        let mut hashes = 0;
+        while self.peek() == Some('#') {
+            self.bump();
+            hashes += 1;
+        }
+        self.bump();
+        let closing = format!("\"{}", "#".repeat(hashes));
+        match self.rest().find(&closing) {
+            Some(end) => {
+                let end = self.pos + end + closing.len();
+                while self.pos < end {
+                    self.bump();
+                }
+                TokenKind::Str
+            }
+            None => {
+                while self.bump().is_some() {}
+                TokenKind::Error
+            }
+        }
+    }
+
+    /// `'x'`, `'\n'` or a lifetime `'a`; the quote is next
+    fn quote(&mut self) -> TokenKind {
+        self.bump();
+        match (self.peek(), self.peek_nth(1)) {
+            (Some('\\'), _) => {
+                self.bump();
+                // One escaped character; `\u{..}` and `\x..` take a few more
+                match self.bump() {
+                    Some('u') if self.peek() == Some('{') => {
+                        self.bump_while(|c| c != '}' && c != '\'' && c != '\n');
+                        if self.peek() == Some('}') {
+                            self.bump();
+                        }
+                    }
+                    Some('x') => {
+                        for _ in 0..2 {
+                            if self.peek().is_some_and(|c| c.is_ascii_hexdigit()) {
+                                self.bump();
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+                if self.peek() == Some('\'') {
+                    self.bump();
+                    TokenKind::Char
+                } else {
+                    TokenKind::Error
+                }
+            }
+            (Some(_), Some('\'')) => {
+                self.bump();
+                self.bump();
+                TokenKind::Char
+            }
+            (Some(c), _) if is_ident_start(c) => {
+                self.bump_while(is_ident_continue);
+                TokenKind::Lifetime
+            }
+            _ => TokenKind::Error,
+        }
+    }
+
+    /// Integers and floats with any base, `_` separators and suffix
+    fn number(&mut self) -> TokenKind {
+        let hex = self.rest().starts_with("0x") || self.rest().starts_with("0X");
+        loop {
+            match self.peek() {
+                Some(c) if c.is_ascii_alphanumeric() || c == '_' => {
+                    self.bump();
+                }
+                // `1.5`, but not `1..2` or `1.max(2)`
+                Some('.') if self.peek_nth(1).is_some_and(|c| c.is_ascii_digit()) => {
+                    self.bump();
+                }
+                // `2.5e-3`
+                Some('+' | '-')
+                    if !hex
+                        && self.source[..self.pos].ends_with(['e', 'E'])
+                        && self.peek_nth(1).is_some_and(|c| c.is_ascii_digit()) =>
+                {
+                    self.bump();
+                }
+                _ => return TokenKind::Number,
+            }
+        }
+    }
+}
+
+fn is_ident_start(c: char) -> bool {
+    c == '_' || c.is_alphabetic()
+}
+
+fn is_ident_continue(c: char) -> bool {
+    c == '_' || c.is_alphanumeric()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::PathBuf;
+
+    /// Every `src/lessons/*.rs` file with its text, sorted by path
+    pub(crate) fn lesson_files() -> Vec<(PathBuf, String)> {
+        let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src/lessons");
+        let mut paths: Vec<PathBuf> = fs::read_dir(&dir)
+            .expect("src/lessons")
+            .map(|entry| entry.expect("directory entry").path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+            .collect();
+        paths.sort();
+        paths
+            .into_iter()
+            .map(|path| {
+                let text = fs::read_to_string(&path).expect("lesson file");
+                (path, text)
+            })
+            .collect()
+    }
+
+    fn kinds(source: &str) -> Vec<(TokenKind, &str)> {
+        tokenize(source).iter().map(|t| (t.kind, t.text)).collect()
+    }
+
+    #[test]
+    fn every_lesson_file_tokenizes_cleanly() {
+        let files = lesson_files();
+        assert!(!files.is_empty());
+        for (path, source) in &files {
+            let name = path.display();
+            let tokens = tokenize(source);
+            let mut end = 0;
+            let mut depth = 0i32;
+            for token in &tokens {
+                assert_ne!(token.kind, TokenKind::Error, "{}:{}", name, token.line);
+                // Only whitespace may lie between tokens: nothing is skipped
+                assert!(
+                    source[end..token.start].trim().is_empty(),
+                    "{}:{}: skipped {:?}",
+                    name,
+                    token.line,
+                    &source[end..token.start]
+                );
+                assert_eq!(&source[token.start..][..token.text.len()], token.text);
+                assert_eq!(source[..token.start].matches('\n').count() + 1, token.line);
+                end = token.start + token.text.len();
+
+                // A string, char or comment read wrong unbalances the brackets
+                if token.kind == TokenKind::Punct {
+                    match token.text {
+                        "(" | "[" | "{" => depth += 1,
+                        ")" | "]" | "}" => depth -= 1,
+                        _ => {}
+                    }
+                }
+                assert!(depth >= 0, "{}:{}: unbalanced bracket", name, token.line);
+            }
+            assert!(source[end..].trim().is_empty(), "{}: trailing text", name);
+            assert_eq!(depth, 0, "{}: unclosed bracket", name);
+        }
+    }
+
+    #[test]
+    fn quotes_are_chars_or_lifetimes() {
+        assert_eq!(
+            kinds("fn f<'a>(c: &'a char) -> char { 'x' } b'\\n' '\\u{1F980}'"),
+            vec![
+                (TokenKind::Ident, "fn"),
+                (TokenKind::Ident, "f"),
+                (TokenKind::Punct, "<"),
+                (TokenKind::Lifetime, "'a"),
+                (TokenKind::Punct, ">"),
+                (TokenKind::Punct, "("),
+                (TokenKind::Ident, "c"),
+                (TokenKind::Punct, ":"),
+                (TokenKind::Punct, "&"),
+                (TokenKind::Lifetime, "'a"),
+                (TokenKind::Ident, "char"),
+                (TokenKind::Punct, ")"),
+                (TokenKind::Punct, "->"),
+                (TokenKind::Ident, "char"),
+                (TokenKind::Punct, "{"),
+                (TokenKind::Char, "'x'"),
+                (TokenKind::Punct, "}"),
+                (TokenKind::Char, "b'\\n'"),
+                (TokenKind::Char, "'\\u{1F980}'"),
+            ]
+        );
+        assert_eq!(
+            kinds(r"'\'' b'\'' '\\' '\x7f' ;"),
+            vec![
+                (TokenKind::Char, r"'\''"),
+                (TokenKind::Char, r"b'\''"),
+                (TokenKind::Char, r"'\\'"),
+                (TokenKind::Char, r"'\x7f'"),
+                (TokenKind::Punct, ";"),
+            ]
+        );
+    }
+
+    #[test]
+    fn strings_comments_and_numbers() {
+        let source = r####"r#"say "hi""# "a\"b" /* x /* y */ z */ /// doc
+//// rule
+//! inner
+1..=2 2.5e-3 0xff_u8 1.max(2)"####;
+        assert_eq!(
+            kinds(source),
+            vec![
+                (TokenKind::Str, r###"r#"say "hi""#"###),
+                (TokenKind::Str, r#""a\"b""#),
+                (TokenKind::Comment, "/* x /* y */ z */"),
+                (TokenKind::DocComment, "/// doc"),
+                (TokenKind::Comment, "//// rule"),
+                (TokenKind::DocComment, "//! inner"),
+                (TokenKind::Number, "1"),
+                (TokenKind::Punct, "..="),
+                (TokenKind::Number, "2"),
+                (TokenKind::Number, "2.5e-3"),
+                (TokenKind::Number, "0xff_u8"),
+                (TokenKind::Number, "1"),
+                (TokenKind::Punct, "."),
+                (TokenKind::Ident, "max"),
+                (TokenKind::Punct, "("),
+                (TokenKind::Number, "2"),
+                (TokenKind::Punct, ")"),
+            ]
+        );
+        assert_eq!(kinds("\"open"), vec![(TokenKind::Error, "\"open")]);
+    }
+}
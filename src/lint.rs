@@ -0,0 +1,503 @@
+//! ============================================================================
+//! LINT MODULE - Flags beginner patterns in the learner's own Rust files
+//! ============================================================================
+//! `rust-lab lint <path>` tokenizes each `.rs` file (`lexer::tokenize`) and
+//! runs every rule at every token. Each finding links to the lesson that
+//! shows the better idiom.
+//!
+//! | Rule              | Flags                        | Better idiom              |
+//! |-------------------|------------------------------|---------------------------|
+//! | `string-param`    | `s: &String`                 | `s: &str`                 |
+//! | `vec-param`       | `v: &Vec<T>`                 | `v: &[T]`                 |
+//! | `unwrap`          | `.unwrap()`                  | `?`, `match`, `unwrap_or` |
+//! | `index-loop`      | `for i in 0..v.len()`        | `for x in &v`             |
+//! | `needless-clone`  | `&x.clone()`, clone to print | borrow `x`                |
+//! | `compound-assign` | `m = m + 1`                  | `m += 1`                  |
+//!
+//! Rules see tokens, not text, so strings and comments never match. These are
+//! hints, not errors: a rule has no type information, so `.unwrap()` in a test
+//! or a deliberate `&String` parameter is flagged too.
+//! ============================================================================
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::lexer::{self, Token, TokenKind};
+
+/// One lint rule
+pub struct Rule {
+    pub id: &'static str,
+    /// Short description of what was found
+    pub title: &'static str,
+    /// What to do instead
+    pub advice: &'static str,
+    pub lesson: &'static str,
+    /// Title of the lesson section (`out.section(..)`), if one fits best
+    pub section: Option<&'static str>,
+    /// Does the pattern match at `tokens[i]`, the token the finding points at?
+    check: fn(&[Token], usize) -> bool,
+}
+
+/// Every rule, in report order
+pub const RULES: &[Rule] = &[
+    Rule {
+        id: "string-param",
+        title: "`&String` instead of `&str`",
+        advice: "Take `&str` instead: it accepts `&String`, string literals and slices alike.",
+        lesson: "borrowing-functions",
+        section: Some("Immutable Borrow in Function"),
+        check: string_param,
+    },
+    Rule {
+        id: "vec-param",
+        title: "`&Vec<T>` instead of `&[T]`",
+        advice: "Take a slice `&[T]` instead: it accepts vectors, arrays and parts of either.",
+        lesson: "vectors",
+        section: Some("Slices (Borrowing a Portion)"),
+        check: vec_param,
+    },
+    Rule {
+        id: "unwrap",
+        title: "`.unwrap()` panics on None / Err",
+        advice: "Handle the missing case with `match`, `if let`, `.unwrap_or(..)` or `?`.",
+        lesson: "option",
+        section: Some("Option Combinators"),
+        check: unwrap,
+    },
+    Rule {
+        id: "index-loop",
+        title: "indexing loop over `0..len()`",
+        advice: "Iterate directly (`for x in &v`), or use `.iter().enumerate()` for the index.",
+        lesson: "iterators",
+        section: Some("Three Ways to Iterate"),
+        check: index_loop,
+    },
+    Rule {
+        id: "needless-clone",
+        title: "`.clone()` only to borrow or read",
+        advice: "Borrow the original instead; cloning copies the whole value for nothing.",
+        lesson: "borrowing",
+        section: Some("Immutable Borrowing"),
+        check: needless_clone,
+    },
+    Rule {
+        id: "compound-assign",
+        title: "`x = x + ...`",
+        advice: "Use the compound operator: `x += ...` (also `-=`, `*=`, `/=`, `%=`).",
+        lesson: "arithmetic",
+        section: None,
+        check: compound_assign,
+    },
+];
+
+/// Tokens `i`, `i + 1`, ... are `texts`
+fn seq(tokens: &[Token], i: usize, texts: &[&str]) -> bool {
+    tokens.len() >= i + texts.len() && texts.iter().enumerate().all(|(k, t)| tokens[i + k].is(t))
+}
+
+fn prev_is(tokens: &[Token], i: usize, text: &str) -> bool {
+    i > 0 && tokens[i - 1].is(text)
+}
+
+/// The unclosed `(`, `[` or `{` around `tokens[i]`; `None` when a `;` at the
+/// same depth comes first (`tokens[i]` starts or is inside a statement)
+fn enclosing_open(tokens: &[Token], i: usize) -> Option<usize> {
+    let mut depth = 0;
+    for j in (0..i).rev() {
+        if tokens[j].kind != TokenKind::Punct {
+            continue;
+        }
+        match tokens[j].text {
+            ")" | "]" | "}" => depth += 1,
+            "(" | "[" | "{" if depth == 0 => return Some(j),
+            "(" | "[" | "{" => depth -= 1,
+            ";" if depth == 0 => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// `tokens[i]` is in the parameter list of `fn name(..)` or `fn name<..>(..)`
+fn in_fn_params(tokens: &[Token], i: usize) -> bool {
+    let Some(open) = enclosing_open(tokens, i).filter(|&j| tokens[j].is("(")) else {
+        return false;
+    };
+    // Step back over the generics, counting `>>` / `<<` as two brackets
+    let mut name = open.checked_sub(1);
+    if let Some(close) = name.filter(|&j| tokens[j].is(">")) {
+        let mut depth = 0i32;
+        name = None;
+        for j in (0..=close).rev() {
+            depth += match tokens[j].text {
+                ">" => 1,
+                ">>" => 2,
+                "<" => -1,
+                "<<" => -2,
+                _ => 0,
+            };
+            if depth <= 0 {
+                name = j.checked_sub(1);
+                break;
+            }
+        }
+    }
+    name.is_some_and(|j| j > 0 && tokens[j].kind == TokenKind::Ident && tokens[j - 1].is("fn"))
+}
+
+/// `s: &String` in a parameter list (but not `&String::new()`)
+fn string_param(tokens: &[Token], i: usize) -> bool {
+    prev_is(tokens, i, ":")
+        && seq(tokens, i, &["&", "String"])
+        && !seq(tokens, i + 2, &["::"])
+        && in_fn_params(tokens, i)
+}
+
+/// `v: &Vec<T>` in a parameter list
+fn vec_param(tokens: &[Token], i: usize) -> bool {
+    prev_is(tokens, i, ":") && seq(tokens, i, &["&", "Vec", "<"]) && in_fn_params(tokens, i)
+}
+
+/// `.unwrap()`
+fn unwrap(tokens: &[Token], i: usize) -> bool {
+    prev_is(tokens, i, ".") && seq(tokens, i, &["unwrap", "(", ")"])
+}
+
+/// `for i in 0..v.len() { .. v[i] .. }` where `i` is only ever used to index
+/// `v` (also `self.items`); loops that use `i` for anything else need it
+fn index_loop(tokens: &[Token], i: usize) -> bool {
+    let from_zero = tokens
+        .get(i + 3)
+        .is_some_and(|t| t.kind == TokenKind::Number && t.text == "0");
+    if !(tokens[i].is("for")
+        && tokens
+            .get(i + 1)
+            .is_some_and(|t| t.kind == TokenKind::Ident)
+        && seq(tokens, i + 2, &["in"])
+        && from_zero
+        && seq(tokens, i + 4, &[".."]))
+    {
+        return false;
+    }
+    // A path like `v` or `self.items`, then `.len()`, then the loop body
+    let path_start = i + 5;
+    let mut j = path_start;
+    while j < tokens.len() && tokens[j].kind == TokenKind::Ident {
+        if seq(tokens, j + 1, &[".", "len", "(", ")", "{"]) {
+            let path = &tokens[path_start..=j];
+            return only_indexes(tokens, j + 6, tokens[i + 1].text, path);
+        }
+        if !seq(tokens, j + 1, &["."]) {
+            return false;
+        }
+        j += 2;
+    }
+    false
+}
+
+/// Every use of `var` in the block starting at `tokens[body]` is `path[var]`,
+/// and there is at least one
+fn only_indexes(tokens: &[Token], body: usize, var: &str, path: &[Token]) -> bool {
+    let mut depth = 1;
+    let mut uses = 0;
+    for k in body..tokens.len() {
+        let token = &tokens[k];
+        if token.kind == TokenKind::Punct {
+            match token.text {
+                "{" => depth += 1,
+                "}" if depth == 1 => return uses > 0,
+                "}" => depth -= 1,
+                _ => {}
+            }
+        } else if token.kind == TokenKind::Ident && token.text == var {
+            let indexed = k > path.len()
+                && tokens[k - 1].is("[")
+                && tokens.get(k + 1).is_some_and(|t| t.is("]"))
+                && tokens[k - 1 - path.len()..k - 1]
+                    .iter()
+                    .zip(path)
+                    .all(|(a, b)| a.text == b.text);
+            if !indexed {
+                return false;
+            }
+            uses += 1;
+        }
+    }
+    false
+}
+
+/// Macros whose arguments are only read (they take references implicitly)
+const FORMAT_MACROS: &[&str] = &[
+    "println", "print", "eprintln", "eprint", "format", "write", "writeln", "panic", "outln",
+];
+
+/// `&x.clone()`, `x.clone().len()` or a `.clone()` inside `println!(..)`
+fn needless_clone(tokens: &[Token], i: usize) -> bool {
+    const READS: &[&str] = &[
+        "len",
+        "is_empty",
+        "iter",
+        "contains",
+        "as_str",
+        "get",
+        "starts_with",
+        "ends_with",
+        "chars",
+    ];
+    if !(prev_is(tokens, i, ".") && seq(tokens, i, &["clone", "(", ")"])) {
+        return false;
+    }
+    let borrowed = i >= 3 && tokens[i - 3].is("&") && tokens[i - 2].kind == TokenKind::Ident;
+    let read_after = tokens.get(i + 3).is_some_and(|t| t.is("."))
+        && tokens.get(i + 4).is_some_and(|t| READS.contains(&t.text));
+    borrowed || read_after || in_format_macro(tokens, i)
+}
+
+/// The unclosed `(` around `tokens[i]` belongs to `name!`
+fn in_format_macro(tokens: &[Token], i: usize) -> bool {
+    enclosing_open(tokens, i).is_some_and(|j| {
+        tokens[j].is("(")
+            && j >= 2
+            && tokens[j - 1].is("!")
+            && FORMAT_MACROS.contains(&tokens[j - 2].text)
+    })
+}
+
+/// `m = m + 1;` as a statement (`let m = m + 1` shadows, which is fine),
+/// when `m += ...` would mean the same: the operator is followed by a single
+/// operand, or by a chain of the same `+` or `*` (`m = m * 2 + 1` and
+/// `m = m - a + b` are left alone)
+fn compound_assign(tokens: &[Token], i: usize) -> bool {
+    const BINARY: [&str; 10] = ["+", "-", "*", "/", "%", "<<", ">>", "&", "|", "^"];
+    let statement_start = i == 0 || ["{", "}", ";"].iter().any(|t| tokens[i - 1].is(t));
+    let assigns_itself = statement_start
+        && tokens[i].kind == TokenKind::Ident
+        && tokens.get(i + 1).is_some_and(|t| t.is("="))
+        && tokens.get(i + 2).is_some_and(|t| t.text == tokens[i].text);
+    let Some(first) = tokens
+        .get(i + 3)
+        .filter(|t| assigns_itself && BINARY[..5].iter().any(|op| t.is(op)))
+    else {
+        return false;
+    };
+
+    // Operators at the top level of the rest of the statement; a `-`, `*`
+    // or `&` that does not follow an operand is unary
+    let mut depth = 0;
+    let mut after_operand = false;
+    for token in &tokens[i + 4..] {
+        match token.text {
+            ";" | "}" if depth == 0 => break,
+            "(" | "[" | "{" => depth += 1,
+            ")" | "]" | "}" => depth -= 1,
+            op if depth == 0 && after_operand && token.is(op) && BINARY.contains(&op) => {
+                if op != first.text || !matches!(op, "+" | "*") {
+                    return false;
+                }
+                after_operand = false;
+                continue;
+            }
+            _ => {}
+        }
+        after_operand = token.kind != TokenKind::Punct || matches!(token.text, ")" | "]" | "?");
+    }
+    true
+}
+
+/// One rule match in one file
+pub struct Finding {
+    pub rule: &'static Rule,
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Runs every rule over `source`; findings come in source order
+pub fn lint(source: &str) -> Vec<Finding> {
+    let tokens: Vec<Token> = lexer::tokenize(source)
+        .into_iter()
+        .filter(|t| !t.is_comment())
+        .collect();
+
+    let mut findings = Vec::new();
+    for i in 0..tokens.len() {
+        for rule in RULES.iter().filter(|rule| (rule.check)(&tokens, i)) {
+            findings.push(Finding {
+                rule,
+                line: tokens[i].line,
+                column: tokens[i].column,
+            });
+        }
+    }
+    findings
+}
+
+/// `.rs` files under `path` (or `path` itself), sorted; hidden directories
+/// and `target/` are skipped
+pub fn rust_files(path: &Path) -> io::Result<Vec<PathBuf>> {
+    if !path.is_dir() {
+        fs::metadata(path)?;
+        return Ok(vec![path.to_path_buf()]);
+    }
+    let mut files = Vec::new();
+    let mut pending = vec![path.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        for entry in fs::read_dir(&dir)? {
+            let entry_path = entry?.path();
+            let name = entry_path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or_default();
+            if entry_path.is_dir() {
+                if !name.starts_with('.') && name != "target" {
+                    pending.push(entry_path);
+                }
+            } else if name.ends_with(".rs") {
+                files.push(entry_path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lessons;
+    use crate::validate;
+
+    /// Ids of the rules that fire on `source`, in source order
+    fn fired(source: &str) -> Vec<&'static str> {
+        lint(source).iter().map(|finding| finding.rule.id).collect()
+    }
+
+    #[test]
+    fn string_param_only_in_parameter_lists() {
+        assert_eq!(fired("fn greet(name: &String) {}"), ["string-param"]);
+        assert_eq!(
+            fired("fn show<T: Into<Vec<u8>>>(label: &String, t: T) {}"),
+            ["string-param"]
+        );
+        assert_eq!(
+            fired("impl A { fn set(&mut self, key: [u8; 2], name: &String) {} }"),
+            ["string-param"]
+        );
+        assert!(fired("fn greet(name: &str) {}").is_empty());
+        assert!(fired("fn f() { let name: &String = &owned; }").is_empty());
+        assert!(fired("fn f() { let empty: &String = &String::new(); }").is_empty());
+        assert!(fired("struct Named<'a> { name: &'a String }").is_empty());
+        assert!(fired("fn f() { takes(&String::new()); }").is_empty());
+        assert!(fired("fn f(x: T) { let s = \"name: &String\"; }").is_empty());
+    }
+
+    #[test]
+    fn vec_param_only_in_parameter_lists() {
+        assert_eq!(fired("fn sum(v: &Vec<i32>) -> i32 { 0 }"), ["vec-param"]);
+        assert!(fired("fn sum(v: &[i32]) -> i32 { 0 }").is_empty());
+        assert!(fired("fn f() { let v: &Vec<i32> = &all; }").is_empty());
+    }
+
+    #[test]
+    fn unwrap_is_flagged_but_not_its_cousins() {
+        assert_eq!(fired("fn f() { x.unwrap(); }"), ["unwrap"]);
+        assert!(fired("fn f() { x.unwrap_or(0); x.expect(\"set\"); }").is_empty());
+        assert!(fired("// x.unwrap()\nfn unwrap() {}").is_empty());
+    }
+
+    #[test]
+    fn index_loop_fires_only_when_the_index_just_indexes() {
+        assert_eq!(
+            fired("fn f(v: &[i32]) { for i in 0..v.len() { total += v[i]; } }"),
+            ["index-loop"]
+        );
+        assert_eq!(
+            fired("fn f(&self) { for i in 0..self.items.len() { show(&self.items[i]); } }"),
+            ["index-loop"]
+        );
+        // `i` is used on its own, so the index is really needed
+        assert!(
+            fired("fn f(v: &[i32]) { for i in 0..v.len() { check(&v, i); v[i]; } }").is_empty()
+        );
+        assert!(fired("fn f(v: &[i32]) { for i in 0..v.len() { out(i); } }").is_empty());
+        // `i` indexes something else as well
+        assert!(fired("fn f(v: &[i32]) { for i in 0..v.len() { w[i] = v[i]; } }").is_empty());
+        assert!(fired("fn f() { for i in 1..v.len() { v[i]; } }").is_empty());
+        assert!(fired("fn f() { for x in &v { x; } }").is_empty());
+    }
+
+    #[test]
+    fn lint_own_loop_over_tokens_is_not_flagged() {
+        // `lint()` above passes `i` to each rule, not just `tokens[i]`
+        let source = include_str!("lint.rs");
+        let index_loops: Vec<usize> = lint(source)
+            .iter()
+            .filter(|finding| finding.rule.id == "index-loop")
+            .map(|finding| finding.line)
+            .collect();
+        assert!(index_loops.is_empty(), "lines {:?}", index_loops);
+    }
+
+    #[test]
+    fn needless_clone_when_only_borrowed_or_read() {
+        assert_eq!(
+            fired("fn f() { takes(&name.clone()); }"),
+            ["needless-clone"]
+        );
+        assert_eq!(
+            fired("fn f() { let n = name.clone().len(); }"),
+            ["needless-clone"]
+        );
+        assert_eq!(
+            fired("fn f() { println!(\"{}\", name.clone()); }"),
+            ["needless-clone"]
+        );
+        assert!(fired("fn f() { let copy = name.clone(); }").is_empty());
+        assert!(fired("fn f() { keep(name.clone()); }").is_empty());
+        assert!(fired("fn f() { println!(\"{}\", n); let c = name.clone(); }").is_empty());
+    }
+
+    #[test]
+    fn compound_assign_on_statements_only() {
+        assert_eq!(fired("fn f() { m = m + 1; }"), ["compound-assign"]);
+        assert_eq!(fired("fn f() { total = total * 2; }"), ["compound-assign"]);
+        assert!(fired("fn f() { let m = m + 1; }").is_empty());
+        assert!(fired("fn f() { m += 1; }").is_empty());
+        assert!(fired("fn f() { m = n + 1; }").is_empty());
+        assert!(fired("fn f() { m = m; }").is_empty());
+        assert_eq!(
+            fired("fn f() { m = m + a.len() + 1; }"),
+            ["compound-assign"]
+        );
+        assert_eq!(fired("fn f() { m = m - (a + b) }"), ["compound-assign"]);
+        assert_eq!(fired("fn f() { m = m * -k; }"), ["compound-assign"]);
+        assert!(fired("fn f() { x = x * 2 + 1; }").is_empty());
+        assert!(fired("fn f() { x = x - a + b; }").is_empty());
+        assert!(fired("fn f() { x = x - a - b; }").is_empty());
+        assert!(fired("fn f() { x = x + y[i] * 2; }").is_empty());
+    }
+
+    #[test]
+    fn findings_point_at_the_flagged_token() {
+        let findings = lint("fn f() {\n    let n = x.unwrap();\n}");
+        assert_eq!(findings.len(), 1);
+        assert_eq!((findings[0].line, findings[0].column), (2, 15));
+    }
+
+    #[test]
+    fn rules_name_real_lesson_sections() {
+        let registry = lessons::registry();
+        let mut problems = Vec::new();
+        for rule in RULES {
+            let label = format!("lint rule '{}'", rule.id);
+            validate::check_lesson_section(
+                &registry,
+                &label,
+                rule.lesson,
+                rule.section,
+                &mut problems,
+            );
+        }
+        assert_eq!(problems, Vec::<String>::new());
+    }
+}
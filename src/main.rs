@@ -1,34 +1,128 @@
-/// ============================================================================
-/// 🦀 RUST LEARNING GUIDE - Modular Organization
-/// ============================================================================
-/// This project demonstrates Rust concepts organized into a nested module.
-///
-/// Project Structure:
-/// src/
-/// ├── main.rs              → Entry point
-/// └── lessons/             → All learning modules
-///     ├── mod.rs           → Module declarations & run_all_lessons()
-///     ├── basics.rs        → Variables, arithmetic, conditionals, loops
-///     ├── arrays.rs        → Arrays and indexing
-///     ├── ownership.rs     → Ownership deep dive, functions & ownership
-///     ├── borrowing.rs     → Borrowing basics, borrowing with functions
-///     ├── structs.rs       → Structs with ownership & borrowing
-///     ├── vectors.rs       → Vectors with ownership & borrowing
-///     ├── enums.rs         → Enums and pattern matching
-///     ├── iterators.rs     → Iterators and functional combinators
-///     └── option_result.rs → Option<T> and Result<T, E> types
-/// ============================================================================
+//! ============================================================================
+//! 🦀 RUST LEARNING GUIDE - Modular Organization
+//! ============================================================================
+//! This project demonstrates Rust concepts organized into a nested module.
+//!
+//! Project Structure:
+//! src/
+//! ├── main.rs              → Entry point
+//! ├── cli.rs               → Command-line parsing (list / run / help)
+//! ├── validate.rs          → Startup checks for lesson metadata
+//! ├── content.rs           → Typed lesson content blocks (heading, section, table, ...)
+//! ├── table.rs             → Tables with Unicode width-aware alignment (box / ASCII / Markdown)
+//! ├── output.rs            → Output sinks lessons emit blocks into (stdout / capture / null)
+//! ├── render/              → Block renderers (terminal, Markdown, HTML)
+//! ├── source.rs            → Reads lesson code & doc comments back from the source
+//! ├── menu.rs              → Full-screen lesson browser (raw ANSI, stty) with a prompt fallback
+//! ├── step.rs              → `run --step`: one section at a time, back/quit, resume point
+//! ├── export.rs            → Markdown / static HTML export of all lessons
+//! ├── graph.rs             → Lesson prerequisite graph: cycle check, topological order, DOT
+//! ├── progress.rs          → Learner progress store (completed lessons, quiz scores)
+//! ├── quiz.rs              → Quiz engine: seeded shuffle, answer checking, scoring
+//! ├── review.rs            → Glossary flashcards scheduled by spaced repetition (SM-2)
+//! ├── predict.rs           → Predict-the-output mode: hide a section's output, diff the guess
+//! ├── json.rs              → Minimal JSON parser (for rustc diagnostics)
+//! ├── rustc.rs             → Runs the local rustc on snippets, parses its JSON diagnostics
+//! ├── challenge.rs         → "Will it compile?" snippets, answered then checked by rustc
+//! ├── explain.rs           → Maps rustc error codes / messages to lesson sections
+//! ├── lexer.rs             → Hand-written Rust tokenizer (idents, literals, comments, operators)
+//! ├── highlight.rs         → ANSI syntax highlighting for code shown in the terminal
+//! ├── glossary.rs          → Glossary terms & cheat sheet from Key Concepts lists and tables
+//! ├── search.rs            → In-memory search index over lesson titles, sections, Key Concepts
+//! ├── lint.rs              → Beginner-pattern rules over tokens, each linked to a lesson
+//! ├── roadmap.rs           → learning_curve.md parser, item → lesson links, checkbox sync
+//! ├── diff.rs              → Line diff (LCS) for snapshots and predictions
+//! ├── snapshot.rs          → Golden-output tests (snapshots/*.snap)
+//! ├── compile_fail.rs      → Tests that compile_fail/*.rs snippets fail with the expected rustc error
+//! ├── exercises/           → Function stubs for the learner + hidden test cases
+//! │   ├── mod.rs           → Exercise trait, case runner (catch_unwind), check()
+//! │   ├── option.rs        → Stubs: find_first_even, half_if_even
+//! │   ├── iterators.rs     → Stub: sum_even_squares
+//! │   ├── collections.rs   → Stub: word_counts
+//! │   └── checks.rs        → Hidden test cases for every stub
+//! └── lessons/             → All learning modules
+//!     ├── mod.rs           → Lesson trait, metadata, registry & run_all_lessons()
+//!     │                      (each lesson module also holds its quiz questions)
+//!     ├── basics.rs        → Variables, arithmetic, conditionals, loops
+//!     ├── arrays.rs        → Arrays and indexing
+//!     ├── ownership.rs     → Ownership deep dive, functions & ownership
+//!     ├── borrowing.rs     → Borrowing basics, borrowing with functions
+//!     ├── structs.rs       → Structs with ownership & borrowing
+//!     ├── vectors.rs       → Vectors with ownership & borrowing
+//!     ├── enums.rs         → Enums and pattern matching
+//!     ├── iterators.rs     → Iterators and functional combinators
+//!     ├── option_result.rs → Option<T> and Result<T, E> types
+//!     └── collection_ds.rs → Collections and data structures
+//! ============================================================================
 
+// Lessons deliberately show beginner idioms (`&String`, index loops, ...)
+// so learners can compare them with the idiomatic versions.
+#[allow(
+    clippy::ptr_arg,
+    clippy::needless_range_loop,
+    clippy::assign_op_pattern,
+    clippy::manual_find,
+    clippy::useless_vec,
+    clippy::vec_init_then_push,
+    clippy::unnecessary_literal_unwrap,
+    // Lesson docs are banners and tables laid out for `source` to read back
+    clippy::empty_line_after_doc_comments,
+    clippy::doc_lazy_continuation
+)]
 mod lessons;
 
-fn main() {
-    // Run all lessons from the lessons module
-    lessons::run_all_lessons();
+mod challenge;
+mod cli;
+#[cfg(test)]
+mod compile_fail;
+mod content;
+mod diff;
+mod exercises;
+mod explain;
+mod export;
+mod glossary;
+mod graph;
+mod highlight;
+mod json;
+mod lexer;
+mod lint;
+mod menu;
+mod output;
+mod predict;
+mod progress;
+mod quiz;
+mod render;
+mod review;
+mod roadmap;
+mod rustc;
+mod search;
+mod source;
+mod step;
+#[cfg(test)]
+mod snapshot;
+mod table;
+mod validate;
 
-    // Or run individual lessons:
-    // lessons::basics::learn_variables_and_mutability();
-    // lessons::ownership::learn_ownership();
-    // lessons::enums::learn_enums_and_pattern_matching();
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    // Refuse to run anything if lesson numbering or registration has drifted
+    let registry = lessons::registry();
+    if let Err(report) = validate::validate(&registry) {
+        eprintln!("{}", report);
+        return ExitCode::FAILURE;
+    }
+
+    // No arguments runs every lesson; see `rust-lab help` for the rest
+    let result = cli::parse(std::env::args().skip(1))
+        .and_then(|command| cli::execute(command, &registry));
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::from(2)
+        }
+    }
 }
 
 
@@ -0,0 +1,828 @@
+//! ============================================================================
+//! MENU MODULE - Full-screen lesson browser (`rust-lab menu`)
+//! ============================================================================
+//! The lessons are listed phase by phase, as `run_all_lessons` shows them,
+//! with a ✓ in front of every completed lesson. Enter opens the selected
+//! lesson in a scrollable pane; reading it to the bottom records a run.
+//!
+//! | Key               | List                  | Lesson pane            |
+//! |-------------------|-----------------------|------------------------|
+//! | ↑ ↓ / `k` `j`     | Move the selection    | Scroll one line        |
+//! | PgUp PgDn / Space | Move a screen         | Scroll a screen        |
+//! | Home End          | First / last lesson   | Top / bottom           |
+//! | Enter → `l`       | Open the lesson       | —                      |
+//! | ← Esc `q`         | Quit                  | Back to the list       |
+//! | Ctrl-C            | Quit                  | Quit                   |
+//!
+//! No crates are needed: `stty raw -echo` switches the terminal to raw mode
+//! (and `stty <saved>` back, also on panic), `poll` waits for keys so that a
+//! resize redraws at once, and frames are ANSI escape sequences on the
+//! alternate screen. Each frame is drawn from the menu state alone
+//! (`Menu::draw`); keys only change that state (`Menu::handle`). When stdin
+//! or stdout is not a terminal, or raw mode is unavailable, the same
+//! menu is a numbered prompt instead (`prompt()`).
+//! ============================================================================
+
+use std::io::{self, BufRead, IsTerminal, Read, Write};
+use std::process::{Command, Stdio};
+
+use crate::highlight::Colors;
+use crate::lessons::{self, Lesson, Phase, Registry};
+use crate::output::CaptureOutput;
+use crate::render::{self, TerminalRenderer};
+use crate::table::{self, Style};
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const DIM: &str = "\x1b[2m";
+const INVERSE: &str = "\x1b[7m";
+
+/// A key press, decoded from the bytes the terminal sends
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Key {
+    Up,
+    Down,
+    Left,
+    Right,
+    PageUp,
+    PageDown,
+    Home,
+    End,
+    Enter,
+    Escape,
+    Backspace,
+    /// Ctrl-C or Ctrl-D (raw mode turns off the signals)
+    Interrupt,
+    Char(char),
+}
+
+/// Decodes the keys read from the terminal so far, returning them and the
+/// number of bytes they took. An escape sequence cut off at the end is left
+/// for the next read, unless `timed_out` says nothing more is coming: then
+/// its ESC is the Escape key.
+fn parse_keys(bytes: &[u8], timed_out: bool) -> (Vec<Key>, usize) {
+    let mut keys = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let (key, len) = match bytes[i..] {
+            // Parameters, then one final byte in `@`..=`~`
+            [0x1b, b'[' | b'O', ref rest @ ..] => {
+                match rest.iter().position(|b| (0x40..=0x7e).contains(b)) {
+                    Some(end) => (sequence_key(&rest[..=end]), end + 3),
+                    None if !timed_out => break,
+                    None => (Some(Key::Escape), 1),
+                }
+            }
+            [0x1b] if !timed_out => break,
+            [0x1b, ..] => (Some(Key::Escape), 1),
+            [b'\r' | b'\n', ..] => (Some(Key::Enter), 1),
+            [0x7f | 0x08, ..] => (Some(Key::Backspace), 1),
+            [0x03 | 0x04, ..] => (Some(Key::Interrupt), 1),
+            [byte, ..] if byte == b' ' || byte.is_ascii_graphic() => {
+                (Some(Key::Char(byte as char)), 1)
+            }
+            _ => (None, 1),
+        };
+        keys.extend(key);
+        i += len;
+    }
+    (keys, i)
+}
+
+/// The key of a complete `ESC [` or `ESC O` sequence, after those two bytes
+fn sequence_key(sequence: &[u8]) -> Option<Key> {
+    match sequence {
+        b"A" => Some(Key::Up),
+        b"B" => Some(Key::Down),
+        b"C" => Some(Key::Right),
+        b"D" => Some(Key::Left),
+        b"H" | b"1~" | b"7~" => Some(Key::Home),
+        b"F" | b"4~" | b"8~" => Some(Key::End),
+        b"5~" => Some(Key::PageUp),
+        b"6~" => Some(Key::PageDown),
+        _ => None,
+    }
+}
+
+/// `line` cut to `width` columns; escape sequences are kept and take no room
+fn clip(line: &str, width: usize) -> String {
+    let mut clipped = String::new();
+    let mut used = 0;
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            clipped.push(c);
+            for c in chars.by_ref() {
+                clipped.push(c);
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+            continue;
+        }
+        let w = table::display_width(c.encode_utf8(&mut [0; 4]));
+        if used + w > width {
+            break;
+        }
+        used += w;
+        clipped.push(c);
+    }
+    if clipped.contains('\x1b') {
+        clipped.push_str(RESET);
+    }
+    clipped
+}
+
+/// `left` and `right` on one line of `width` columns
+fn spread(left: &str, right: &str, width: usize) -> String {
+    let gap = width.saturating_sub(table::display_width(left) + table::display_width(right));
+    format!("{}{}{}", left, " ".repeat(gap.max(1)), right)
+}
+
+/// A lesson opened in the scrollable pane
+struct Pane {
+    lesson: usize,
+    lines: Vec<String>,
+    offset: usize,
+}
+
+/// The browser state: the lessons, which are completed, and what is shown
+pub struct Menu<'r> {
+    lessons: Vec<&'r dyn Lesson>,
+    completed: Vec<bool>,
+    /// Lessons read to the end during this session
+    read: Vec<usize>,
+    selected: usize,
+    pane: Option<Pane>,
+    colors: Colors,
+}
+
+impl<'r> Menu<'r> {
+    /// Lists the lessons phase by phase; `done` says which are completed
+    pub fn new(registry: &'r Registry, done: impl Fn(&dyn Lesson) -> bool, colors: Colors) -> Self {
+        let lessons: Vec<&dyn Lesson> = Phase::ALL
+            .into_iter()
+            .flat_map(|phase| registry.in_phase(phase))
+            .collect();
+        Menu {
+            completed: lessons.iter().map(|&lesson| done(lesson)).collect(),
+            lessons,
+            read: Vec::new(),
+            selected: 0,
+            pane: None,
+            colors,
+        }
+    }
+
+    /// The lessons read to the end during this session
+    pub fn read(&self) -> Vec<&'r dyn Lesson> {
+        self.read.iter().map(|&i| self.lessons[i]).collect()
+    }
+
+    fn mark_read(&mut self, lesson: usize) {
+        self.completed[lesson] = true;
+        if !self.read.contains(&lesson) {
+            self.read.push(lesson);
+        }
+    }
+
+    /// The lesson as `rust-lab run` prints it, line by line
+    fn lesson_lines(&self, lesson: usize) -> Vec<String> {
+        let mut capture = CaptureOutput::default();
+        lessons::run_lesson(self.lessons[lesson], &mut capture);
+        let mut renderer = TerminalRenderer::new(Style::for_terminal(), self.colors);
+        render::render_all(&mut renderer, &capture.into_blocks())
+            .trim_start_matches('\n')
+            .lines()
+            .map(String::from)
+            .collect()
+    }
+
+    /// Applies one key for a screen `height` lines high; `false` quits
+    fn handle(&mut self, key: Key, height: usize) -> bool {
+        let page = body_height(height);
+        let last = self.lessons.len() - 1;
+
+        let Some(pane) = &mut self.pane else {
+            match key {
+                Key::Up | Key::Char('k') => self.selected = self.selected.saturating_sub(1),
+                Key::Down | Key::Char('j') => self.selected = (self.selected + 1).min(last),
+                Key::PageUp => self.selected = self.selected.saturating_sub(page),
+                Key::PageDown | Key::Char(' ') => {
+                    self.selected = (self.selected + page).min(last);
+                }
+                Key::Home => self.selected = 0,
+                Key::End => self.selected = last,
+                Key::Enter | Key::Right | Key::Char('l') => {
+                    let lines = self.lesson_lines(self.selected);
+                    self.pane = Some(Pane {
+                        lesson: self.selected,
+                        lines,
+                        offset: 0,
+                    });
+                    self.check_read_to_end(height);
+                }
+                Key::Left | Key::Escape | Key::Interrupt | Key::Char('q') => return false,
+                _ => {}
+            }
+            return true;
+        };
+
+        let bottom = pane.lines.len().saturating_sub(page);
+        match key {
+            Key::Up | Key::Char('k') => pane.offset = pane.offset.saturating_sub(1),
+            Key::Down | Key::Char('j') | Key::Enter => pane.offset = (pane.offset + 1).min(bottom),
+            Key::PageUp | Key::Char('b') => pane.offset = pane.offset.saturating_sub(page),
+            Key::PageDown | Key::Char(' ') => pane.offset = (pane.offset + page).min(bottom),
+            Key::Home => pane.offset = 0,
+            Key::End => pane.offset = bottom,
+            Key::Left | Key::Escape | Key::Backspace | Key::Char('q' | 'h') => self.pane = None,
+            Key::Interrupt => return false,
+            _ => {}
+        }
+        self.check_read_to_end(height);
+        true
+    }
+
+    /// Marks the open lesson read once its last line has been on screen
+    fn check_read_to_end(&mut self, height: usize) {
+        if let Some(pane) = &self.pane
+            && pane.offset + body_height(height) >= pane.lines.len()
+        {
+            let lesson = pane.lesson;
+            self.mark_read(lesson);
+        }
+    }
+
+    /// One full frame: a title line, a rule, the body and a key help line
+    fn draw(&self, width: usize, height: usize) -> String {
+        let page = body_height(height);
+        let (title, body, help) = match &self.pane {
+            None => self.draw_list(width, page),
+            Some(pane) => self.draw_pane(pane, page),
+        };
+
+        let mut lines = vec![
+            format!("{}{}{}", BOLD, clip(&title, width), RESET),
+            "─".repeat(width),
+        ];
+        lines.extend(body);
+        lines.resize(height.saturating_sub(1), String::new());
+        lines.push(format!("{}{}{}", DIM, help, RESET));
+
+        // Home, then every line cleared to its end; `\r\n` because raw mode
+        // does not return the carriage on `\n`
+        let mut frame = String::from("\x1b[H");
+        for (i, line) in lines.iter().take(height).enumerate() {
+            if i > 0 {
+                frame.push_str("\r\n");
+            }
+            frame.push_str(&clip(line, width));
+            frame.push_str("\x1b[K");
+        }
+        frame.push_str("\x1b[J");
+        frame
+    }
+
+    fn draw_list(&self, width: usize, page: usize) -> (String, Vec<String>, String) {
+        let done = self.completed.iter().filter(|&&c| c).count();
+        let title = spread(
+            " 🦀 RUST LAB — Lessons",
+            &format!("{}/{} completed ", done, self.lessons.len()),
+            width,
+        );
+
+        let mut rows = Vec::new();
+        let mut selected_row = 0;
+        let mut previous_phase = None;
+        for (i, lesson) in self.lessons.iter().enumerate() {
+            let phase = lesson.phase();
+            if previous_phase != Some(phase) {
+                if previous_phase.is_some() {
+                    rows.push(String::new());
+                }
+                rows.push(format!(
+                    " {}Phase {} — {}{}",
+                    BOLD,
+                    phase.number(),
+                    phase.title(),
+                    RESET
+                ));
+                previous_phase = Some(phase);
+            }
+            let marker = if self.completed[i] { "✓" } else { " " };
+            let row = format!("  {} {:>3}  {}", marker, lesson.number(), lesson.title());
+            if i == self.selected {
+                selected_row = rows.len();
+                let row = format!("▸{}", &row[1..]);
+                rows.push(format!(
+                    "{}{}{}",
+                    INVERSE,
+                    table::pad(&row, width, table::Align::Left),
+                    RESET
+                ));
+            } else {
+                rows.push(row);
+            }
+        }
+
+        // Scroll just enough to keep the selection (and its phase title) visible
+        let top = (selected_row + 1).saturating_sub(page);
+        let body = rows.into_iter().skip(top).take(page).collect();
+        let help = " ↑/↓ move · PgUp/PgDn page · Enter open · q quit".to_string();
+        (title, body, help)
+    }
+
+    fn draw_pane(&self, pane: &Pane, page: usize) -> (String, Vec<String>, String) {
+        let lesson = self.lessons[pane.lesson];
+        let title = format!(" Lesson {}: {}", lesson.number(), lesson.title());
+        let body = pane
+            .lines
+            .iter()
+            .skip(pane.offset)
+            .take(page)
+            .cloned()
+            .collect();
+
+        let total = pane.lines.len();
+        let shown = (pane.offset + page).min(total);
+        let help = format!(
+            " ↑/↓ scroll · PgUp/PgDn page · ← or q back   lines {}–{} of {}",
+            (pane.offset + 1).min(total),
+            shown,
+            total
+        );
+        (title, body, help)
+    }
+}
+
+/// Lines between the title rule and the help line
+fn body_height(height: usize) -> usize {
+    height.saturating_sub(3).max(1)
+}
+
+/// Raw mode on the alternate screen; dropping it restores the terminal
+struct RawTerminal {
+    saved: String,
+}
+
+/// Runs `stty` on the terminal attached to stdin
+fn stty(args: &[&str]) -> io::Result<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .stderr(Stdio::null())
+        .output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(format!("stty {} failed", args.join(" "))));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+impl RawTerminal {
+    fn enter() -> io::Result<RawTerminal> {
+        let saved = stty(&["-g"])?;
+        stty(&["raw", "-echo"])?;
+        // Alternate screen, hidden cursor
+        print!("\x1b[?1049h\x1b[?25l");
+        io::stdout().flush()?;
+        Ok(RawTerminal { saved })
+    }
+
+    /// (columns, rows), 80×24 when the terminal does not say
+    fn size(&self) -> (usize, usize) {
+        let size = stty(&["size"]).unwrap_or_default();
+        let mut numbers = size.split_whitespace().filter_map(|n| n.parse().ok());
+        match (numbers.next(), numbers.next()) {
+            (Some(rows), Some(columns)) if rows > 0 && columns > 0 => (columns, rows),
+            _ => (80, 24),
+        }
+    }
+}
+
+/// Window size changes, reported by SIGWINCH
+#[cfg(unix)]
+mod resize {
+    use std::ffi::c_int;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    /// Not in `std`, and there is no libc crate to take it from; 28 is the
+    /// number on Linux, macOS and the BSDs
+    const SIGWINCH: c_int = 28;
+
+    static RESIZED: AtomicBool = AtomicBool::new(false);
+
+    // `sighandler_t signal(int, sighandler_t)`: the handler type is a
+    // pointer, so the previous handler comes back as a pointer-sized integer
+    unsafe extern "C" {
+        fn signal(signum: c_int, handler: extern "C" fn(c_int)) -> usize;
+    }
+
+    extern "C" fn on_resize(_: c_int) {
+        RESIZED.store(true, Ordering::Relaxed);
+    }
+
+    /// `signal()` installs the handler with SA_RESTART, so a blocked `read`
+    /// would sleep through a resize; the menu waits in `input::wait` instead,
+    /// and `poll` is never restarted after a handler
+    pub fn watch() {
+        // SAFETY: the handler only stores to an atomic
+        unsafe {
+            signal(SIGWINCH, on_resize);
+        }
+    }
+
+    /// Whether the window changed size since the last call
+    pub fn take() -> bool {
+        RESIZED.swap(false, Ordering::Relaxed)
+    }
+}
+
+#[cfg(not(unix))]
+mod resize {
+    pub fn watch() {}
+
+    pub fn take() -> bool {
+        false
+    }
+}
+
+/// Waiting for stdin with a timeout, through `poll`
+#[cfg(unix)]
+mod input {
+    use std::ffi::{c_int, c_short};
+    use std::io;
+
+    /// `struct pollfd` from <poll.h>
+    #[repr(C)]
+    struct PollFd {
+        fd: c_int,
+        events: c_short,
+        revents: c_short,
+    }
+
+    /// Data to read; the same bit everywhere `poll` exists
+    const POLLIN: c_short = 1;
+
+    /// `nfds_t` is an `unsigned long` on Linux and an `unsigned int` elsewhere
+    #[cfg(target_os = "linux")]
+    type Nfds = std::ffi::c_ulong;
+    #[cfg(not(target_os = "linux"))]
+    type Nfds = std::ffi::c_uint;
+
+    unsafe extern "C" {
+        fn poll(fds: *mut PollFd, nfds: Nfds, timeout: c_int) -> c_int;
+    }
+
+    /// Whether stdin has input (or hit end of file) within `millis`; a signal
+    /// arriving first is an `Interrupted` error
+    pub fn wait(millis: c_int) -> io::Result<bool> {
+        let mut stdin = PollFd {
+            fd: 0,
+            events: POLLIN,
+            revents: 0,
+        };
+        // SAFETY: `stdin` is one valid pollfd that outlives the call
+        match unsafe { poll(&mut stdin, 1, millis) } {
+            -1 => Err(io::Error::last_os_error()),
+            0 => Ok(false),
+            _ => Ok(true),
+        }
+    }
+}
+
+#[cfg(not(unix))]
+mod input {
+    use std::ffi::c_int;
+    use std::io;
+
+    /// Without `poll` a read simply blocks
+    pub fn wait(_millis: c_int) -> io::Result<bool> {
+        Ok(true)
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        print!("\x1b[?25h\x1b[?1049l");
+        let _ = io::stdout().flush();
+        let _ = stty(&[&self.saved]);
+    }
+}
+
+/// Browses full screen when stdin and stdout are terminals that support raw
+/// mode, with the numbered prompt otherwise
+pub fn run(menu: &mut Menu) -> io::Result<()> {
+    let dumb = std::env::var("TERM").is_ok_and(|term| term == "dumb");
+    if !dumb
+        && io::stdin().is_terminal()
+        && io::stdout().is_terminal()
+        && let Ok(terminal) = RawTerminal::enter()
+    {
+        return full_screen(menu, &terminal);
+    }
+    prompt(menu, &mut io::stdin().lock(), &mut io::stdout())
+}
+
+fn full_screen(menu: &mut Menu, terminal: &RawTerminal) -> io::Result<()> {
+    let mut stdin = io::stdin().lock();
+    let mut stdout = io::stdout().lock();
+    let mut buffer = [0; 64];
+    let mut pending = Vec::new();
+    resize::watch();
+    let (mut width, mut height) = terminal.size();
+    let mut redraw = true;
+    loop {
+        if resize::take() {
+            (width, height) = terminal.size();
+            redraw = true;
+        }
+        if redraw {
+            stdout.write_all(menu.draw(width, height).as_bytes())?;
+            stdout.flush()?;
+            redraw = false;
+        }
+
+        // A resize interrupts the wait; the timeout catches one that lands
+        // between `resize::take` and the wait
+        let read = match input::wait(250) {
+            Ok(true) => stdin.read(&mut buffer),
+            Ok(false) => continue,
+            Err(error) => Err(error),
+        };
+        let n = match read {
+            Ok(n) => n,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        };
+        if n == 0 {
+            return Ok(());
+        }
+        pending.extend_from_slice(&buffer[..n]);
+        let (mut keys, mut used) = parse_keys(&pending, false);
+        // A key's escape sequence can be split across reads; a lone ESC that
+        // nothing follows within a tenth of a second is the Escape key
+        while used < pending.len() {
+            let n = match input::wait(100) {
+                Ok(true) => stdin.read(&mut buffer)?,
+                Ok(false) => 0,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error),
+            };
+            pending.extend_from_slice(&buffer[..n]);
+            (keys, used) = parse_keys(&pending, n == 0);
+        }
+        pending.clear();
+        for key in keys {
+            if !menu.handle(key, height) {
+                return Ok(());
+            }
+        }
+        redraw = true;
+    }
+}
+
+/// The menu as a numbered list: type a lesson number (or id) to print it
+pub fn prompt(menu: &mut Menu, input: &mut dyn BufRead, out: &mut dyn Write) -> io::Result<()> {
+    let list = |menu: &Menu, out: &mut dyn Write| -> io::Result<()> {
+        let mut previous_phase = None;
+        for (i, lesson) in menu.lessons.iter().enumerate() {
+            let phase = lesson.phase();
+            if previous_phase != Some(phase) {
+                writeln!(out, "\nPhase {} — {}", phase.number(), phase.title())?;
+                previous_phase = Some(phase);
+            }
+            let marker = if menu.completed[i] { "✓" } else { " " };
+            writeln!(
+                out,
+                "  {} {:>3}  {}",
+                marker,
+                lesson.number(),
+                lesson.title()
+            )?;
+        }
+        Ok(())
+    };
+
+    list(menu, out)?;
+    loop {
+        writeln!(out, "\nLesson number to read (Enter: list again, q: quit)")?;
+        write!(out, "> ")?;
+        out.flush()?;
+        let mut reply = String::new();
+        if input.read_line(&mut reply)? == 0 {
+            return Ok(());
+        }
+        let reply = reply.trim();
+        let found = menu
+            .lessons
+            .iter()
+            .position(|l| l.number().to_string() == reply || l.id() == reply);
+        match (reply, found) {
+            ("q" | "quit", _) => return Ok(()),
+            ("", _) => list(menu, out)?,
+            (_, Some(i)) => {
+                write!(out, "{}", menu.lesson_lines(i).join("\n"))?;
+                writeln!(out)?;
+                menu.mark_read(i);
+            }
+            (_, None) => writeln!(
+                out,
+                "  (no lesson '{}'; type a number from the list)",
+                reply
+            )?,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lessons::LessonMeta;
+    use crate::output::{Output, outln};
+
+    /// A lesson printing `lines` lines of output
+    struct Fake(&'static LessonMeta, usize);
+
+    impl Lesson for Fake {
+        fn meta(&self) -> &'static LessonMeta {
+            self.0
+        }
+
+        fn run(&self, out: &mut dyn Output) {
+            for line in 0..self.1 {
+                outln!(out, "line {}", line);
+            }
+        }
+    }
+
+    const fn meta(id: &'static str, number: u32, phase: Phase) -> LessonMeta {
+        LessonMeta {
+            id,
+            number,
+            title: id,
+            phase,
+            module: "basics",
+            entry: "learn",
+            requires: &[],
+        }
+    }
+
+    static METAS: [LessonMeta; 3] = [
+        meta("short", 1, Phase::Basics),
+        meta("long", 2, Phase::Basics),
+        meta("later", 3, Phase::DataStructures),
+    ];
+
+    fn registry() -> Registry {
+        let mut registry = Registry::default();
+        registry.register(Fake(&METAS[0], 1));
+        registry.register(Fake(&METAS[1], 40));
+        registry.register(Fake(&METAS[2], 1));
+        registry
+    }
+
+    fn ids(lessons: &[&dyn Lesson]) -> Vec<&'static str> {
+        lessons.iter().map(|lesson| lesson.id()).collect()
+    }
+
+    #[test]
+    fn keys_are_decoded() {
+        assert_eq!(
+            parse_keys(b"\x1b[A\x1b[B\x1bOC\x1b[D", false),
+            (vec![Key::Up, Key::Down, Key::Right, Key::Left], 12)
+        );
+        assert_eq!(
+            parse_keys(b"\x1b[5~\x1b[6~\x1b[1~\x1b[F", false),
+            (vec![Key::PageUp, Key::PageDown, Key::Home, Key::End], 15)
+        );
+        assert_eq!(
+            parse_keys(b"jq \r\x7f\x03\x1b[99Z", false),
+            (
+                vec![
+                    Key::Char('j'),
+                    Key::Char('q'),
+                    Key::Char(' '),
+                    Key::Enter,
+                    Key::Backspace,
+                    Key::Interrupt,
+                ],
+                11
+            )
+        );
+    }
+
+    #[test]
+    fn cut_off_sequences_wait_for_the_rest() {
+        assert_eq!(parse_keys(b"j\x1b", false), (vec![Key::Char('j')], 1));
+        assert_eq!(parse_keys(b"j\x1b[5", false), (vec![Key::Char('j')], 1));
+        assert_eq!(parse_keys(b"\x1b[5~", false), (vec![Key::PageUp], 4));
+    }
+
+    #[test]
+    fn a_lone_escape_is_the_escape_key_once_timed_out() {
+        assert_eq!(parse_keys(b"\x1b", true), (vec![Key::Escape], 1));
+        assert_eq!(
+            parse_keys(b"\x1b[", true),
+            (vec![Key::Escape, Key::Char('[')], 2)
+        );
+        assert_eq!(
+            parse_keys(b"\x1bq", false),
+            (vec![Key::Escape, Key::Char('q')], 2)
+        );
+    }
+
+    #[test]
+    fn clip_counts_columns_not_bytes() {
+        assert_eq!(clip("hello", 3), "hel");
+        assert_eq!(clip("🦀🦀🦀", 5), "🦀🦀");
+        assert_eq!(clip("日本語", 6), "日本語");
+        assert_eq!(
+            clip("\x1b[1mbold\x1b[0m text", 6),
+            "\x1b[1mbold\x1b[0m t\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn list_moves_within_the_lessons() {
+        let registry = registry();
+        let mut menu = Menu::new(&registry, |_| false, Colors::Plain);
+        assert!(menu.handle(Key::Up, 10));
+        assert_eq!(menu.selected, 0);
+        menu.handle(Key::End, 10);
+        assert_eq!(menu.selected, 2);
+        menu.handle(Key::Down, 10);
+        assert_eq!(menu.selected, 2);
+        menu.handle(Key::Char('k'), 10);
+        assert_eq!(menu.selected, 1);
+        menu.handle(Key::PageUp, 10);
+        assert_eq!(menu.selected, 0);
+        assert!(!menu.handle(Key::Char('q'), 10));
+    }
+
+    #[test]
+    fn scrolling_to_the_bottom_marks_a_lesson_read() {
+        let registry = registry();
+        let mut menu = Menu::new(&registry, |_| false, Colors::Plain);
+        menu.handle(Key::Down, 10);
+        menu.handle(Key::Enter, 10);
+        let lines = menu.pane.as_ref().unwrap().lines.len();
+        assert!(lines > body_height(10));
+        assert!(menu.read().is_empty());
+
+        menu.handle(Key::PageDown, 10);
+        assert_eq!(menu.pane.as_ref().unwrap().offset, body_height(10));
+        menu.handle(Key::Up, 10);
+        assert_eq!(menu.pane.as_ref().unwrap().offset, body_height(10) - 1);
+        assert!(menu.read().is_empty());
+
+        menu.handle(Key::End, 10);
+        assert_eq!(menu.pane.as_ref().unwrap().offset, lines - body_height(10));
+        menu.handle(Key::Down, 10);
+        assert_eq!(menu.pane.as_ref().unwrap().offset, lines - body_height(10));
+        assert_eq!(ids(&menu.read()), ["long"]);
+        assert!(menu.completed[1]);
+
+        assert!(menu.handle(Key::Escape, 10));
+        assert!(menu.pane.is_none());
+        assert!(!menu.handle(Key::Escape, 10));
+    }
+
+    #[test]
+    fn a_lesson_that_fits_is_read_when_opened() {
+        let registry = registry();
+        let mut menu = Menu::new(&registry, |_| false, Colors::Plain);
+        menu.handle(Key::Enter, 24);
+        menu.handle(Key::Home, 24);
+        menu.handle(Key::Left, 24);
+        menu.handle(Key::Enter, 24);
+        assert_eq!(ids(&menu.read()), ["short"]);
+    }
+
+    #[test]
+    fn list_frame_shows_phases_and_completion() {
+        let registry = registry();
+        let mut menu = Menu::new(&registry, |lesson| lesson.id() == "later", Colors::Plain);
+        menu.handle(Key::Down, 12);
+        let frame = menu.draw(50, 12);
+        assert!(frame.contains("1/3 completed"));
+        assert!(frame.contains("Phase 1"));
+        assert!(frame.contains("▸     2  long"));
+        assert!(frame.contains("  ✓   3  later"));
+    }
+
+    #[test]
+    fn prompt_prints_lessons_by_number_or_id() {
+        let registry = registry();
+        let mut menu = Menu::new(&registry, |_| false, Colors::Plain);
+        let mut out = Vec::new();
+        prompt(&mut menu, &mut &b"3\nshort\nnine\n\nq\n"[..], &mut out).unwrap();
+        let printed = String::from_utf8(out).unwrap();
+
+        assert_eq!(ids(&menu.read()), ["later", "short"]);
+        assert_eq!(printed.matches("line 0").count(), 2);
+        assert!(printed.contains("(no lesson 'nine'; type a number from the list)"));
+        assert_eq!(printed.matches("  ✓   3  later").count(), 1);
+        assert_eq!(printed.matches("Phase 1").count(), 2);
+    }
+}
@@ -0,0 +1,142 @@
+//! ============================================================================
+//! OUTPUT MODULE - Where lesson output goes
+//! ============================================================================
+//! Lessons never call `println!` directly; they emit content `Block`s through
+//! `&mut dyn Output` (plain example output via the `outln!` macro), so the same
+//! lesson can be printed, captured or silenced.
+//!
+//! | Sink            | Behavior                                  |
+//! |-----------------|-------------------------------------------|
+//! | `StdoutOutput`  | Renders each block for the terminal       |
+//! | `CaptureOutput` | Keeps blocks in memory (tests, exporters) |
+//! | `NullOutput`    | Discards everything                       |
+//! | `SourceOutput`  | Adds each section's code, then passes on  |
+//! ============================================================================
+
+use crate::content::Block;
+use crate::highlight::Colors;
+use crate::render::{self, Renderer, TerminalRenderer};
+use crate::source;
+use crate::table::Style;
+
+/// A destination for lesson content
+pub trait Output {
+    /// Receives one block of content
+    fn emit(&mut self, block: Block);
+
+    /// Writes one line printed by example code; embedded `\n` start new lines
+    fn write_line(&mut self, line: &str) {
+        self.emit(Block::Output(line.to_string()));
+    }
+
+    /// Starts a `--- Title ---` section
+    fn section(&mut self, title: &str) {
+        self.emit(Block::Section(title.to_string()));
+    }
+}
+
+/// `println!` for an `Output`: `outln!(out)` or `outln!(out, "x = {}", x)`
+macro_rules! outln {
+    ($out:expr) => {
+        $out.write_line("")
+    };
+    ($out:expr, $($arg:tt)*) => {
+        $out.write_line(&format!($($arg)*))
+    };
+}
+pub(crate) use outln;
+
+/// Renders each block for the terminal and prints it immediately
+pub struct StdoutOutput {
+    renderer: TerminalRenderer,
+}
+
+impl Default for StdoutOutput {
+    fn default() -> Self {
+        StdoutOutput {
+            renderer: TerminalRenderer::new(Style::for_terminal(), Colors::for_stdout()),
+        }
+    }
+}
+
+impl Output for StdoutOutput {
+    fn emit(&mut self, block: Block) {
+        print!("{}", self.renderer.render(&block));
+    }
+}
+
+impl Drop for StdoutOutput {
+    /// Prints whatever the renderer still holds open (a summary's closing line)
+    fn drop(&mut self) {
+        print!("{}", self.renderer.finish());
+    }
+}
+
+/// Records every block in memory
+#[derive(Default)]
+pub struct CaptureOutput {
+    blocks: Vec<Block>,
+}
+
+impl CaptureOutput {
+    /// The captured blocks, for re-rendering in another format
+    pub fn into_blocks(self) -> Vec<Block> {
+        self.blocks
+    }
+
+    /// Captured output as one string, exactly as `StdoutOutput` would print it
+    #[cfg_attr(not(test), allow(dead_code))] // only the snapshot tests need text
+    pub fn text(&self) -> String {
+        render::render_all(&mut TerminalRenderer::default(), &self.blocks)
+    }
+}
+
+impl Output for CaptureOutput {
+    fn emit(&mut self, block: Block) {
+        self.blocks.push(block);
+    }
+}
+
+/// Discards everything
+pub struct NullOutput;
+
+impl Output for NullOutput {
+    fn emit(&mut self, _block: Block) {}
+}
+
+/// Wraps another sink and puts the code of each section in front of its
+/// output (`rust-lab run --show-source`)
+///
+/// The code comes from the lesson's embedded module source: the lesson
+/// heading is followed by the code before the first `out.section(..)` call,
+/// each section by the code it runs (`source::section_code`).
+pub struct SourceOutput<'a> {
+    inner: &'a mut dyn Output,
+    source: &'static str,
+    entry: &'static str,
+}
+
+impl<'a> SourceOutput<'a> {
+    /// Shows code from the function `entry` of the module text `source`
+    pub fn new(inner: &'a mut dyn Output, source: &'static str, entry: &'static str) -> Self {
+        SourceOutput {
+            inner,
+            source,
+            entry,
+        }
+    }
+}
+
+impl Output for SourceOutput<'_> {
+    fn emit(&mut self, block: Block) {
+        let code = match &block {
+            Block::Heading { .. } => source::section_code(self.source, self.entry, None),
+            Block::Section(title) => source::section_code(self.source, self.entry, Some(title)),
+            _ => None,
+        };
+        self.inner.emit(block);
+        if let Some(code) = code {
+            self.inner.emit(Block::CodeSample(code));
+        }
+    }
+}
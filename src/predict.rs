@@ -0,0 +1,285 @@
+//! ============================================================================
+//! PREDICT MODULE - Guess a section's output before seeing it
+//! ============================================================================
+//! `rust-lab predict <lesson>` runs the lesson into a `CaptureOutput`, then for
+//! each section shows the code but hides what it printed. The learner types
+//! the lines they expect, ends with a line holding only `.`, and gets a diff
+//! (a `q` instead of the first line stops the session):
+//!
+//! | Mark | Meaning                                  |
+//! |------|------------------------------------------|
+//! | `✓`  | Predicted correctly                      |
+//! | `-`  | Printed by the code, missing from yours  |
+//! | `+`  | In your prediction, never printed        |
+//!
+//! A section is everything between two `out.section(..)` calls; sections that
+//! print nothing are skipped. Blank lines and trailing spaces are ignored on
+//! both sides, and a section scores matching lines / lines on the longer side.
+//! ============================================================================
+
+use std::io::{self, BufRead, Write};
+
+use crate::content::Block;
+use crate::diff::{self, Edit};
+use crate::highlight::{self, Colors};
+use crate::lessons::{self, Lesson};
+use crate::output::CaptureOutput;
+use crate::progress::Score;
+use crate::source;
+
+/// The output of one lesson section
+#[derive(Debug)]
+pub struct Segment {
+    /// `None` for output printed before the first section
+    pub title: Option<String>,
+    pub lines: Vec<String>,
+}
+
+/// Runs the lesson silently and splits what it printed by section
+pub fn segments(lesson: &dyn Lesson) -> Vec<Segment> {
+    let mut capture = CaptureOutput::default();
+    lesson.run(&mut capture);
+
+    let mut segments = vec![Segment {
+        title: None,
+        lines: Vec::new(),
+    }];
+    for block in capture.into_blocks() {
+        match block {
+            Block::Section(title) => segments.push(Segment {
+                title: Some(title),
+                lines: Vec::new(),
+            }),
+            Block::Output(text) => {
+                let current = segments.last_mut().expect("starts with one segment");
+                current.lines.extend(
+                    text.lines()
+                        .map(str::trim_end)
+                        .filter(|line| !line.is_empty())
+                        .map(String::from),
+                );
+            }
+            _ => {}
+        }
+    }
+    segments.retain(|segment| !segment.lines.is_empty());
+    segments
+}
+
+/// Reads prediction lines up to a lone `.`; `None` if the input ended first
+/// without a single line, or the first line is a lone `q`
+fn read_prediction(input: &mut dyn BufRead) -> io::Result<Option<Vec<String>>> {
+    let mut lines = Vec::new();
+    loop {
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(if lines.is_empty() { None } else { Some(lines) });
+        }
+        let line = line.trim_end();
+        if line == "." {
+            return Ok(Some(lines));
+        }
+        if line == "q" && lines.is_empty() {
+            return Ok(None);
+        }
+        if !line.is_empty() {
+            lines.push(line.to_string());
+        }
+    }
+}
+
+/// Asks for a prediction of every section (or only `section`, numbered from
+/// 1 like the segments) and prints the diffs
+///
+/// Returns `None` when the input ends before the last section or the learner
+/// stops with `q`.
+pub fn run(
+    lesson: &dyn Lesson,
+    section: Option<usize>,
+    colors: Colors,
+    input: &mut dyn BufRead,
+    out: &mut dyn Write,
+) -> io::Result<Option<Score>> {
+    let segments = segments(lesson);
+    let module_source = lessons::module_source(lesson.meta().module);
+    let total_sections = segments.len();
+    let mut score = Score {
+        correct: 0,
+        total: 0,
+    };
+
+    for (n, segment) in segments.iter().enumerate() {
+        if section.is_some_and(|wanted| wanted != n + 1) {
+            continue;
+        }
+
+        let title = segment.title.as_deref().unwrap_or("Introduction");
+        writeln!(
+            out,
+            "\n--- Section {}/{}: {} ---",
+            n + 1,
+            total_sections,
+            title
+        )?;
+        let code = module_source.and_then(|module| {
+            source::section_code(module, lesson.meta().entry, segment.title.as_deref())
+        });
+        match code {
+            Some(code) => write!(out, "{}", highlight::indented(&code, colors))?,
+            None => writeln!(out, "    (source not available)")?,
+        }
+
+        writeln!(
+            out,
+            "\nWhat does it print? ({} non-blank lines; finish with a line containing only `.`, or type q to stop)",
+            segment.lines.len()
+        )?;
+        out.flush()?;
+        let Some(prediction) = read_prediction(input)? else {
+            writeln!(out, "\nStopped before the last section.")?;
+            return Ok(None);
+        };
+
+        let actual = segment.lines.join("\n");
+        let predicted = prediction.join("\n");
+        let edits = diff::diff_lines(&actual, &predicted);
+        let matched = edits.iter().filter(|e| matches!(e, Edit::Same(_))).count();
+        let lines = segment.lines.len().max(prediction.len());
+
+        writeln!(out)?;
+        for edit in &edits {
+            match edit {
+                Edit::Same(line) => writeln!(out, "  ✓ {}", line)?,
+                Edit::Removed(line) => writeln!(out, "  - {}", line)?,
+                Edit::Added(line) => writeln!(out, "  + {}", line)?,
+            }
+        }
+        if matched == lines {
+            writeln!(out, "✅ Exactly right! ({}/{})", matched, lines)?;
+        } else {
+            writeln!(
+                out,
+                "{}/{} lines right (- printed but not predicted, + predicted but not printed)",
+                matched, lines
+            )?;
+        }
+
+        score.correct += matched as u32;
+        score.total += lines as u32;
+    }
+
+    writeln!(out, "\nScore: {}/{} lines", score.correct, score.total)?;
+    Ok(Some(score))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lessons::{LessonMeta, Phase};
+    use crate::output::{Output, outln};
+
+    struct Printing;
+
+    impl Lesson for Printing {
+        fn meta(&self) -> &'static LessonMeta {
+            static META: LessonMeta = LessonMeta {
+                id: "printing",
+                number: 1,
+                title: "Printing",
+                phase: Phase::Basics,
+                module: "basics",
+                entry: "learn_printing",
+                requires: &[],
+            };
+            &META
+        }
+
+        fn run(&self, out: &mut dyn Output) {
+            outln!(out, "intro");
+            out.section("Quiet");
+            out.section("Numbers");
+            outln!(out, "1\n\n2  ");
+            outln!(out, "3");
+            out.section("Done");
+            outln!(out, "done");
+        }
+    }
+
+    fn predict(section: Option<usize>, typed: &str) -> (Option<Score>, String) {
+        let mut out = Vec::new();
+        let score = run(
+            &Printing,
+            section,
+            Colors::Plain,
+            &mut typed.as_bytes(),
+            &mut out,
+        )
+        .unwrap();
+        (score, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn silent_sections_are_skipped_and_blank_lines_dropped() {
+        let segments = segments(&Printing);
+        let titles: Vec<Option<&str>> = segments.iter().map(|s| s.title.as_deref()).collect();
+        assert_eq!(titles, [None, Some("Numbers"), Some("Done")]);
+        assert_eq!(segments[1].lines, ["1", "2", "3"]);
+    }
+
+    #[test]
+    fn predictions_are_diffed_and_scored() {
+        let (score, printed) = predict(Some(2), "1\n\n3\n4\n.\n");
+        assert_eq!(
+            score,
+            Some(Score {
+                correct: 2,
+                total: 3
+            })
+        );
+        assert!(printed.contains("--- Section 2/3: Numbers ---"));
+        assert!(printed.contains("  ✓ 1\n  - 2\n  ✓ 3\n  + 4\n"));
+        assert!(printed.contains("2/3 lines right"));
+        assert!(!printed.contains("Section 1/3"));
+    }
+
+    #[test]
+    fn every_section_is_asked_in_turn() {
+        let (score, printed) = predict(None, "intro\n.\n1\n2\n3\n.\nnope\n.\n");
+        assert_eq!(
+            score,
+            Some(Score {
+                correct: 4,
+                total: 5
+            })
+        );
+        assert_eq!(printed.matches("✅ Exactly right!").count(), 2);
+        assert!(printed.ends_with("\nScore: 4/5 lines\n"));
+    }
+
+    #[test]
+    fn empty_input_stops_the_session() {
+        let (score, printed) = predict(None, "");
+        assert_eq!(score, None);
+        assert!(printed.contains("Stopped before the last section."));
+        assert!(!printed.contains("Score:"));
+    }
+
+    #[test]
+    fn q_stops_but_only_as_the_first_line() {
+        let (score, _) = predict(None, "intro\n.\nq\n");
+        assert_eq!(score, None);
+
+        let (score, _) = predict(Some(3), "q\n");
+        assert_eq!(score, None);
+
+        let (score, printed) = predict(Some(3), "done\nq\n.\n");
+        assert_eq!(
+            score,
+            Some(Score {
+                correct: 1,
+                total: 2
+            })
+        );
+        assert!(printed.contains("  + q\n"));
+    }
+}
@@ -0,0 +1,461 @@
+//! ============================================================================
+//! PROGRESS MODULE - Remembering what the learner has done
+//! ============================================================================
+//! Progress lives in one small text file, `progress.txt`, inside the data
+//! directory (first match wins):
+//! - `$RUST_LAB_HOME`
+//! - `$XDG_DATA_HOME/rust-lab`
+//! - `$HOME/.local/share/rust-lab`
+//!
+//! File format (one line per lesson, `key=value` fields, any order):
+//!
+//!   rust-lab-progress 1
+//!   lesson ownership completed=1760745600 last_run=1760832000 runs=3 quiz=4/5
+//!
+//! | Field       | Meaning                                       |
+//! |-------------|-----------------------------------------------|
+//! | `completed` | Unix time the lesson was first run to the end |
+//! | `last_run`  | Unix time of the most recent run              |
+//! | `runs`      | How many times the lesson was run             |
+//! | `quiz`      | Best quiz score, `correct/total`              |
+//! | `viewed`    | Page `--step` stopped at (0 = introduction)   |
+//!
+//! A missing file is an empty history. A damaged file is reported as a
+//! `ProgressError::Corrupt` (never a panic), and `rust-lab reset` removes it.
+//! Other data files (`cards.txt`) share the directory and the file helpers
+//! at the bottom of this module.
+//! ============================================================================
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const HEADER: &str = "rust-lab-progress 1";
+const FILE_NAME: &str = "progress.txt";
+
+/// Everything that can go wrong while loading or saving progress
+#[derive(Debug)]
+pub enum ProgressError {
+    NoDataDir,
+    Io {
+        path: PathBuf,
+        error: io::Error,
+    },
+    Corrupt {
+        path: PathBuf,
+        line: usize,
+        reason: String,
+    },
+}
+
+impl fmt::Display for ProgressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProgressError::NoDataDir => write!(
+                f,
+                "no data directory found (set RUST_LAB_HOME, XDG_DATA_HOME or HOME)"
+            ),
+            ProgressError::Io { path, error } => write!(f, "{}: {}", path.display(), error),
+            ProgressError::Corrupt { path, line, reason } => write!(
+                f,
+                "{}:{}: {} (run `rust-lab reset` to start over)",
+                path.display(),
+                line,
+                reason
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ProgressError {}
+
+/// A quiz result: `correct` answers out of `total` questions
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Score {
+    pub correct: u32,
+    pub total: u32,
+}
+
+/// What the learner has done with one lesson
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LessonProgress {
+    pub completed: Option<u64>,
+    pub last_run: Option<u64>,
+    pub runs: u32,
+    pub quiz: Option<Score>,
+    /// Where an unfinished `run --step` stopped
+    pub viewed: Option<u32>,
+}
+
+/// Progress for every lesson, keyed by lesson id
+///
+/// Ids that no longer match a lesson are kept, so renaming a lesson and
+/// renaming it back loses nothing.
+#[derive(Debug, Default, PartialEq)]
+pub struct Progress {
+    lessons: BTreeMap<String, LessonProgress>,
+}
+
+impl Progress {
+    pub fn lesson(&self, id: &str) -> Option<&LessonProgress> {
+        self.lessons.get(id)
+    }
+
+    /// Records one complete run of a lesson at Unix time `now`
+    pub fn record_run(&mut self, id: &str, now: u64) {
+        let entry = self.lessons.entry(id.to_string()).or_default();
+        entry.completed.get_or_insert(now);
+        entry.last_run = Some(now);
+        entry.runs += 1;
+    }
+
+    /// Remembers the page `run --step` stopped at, or forgets it (`None`)
+    pub fn set_viewed(&mut self, id: &str, page: Option<u32>) {
+        match (self.lessons.get_mut(id), page) {
+            (Some(entry), _) => entry.viewed = page,
+            (None, Some(_)) => {
+                self.lessons.entry(id.to_string()).or_default().viewed = page;
+            }
+            (None, None) => {}
+        }
+    }
+
+    /// Records a finished quiz, keeping the best score seen so far
+    pub fn record_quiz(&mut self, id: &str, score: Score) {
+        let entry = self.lessons.entry(id.to_string()).or_default();
+        let better = |old: Score| {
+            // a/b > c/d  ⇔  a·d > c·b (no floating point)
+            u64::from(score.correct) * u64::from(old.total)
+                > u64::from(old.correct) * u64::from(score.total)
+        };
+        if entry.quiz.is_none_or(better) {
+            entry.quiz = Some(score);
+        }
+    }
+
+    /// Serializes to the text format described at the top of this module
+    fn to_text(&self) -> String {
+        let mut text = format!("{}\n", HEADER);
+        for (id, lesson) in &self.lessons {
+            text.push_str(&format!("lesson {}", id));
+            if let Some(time) = lesson.completed {
+                text.push_str(&format!(" completed={}", time));
+            }
+            if let Some(time) = lesson.last_run {
+                text.push_str(&format!(" last_run={}", time));
+            }
+            text.push_str(&format!(" runs={}", lesson.runs));
+            if let Some(score) = lesson.quiz {
+                text.push_str(&format!(" quiz={}/{}", score.correct, score.total));
+            }
+            if let Some(page) = lesson.viewed {
+                text.push_str(&format!(" viewed={}", page));
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Parses the text format; errors carry a 1-based line number and a reason
+    fn from_text(text: &str) -> Result<Progress, (usize, String)> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.trim()));
+        match lines.next() {
+            Some((_, HEADER)) => {}
+            Some((n, other)) => {
+                return Err((n, format!("expected `{}`, found `{}`", HEADER, other)));
+            }
+            None => return Err((1, "empty file".to_string())),
+        }
+
+        let mut progress = Progress::default();
+        for (n, line) in lines {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut words = line.split_whitespace();
+            let id = match (words.next(), words.next()) {
+                (Some("lesson"), Some(id)) => id,
+                _ => return Err((n, format!("expected `lesson <id> ...`, found `{}`", line))),
+            };
+
+            let mut lesson = LessonProgress::default();
+            for field in words {
+                let (key, value) = field
+                    .split_once('=')
+                    .ok_or_else(|| (n, format!("expected key=value, found `{}`", field)))?;
+                let bad_value = || (n, format!("invalid value for `{}`: `{}`", key, value));
+                match key {
+                    "completed" => lesson.completed = Some(value.parse().map_err(|_| bad_value())?),
+                    "last_run" => lesson.last_run = Some(value.parse().map_err(|_| bad_value())?),
+                    "runs" => lesson.runs = value.parse().map_err(|_| bad_value())?,
+                    "quiz" => lesson.quiz = Some(parse_score(value).ok_or_else(bad_value)?),
+                    "viewed" => lesson.viewed = Some(value.parse().map_err(|_| bad_value())?),
+                    // Fields written by newer versions are skipped, not rejected
+                    _ => {}
+                }
+            }
+            progress.lessons.insert(id.to_string(), lesson);
+        }
+        Ok(progress)
+    }
+}
+
+fn parse_score(value: &str) -> Option<Score> {
+    let (correct, total) = value.split_once('/')?;
+    let score = Score {
+        correct: correct.parse().ok()?,
+        total: total.parse().ok()?,
+    };
+    (score.correct <= score.total).then_some(score)
+}
+
+/// The progress file on disk
+pub struct ProgressStore {
+    path: PathBuf,
+}
+
+impl ProgressStore {
+    /// The store in the learner's data directory
+    pub fn locate() -> Result<ProgressStore, ProgressError> {
+        Ok(ProgressStore {
+            path: data_dir()?.join(FILE_NAME),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Reads the stored progress; a missing file means no progress yet
+    pub fn load(&self) -> Result<Progress, ProgressError> {
+        load_file(&self.path, Progress::from_text).map(Option::unwrap_or_default)
+    }
+
+    pub fn save(&self, progress: &Progress) -> Result<(), ProgressError> {
+        save_file(&self.path, &progress.to_text())
+    }
+
+    /// Deletes the progress file; returns whether there was one
+    pub fn reset(&self) -> Result<bool, ProgressError> {
+        remove_file(&self.path)
+    }
+
+    /// Loads, applies `update`, and saves
+    pub fn update(&self, update: impl FnOnce(&mut Progress)) -> Result<(), ProgressError> {
+        let mut progress = self.load()?;
+        update(&mut progress);
+        self.save(&progress)
+    }
+}
+
+/// The learner's data directory, where every rust-lab file is kept
+pub fn data_dir() -> Result<PathBuf, ProgressError> {
+    let var = |name: &str| std::env::var_os(name).filter(|value| !value.is_empty());
+    if let Some(home) = var("RUST_LAB_HOME") {
+        Ok(PathBuf::from(home))
+    } else if let Some(data) = var("XDG_DATA_HOME") {
+        Ok(Path::new(&data).join("rust-lab"))
+    } else if let Some(home) = var("HOME") {
+        Ok(Path::new(&home).join(".local/share/rust-lab"))
+    } else {
+        Err(ProgressError::NoDataDir)
+    }
+}
+
+fn io_error(path: &Path, error: io::Error) -> ProgressError {
+    ProgressError::Io {
+        path: path.to_path_buf(),
+        error,
+    }
+}
+
+/// Reads and parses a data file; `None` when it does not exist yet
+pub fn load_file<T>(
+    path: &Path,
+    parse: impl FnOnce(&str) -> Result<T, (usize, String)>,
+) -> Result<Option<T>, ProgressError> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(error) => return Err(io_error(path, error)),
+    };
+    parse(&text)
+        .map(Some)
+        .map_err(|(line, reason)| ProgressError::Corrupt {
+            path: path.to_path_buf(),
+            line,
+            reason,
+        })
+}
+
+/// Writes a data file through a temporary file, so a crash never leaves half a file
+pub fn save_file(path: &Path, text: &str) -> Result<(), ProgressError> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|error| io_error(path, error))?;
+    }
+    let temporary = path.with_extension("tmp");
+    fs::write(&temporary, text).map_err(|error| io_error(path, error))?;
+    fs::rename(&temporary, path).map_err(|error| io_error(path, error))
+}
+
+/// Deletes a data file; returns whether there was one
+pub fn remove_file(path: &Path) -> Result<bool, ProgressError> {
+    match fs::remove_file(path) {
+        Ok(()) => Ok(true),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(false),
+        Err(error) => Err(io_error(path, error)),
+    }
+}
+
+/// Seconds since the Unix epoch
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// `YYYY-MM-DD` (UTC) for a Unix time
+pub fn format_date(time: u64) -> String {
+    // Days-to-civil conversion from Howard Hinnant's date algorithms
+    let days = (time / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Progress {
+        let mut progress = Progress::default();
+        progress.record_run("ownership", 1_760_745_600);
+        progress.record_run("ownership", 1_760_832_000);
+        progress.record_quiz(
+            "ownership",
+            Score {
+                correct: 4,
+                total: 5,
+            },
+        );
+        progress.set_viewed("loops", Some(2));
+        progress
+    }
+
+    #[test]
+    fn text_round_trips() {
+        let text = sample().to_text();
+        assert_eq!(
+            text,
+            "rust-lab-progress 1\n\
+             lesson loops runs=0 viewed=2\n\
+             lesson ownership completed=1760745600 last_run=1760832000 runs=2 quiz=4/5\n"
+        );
+        assert_eq!(Progress::from_text(&text), Ok(sample()));
+    }
+
+    #[test]
+    fn blank_lines_comments_and_unknown_fields_are_skipped() {
+        let text = "rust-lab-progress 1\n\n# note\nlesson loops runs=1 stars=5\n";
+        let progress = Progress::from_text(text).unwrap();
+        assert_eq!(progress.lesson("loops").unwrap().runs, 1);
+    }
+
+    #[test]
+    fn corrupt_lines_report_line_and_reason() {
+        let error = |text: &str| Progress::from_text(text).unwrap_err();
+        assert_eq!(error(""), (1, "empty file".to_string()));
+        assert_eq!(
+            error("progress v2\n"),
+            (
+                1,
+                "expected `rust-lab-progress 1`, found `progress v2`".to_string()
+            )
+        );
+        assert_eq!(
+            error("rust-lab-progress 1\nchapter loops\n"),
+            (
+                2,
+                "expected `lesson <id> ...`, found `chapter loops`".to_string()
+            )
+        );
+        assert_eq!(
+            error("rust-lab-progress 1\nlesson\n"),
+            (2, "expected `lesson <id> ...`, found `lesson`".to_string())
+        );
+        assert_eq!(
+            error("rust-lab-progress 1\nlesson loops runs\n"),
+            (2, "expected key=value, found `runs`".to_string())
+        );
+        assert_eq!(
+            error("rust-lab-progress 1\n\nlesson loops runs=many\n"),
+            (3, "invalid value for `runs`: `many`".to_string())
+        );
+        for quiz in ["4", "4/x", "6/5"] {
+            let text = format!("rust-lab-progress 1\nlesson loops quiz={}\n", quiz);
+            assert_eq!(
+                error(&text),
+                (2, format!("invalid value for `quiz`: `{}`", quiz))
+            );
+        }
+    }
+
+    #[test]
+    fn best_quiz_score_is_kept() {
+        let mut progress = sample();
+        progress.record_quiz(
+            "ownership",
+            Score {
+                correct: 1,
+                total: 2,
+            },
+        );
+        assert_eq!(
+            progress.lesson("ownership").unwrap().quiz,
+            Some(Score {
+                correct: 4,
+                total: 5
+            })
+        );
+        progress.record_quiz(
+            "ownership",
+            Score {
+                correct: 9,
+                total: 10,
+            },
+        );
+        assert_eq!(
+            progress.lesson("ownership").unwrap().quiz,
+            Some(Score {
+                correct: 9,
+                total: 10
+            })
+        );
+    }
+
+    #[test]
+    fn format_date_handles_epoch_leap_days_and_year_ends() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(951_782_400), "2000-02-29");
+        assert_eq!(format_date(1_760_745_600), "2025-10-18");
+        assert_eq!(format_date(1_767_225_599), "2025-12-31");
+        assert_eq!(format_date(1_767_225_600), "2026-01-01");
+    }
+}
@@ -0,0 +1,435 @@
+//! ============================================================================
+//! QUIZ MODULE - Per-lesson question banks, asked on the terminal
+//! ============================================================================
+//! Every lesson can return a bank of questions from `Lesson::questions()`;
+//! `rust-lab quiz <lesson>` asks them in a shuffled order, reads answers from
+//! stdin, explains wrong answers and records the score in the progress store.
+//!
+//! | Answer kind | The learner types                |
+//! |-------------|----------------------------------|
+//! | `Choice`    | A letter (`b`) or number (`2`)   |
+//! | `TrueFalse` | `true` / `false` / `t` / `f`     |
+//! | `Text`      | A short answer, case-insensitive |
+//!
+//! The shuffle is driven by a seeded generator, so `--seed N` replays exactly
+//! the same quiz (question order and choice order). A wrong answer points
+//! back to the lesson section that teaches it.
+//! ============================================================================
+
+use std::io::{self, BufRead, Write};
+
+use crate::lessons::Lesson;
+use crate::progress::Score;
+
+/// One question in a lesson's bank
+#[derive(Debug)]
+pub struct Question {
+    pub prompt: &'static str,
+    pub answer: Answer,
+    /// Shown after a wrong answer
+    pub explanation: &'static str,
+    /// Title of the lesson section (`out.section(..)`) that covers the question
+    pub section: Option<&'static str>,
+}
+
+/// The kind of question and its correct answer
+#[derive(Debug)]
+pub enum Answer {
+    /// Multiple choice; `correct` indexes `options`
+    Choice {
+        options: &'static [&'static str],
+        correct: usize,
+    },
+    TrueFalse(bool),
+    /// Short answer; any of the accepted spellings is right
+    Text(&'static [&'static str]),
+}
+
+/// ============================================================================
+/// SEEDED SHUFFLING
+/// ============================================================================
+/// SplitMix64: tiny, fast and good enough to shuffle a dozen questions. The
+/// same seed always gives the same sequence on every platform.
+/// ============================================================================
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..bound` (`bound` > 0)
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    /// Fisher–Yates shuffle
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// A question as it is shown: choices in their shuffled order
+struct Asked<'q> {
+    question: &'q Question,
+    /// Indexes into `Answer::Choice::options`, in display order
+    order: Vec<usize>,
+}
+
+impl Asked<'_> {
+    fn letter(position: usize) -> char {
+        (b'a' + position as u8) as char
+    }
+
+    /// Checks a reply; `Err` means the reply was not a valid answer at all
+    fn check(&self, reply: &str) -> Result<bool, &'static str> {
+        let reply = reply.trim().to_lowercase();
+        match &self.question.answer {
+            Answer::Choice { correct, .. } => {
+                let position = match reply.parse::<usize>() {
+                    Ok(n) if (1..=self.order.len()).contains(&n) => n - 1,
+                    _ => reply
+                        .chars()
+                        .next()
+                        .filter(|_| reply.chars().count() == 1)
+                        .and_then(|c| (0..self.order.len()).find(|&p| Asked::letter(p) == c))
+                        .ok_or("answer with one of the letters shown")?,
+                };
+                Ok(self.order[position] == *correct)
+            }
+            Answer::TrueFalse(truth) => match reply.as_str() {
+                "t" | "true" | "y" | "yes" => Ok(*truth),
+                "f" | "false" | "n" | "no" => Ok(!*truth),
+                _ => Err("answer true or false"),
+            },
+            Answer::Text(accepted) => {
+                if reply.is_empty() {
+                    return Err("type an answer");
+                }
+                Ok(accepted.iter().any(|a| normalize(a) == normalize(&reply)))
+            }
+        }
+    }
+
+    /// The correct answer as the learner saw it, e.g. `b) Some(5)`
+    fn solution(&self) -> String {
+        match &self.question.answer {
+            Answer::Choice { options, correct } => {
+                let position = self.order.iter().position(|i| i == correct).unwrap_or(0);
+                format!("{}) {}", Asked::letter(position), options[*correct])
+            }
+            Answer::TrueFalse(truth) => truth.to_string(),
+            Answer::Text(accepted) => accepted.first().copied().unwrap_or_default().to_string(),
+        }
+    }
+}
+
+/// Lowercase, no backticks, single spaces: `` `Some(5)` `` == `some(5)`
+fn normalize(answer: &str) -> String {
+    answer
+        .replace('`', "")
+        .to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Asks every question of `lesson`, shuffled by `seed`
+///
+/// Returns `None` when the input ends before the last question.
+pub fn run(
+    lesson: &dyn Lesson,
+    seed: u64,
+    input: &mut dyn BufRead,
+    out: &mut dyn Write,
+) -> io::Result<Option<Score>> {
+    let mut rng = Rng::new(seed);
+    let mut questions: Vec<&Question> = lesson.questions().iter().collect();
+    rng.shuffle(&mut questions);
+
+    let total = questions.len() as u32;
+    let mut correct = 0;
+
+    for (n, question) in questions.into_iter().enumerate() {
+        let mut order: Vec<usize> = match &question.answer {
+            Answer::Choice { options, .. } => (0..options.len()).collect(),
+            _ => Vec::new(),
+        };
+        rng.shuffle(&mut order);
+        let asked = Asked { question, order };
+
+        writeln!(out, "\nQuestion {}/{}: {}", n + 1, total, question.prompt)?;
+        match &question.answer {
+            Answer::Choice { options, .. } => {
+                for (position, &index) in asked.order.iter().enumerate() {
+                    writeln!(out, "  {}) {}", Asked::letter(position), options[index])?;
+                }
+            }
+            Answer::TrueFalse(_) => writeln!(out, "  (true / false)")?,
+            Answer::Text(_) => {}
+        }
+
+        let right = loop {
+            write!(out, "> ")?;
+            out.flush()?;
+            let mut reply = String::new();
+            if input.read_line(&mut reply)? == 0 {
+                writeln!(out, "\nQuiz ended early ({} of {} answered).", n, total)?;
+                return Ok(None);
+            }
+            match asked.check(&reply) {
+                Ok(right) => break right,
+                Err(hint) => writeln!(out, "  ({})", hint)?,
+            }
+        };
+
+        if right {
+            correct += 1;
+            writeln!(out, "✅ Correct!")?;
+        } else {
+            writeln!(out, "❌ Not quite. The answer is: {}", asked.solution())?;
+            writeln!(out, "   {}", question.explanation)?;
+            let section = question
+                .section
+                .map_or(String::new(), |title| format!(", section \"{}\"", title));
+            writeln!(
+                out,
+                "   📖 Review lesson {}{} (`rust-lab run {}`)",
+                lesson.number(),
+                section,
+                lesson.id()
+            )?;
+        }
+    }
+
+    writeln!(out, "\nScore: {}/{}", correct, total)?;
+    Ok(Some(Score { correct, total }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lessons::{self, LessonMeta, Phase};
+    use crate::output::Output;
+    use crate::validate;
+
+    static META: LessonMeta = LessonMeta {
+        id: "quizzed",
+        number: 7,
+        title: "Quizzed",
+        phase: Phase::Basics,
+        module: "basics",
+        entry: "learn_quizzed",
+        requires: &[],
+    };
+
+    static QUESTIONS: [Question; 3] = [
+        Question {
+            prompt: "Is a `String` owned?",
+            answer: Answer::TrueFalse(true),
+            explanation: "It owns its heap buffer.",
+            section: None,
+        },
+        Question {
+            prompt: "Does `let` make a binding mutable?",
+            answer: Answer::TrueFalse(false),
+            explanation: "Only `let mut` does.",
+            section: Some("Mutability"),
+        },
+        Question {
+            prompt: "Can two `&mut` borrows overlap?",
+            answer: Answer::TrueFalse(false),
+            explanation: "One `&mut` at a time.",
+            section: None,
+        },
+    ];
+
+    struct Quizzed;
+
+    impl Lesson for Quizzed {
+        fn meta(&self) -> &'static LessonMeta {
+            &META
+        }
+
+        fn run(&self, _out: &mut dyn Output) {}
+
+        fn questions(&self) -> &'static [Question] {
+            &QUESTIONS
+        }
+    }
+
+    /// Runs the quiz with `replies` as stdin; returns the score and the transcript
+    fn session(seed: u64, replies: &str) -> (Option<Score>, String) {
+        let mut out = Vec::new();
+        let score = run(&Quizzed, seed, &mut replies.as_bytes(), &mut out).unwrap();
+        (score, String::from_utf8(out).unwrap())
+    }
+
+    fn question(answer: Answer) -> Question {
+        Question {
+            prompt: "",
+            answer,
+            explanation: "",
+            section: None,
+        }
+    }
+
+    #[test]
+    fn same_seed_same_shuffle() {
+        let shuffled = |seed| {
+            let mut items: Vec<u32> = (0..10).collect();
+            Rng::new(seed).shuffle(&mut items);
+            items
+        };
+        assert_eq!(shuffled(42), shuffled(42));
+        assert_ne!(shuffled(42), shuffled(43));
+        let mut sorted = shuffled(42);
+        sorted.sort();
+        assert_eq!(sorted, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn choice_answers_follow_the_shown_order() {
+        let question = question(Answer::Choice {
+            options: &["None", "Some(5)", "Err"],
+            correct: 1,
+        });
+        // Shown as a) Err  b) Some(5)  c) None
+        let asked = Asked {
+            question: &question,
+            order: vec![2, 1, 0],
+        };
+        assert_eq!(asked.check("b"), Ok(true));
+        assert_eq!(asked.check(" B \n"), Ok(true));
+        assert_eq!(asked.check("2"), Ok(true));
+        assert_eq!(asked.check("a"), Ok(false));
+        assert_eq!(asked.check("3"), Ok(false));
+        for invalid in ["4", "0", "d", "ab", ""] {
+            assert!(asked.check(invalid).is_err(), "{:?}", invalid);
+        }
+        assert_eq!(asked.solution(), "b) Some(5)");
+    }
+
+    #[test]
+    fn true_false_and_text_answers() {
+        let truth = question(Answer::TrueFalse(true));
+        let truth = Asked {
+            question: &truth,
+            order: Vec::new(),
+        };
+        for reply in ["t", "TRUE", "yes", "y"] {
+            assert_eq!(truth.check(reply), Ok(true), "{}", reply);
+        }
+        for reply in ["f", "false", "No"] {
+            assert_eq!(truth.check(reply), Ok(false), "{}", reply);
+        }
+        assert!(truth.check("maybe").is_err());
+
+        let text = question(Answer::Text(&["`Some(5)`", "some five"]));
+        let text = Asked {
+            question: &text,
+            order: Vec::new(),
+        };
+        assert_eq!(text.check("some(5)"), Ok(true));
+        assert_eq!(text.check("  Some   Five "), Ok(true));
+        assert_eq!(text.check("None"), Ok(false));
+        assert!(text.check("   ").is_err());
+        assert_eq!(text.solution(), "`Some(5)`");
+    }
+
+    #[test]
+    fn scripted_session_scores_and_explains() {
+        // Two of the three answers are `false`, whatever order they come in
+        let (score, text) = session(3, "maybe\nfalse\nfalse\nfalse\n");
+        assert_eq!(
+            score,
+            Some(Score {
+                correct: 2,
+                total: 3
+            })
+        );
+        assert_eq!(text.matches("  (answer true or false)").count(), 1);
+        assert_eq!(text.matches("✅ Correct!").count(), 2);
+        assert!(text.contains("❌ Not quite. The answer is: true\n   It owns its heap buffer.\n"));
+        assert!(text.contains("📖 Review lesson 7 (`rust-lab run quizzed`)"));
+        assert!(text.ends_with("\nScore: 2/3\n"));
+
+        let (score, text) = session(3, "true\ntrue\ntrue\n");
+        assert_eq!(
+            score,
+            Some(Score {
+                correct: 1,
+                total: 3
+            })
+        );
+        assert!(
+            text.contains("📖 Review lesson 7, section \"Mutability\" (`rust-lab run quizzed`)")
+        );
+    }
+
+    #[test]
+    fn seed_replays_the_same_quiz() {
+        let order = |seed| {
+            let (_, text) = session(seed, "true\ntrue\ntrue\n");
+            text.lines()
+                .filter(|line| line.starts_with("Question "))
+                .map(String::from)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(order(3), order(3));
+        let orders: std::collections::HashSet<_> = (0..20).map(order).collect();
+        assert!(orders.len() > 1, "every seed gave the same order");
+    }
+
+    #[test]
+    fn end_of_input_stops_the_quiz_without_a_score() {
+        let (score, text) = session(3, "true\n");
+        assert_eq!(score, None);
+        assert!(text.ends_with("\nQuiz ended early (1 of 3 answered).\n"));
+    }
+
+    #[test]
+    fn lesson_questions_are_well_formed() {
+        let registry = lessons::registry();
+        let mut problems = Vec::new();
+        for lesson in registry.lessons() {
+            for (i, question) in lesson.questions().iter().enumerate() {
+                let label = format!("lesson '{}' question {}", lesson.id(), i + 1);
+                match &question.answer {
+                    Answer::Choice { options, correct } if *correct >= options.len() => problems
+                        .push(format!(
+                            "{}: answer {} but only {} options",
+                            label,
+                            correct,
+                            options.len()
+                        )),
+                    Answer::Choice { options, .. } if options.len() < 2 => {
+                        problems.push(format!("{}: needs at least two options", label))
+                    }
+                    Answer::Text([]) => problems.push(format!("{}: no accepted answers", label)),
+                    _ => {}
+                }
+                if question.section.is_some() {
+                    validate::check_lesson_section(
+                        &registry,
+                        &label,
+                        lesson.id(),
+                        question.section,
+                        &mut problems,
+                    );
+                }
+            }
+        }
+        assert_eq!(problems, Vec::<String>::new());
+    }
+}
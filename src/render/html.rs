@@ -0,0 +1,162 @@
+//! ============================================================================
+//! HTML RENDERER - Fragments for the static site export
+//! ============================================================================
+//! - Text is HTML-escaped; `backticks` become `<code>` spans
+//! - Consecutive `Output` lines are grouped into one `<pre class="output">`
+//! - Code samples become `<pre class="code"><code>` blocks
+//! - Warnings become `<p class="warning">` paragraphs
+//!
+//! ============================================================================
+
+use super::Renderer;
+use crate::content::Block;
+use crate::table::{Align, Table};
+
+/// Renders blocks as HTML fragments (no `<html>` / `<body>` wrapper)
+#[derive(Default)]
+pub struct HtmlRenderer {
+    in_output: bool,
+}
+
+impl HtmlRenderer {
+    /// Closes the output `<pre>` if the previous block was output
+    fn close_output(&mut self) -> &'static str {
+        if std::mem::take(&mut self.in_output) {
+            "</pre>\n"
+        } else {
+            ""
+        }
+    }
+}
+
+impl Renderer for HtmlRenderer {
+    fn render(&mut self, block: &Block) -> String {
+        if let Block::Output(line) = block {
+            let open = if self.in_output {
+                ""
+            } else {
+                "<pre class=\"output\">"
+            };
+            self.in_output = true;
+            return format!("{}{}\n", open, escape(line));
+        }
+
+        let close = self.close_output();
+        let text = match block {
+            Block::Banner(text) => format!("<h1>{}</h1>\n", inline(text)),
+            Block::Heading { number, title } => {
+                format!("<h1>Lesson {}: {}</h1>\n", number, inline(title))
+            }
+            Block::Summary(title) => format!("<h3>Summary: {}</h3>\n", inline(title)),
+            Block::Section(title) => format!("<h3>{}</h3>\n", inline(title)),
+            Block::Paragraph(text) => format!("<p>{}</p>\n", inline(text)),
+            Block::List(items) => {
+                let items: String = items
+                    .iter()
+                    .map(|item| format!("  <li>{}</li>\n", inline(item)))
+                    .collect();
+                format!("<ul>\n{}</ul>\n", items)
+            }
+            Block::Table(table) => html_table(table),
+            Block::CodeSample(code) => format!(
+                "<pre class=\"code\"><code class=\"language-rust\">{}</code></pre>\n",
+                escape(code.trim_end())
+            ),
+            Block::Warning(text) => format!("<p class=\"warning\">⚠️ {}</p>\n", inline(text)),
+            Block::Output(_) => unreachable!("handled above"),
+        };
+        format!("{}{}", close, text)
+    }
+
+    fn finish(&mut self) -> String {
+        self.close_output().to_string()
+    }
+}
+
+fn html_table(table: &Table) -> String {
+    let cell = |tag: &str, text: &str, align: Align| {
+        let style = match align {
+            Align::Left => "",
+            Align::Center => " style=\"text-align:center\"",
+        };
+        format!("<{}{}>{}</{}>", tag, style, inline(text), tag)
+    };
+    let row = |tag: &str, cells: &[String]| {
+        let cells: String = cells
+            .iter()
+            .zip(&table.align)
+            .map(|(text, &align)| cell(tag, text, align))
+            .collect();
+        format!("  <tr>{}</tr>\n", cells)
+    };
+
+    let mut text = String::from("<table>\n");
+    text.push_str(&row("th", &table.headers));
+    for cells in &table.rows {
+        text.push_str(&row("td", cells));
+    }
+    text.push_str("</table>\n");
+    text
+}
+
+/// Escapes the characters HTML treats specially
+pub fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Escapes text and turns `code` spans into `<code>` elements
+fn inline(text: &str) -> String {
+    escape(text)
+        .split('`')
+        .enumerate()
+        .map(|(i, part)| {
+            if i % 2 == 1 {
+                format!("<code>{}</code>", part)
+            } else {
+                part.to_string()
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render;
+
+    #[test]
+    fn escape_covers_the_special_characters() {
+        assert_eq!(
+            escape(r#"<a href="x">&amp;</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;&amp;amp;&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn backticks_become_code_spans() {
+        assert_eq!(
+            inline("Use `Vec<T>` or `&[T]`"),
+            "Use <code>Vec&lt;T&gt;</code> or <code>&amp;[T]</code>"
+        );
+        assert_eq!(inline("no code"), "no code");
+    }
+
+    #[test]
+    fn consecutive_output_lines_share_one_pre() {
+        let blocks = [
+            Block::Output("a < b".to_string()),
+            Block::Output("done".to_string()),
+            Block::Paragraph("Then `x`".to_string()),
+            Block::Output("last".to_string()),
+        ];
+        assert_eq!(
+            render::render_all(&mut HtmlRenderer::default(), &blocks),
+            "<pre class=\"output\">a &lt; b\ndone\n</pre>\n\
+             <p>Then <code>x</code></p>\n\
+             <pre class=\"output\">last\n</pre>\n"
+        );
+    }
+}
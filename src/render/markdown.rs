@@ -0,0 +1,95 @@
+//! ============================================================================
+//! MARKDOWN RENDERER - GitHub-flavoured Markdown
+//! ============================================================================
+//! - Headings become `#` / `###` titles
+//! - Consecutive `Output` lines are grouped into one ```text fence
+//! - Code samples become ```rust fences
+//! - Tables become aligned pipe tables (`:---:` for centered columns)
+//! - Warnings become `> ⚠️` block quotes
+//!
+//! ============================================================================
+
+use super::Renderer;
+use crate::content::Block;
+use crate::table::Style;
+
+/// Renders blocks as Markdown
+#[derive(Default)]
+pub struct MarkdownRenderer {
+    in_output: bool,
+}
+
+impl MarkdownRenderer {
+    /// Closes the ```text fence if the previous block was output
+    fn close_output(&mut self) -> &'static str {
+        if std::mem::take(&mut self.in_output) {
+            "```\n\n"
+        } else {
+            ""
+        }
+    }
+}
+
+impl Renderer for MarkdownRenderer {
+    fn render(&mut self, block: &Block) -> String {
+        if let Block::Output(line) = block {
+            let open = if self.in_output { "" } else { "```text\n" };
+            self.in_output = true;
+            return format!("{}{}\n", open, line);
+        }
+
+        let close = self.close_output();
+        let text = match block {
+            Block::Banner(text) => format!("# {}\n\n", text),
+            Block::Heading { number, title } => format!("# Lesson {}: {}\n\n", number, title),
+            Block::Summary(title) => format!("### Summary: {}\n\n", title),
+            Block::Section(title) => format!("### {}\n\n", title),
+            Block::Paragraph(text) => format!("{}\n\n", text),
+            Block::List(items) => {
+                let lines: String = items.iter().map(|item| format!("- {}\n", item)).collect();
+                format!("{}\n", lines)
+            }
+            Block::Table(table) => format!("{}\n", table.render(Style::Markdown)),
+            Block::CodeSample(code) => format!("```rust\n{}\n```\n\n", code.trim_end()),
+            Block::Warning(text) => format!("> ⚠️ {}\n\n", text),
+            Block::Output(_) => unreachable!("handled above"),
+        };
+        format!("{}{}", close, text)
+    }
+
+    fn finish(&mut self) -> String {
+        self.close_output().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render;
+
+    #[test]
+    fn output_lines_share_one_fence_closed_at_finish() {
+        let blocks = [
+            Block::Section("Demo".to_string()),
+            Block::Output("one".to_string()),
+            Block::Output("two".to_string()),
+        ];
+        assert_eq!(
+            render::render_all(&mut MarkdownRenderer::default(), &blocks),
+            "### Demo\n\n```text\none\ntwo\n```\n\n"
+        );
+    }
+
+    #[test]
+    fn a_new_block_closes_the_output_fence() {
+        let blocks = [
+            Block::Output("one".to_string()),
+            Block::Warning("careful".to_string()),
+            Block::CodeSample("let x = 1;\n".to_string()),
+        ];
+        assert_eq!(
+            render::render_all(&mut MarkdownRenderer::default(), &blocks),
+            "```text\none\n```\n\n> ⚠️ careful\n\n```rust\nlet x = 1;\n```\n\n"
+        );
+    }
+}
@@ -0,0 +1,38 @@
+//! ============================================================================
+//! RENDER MODULE - Turning content blocks into text
+//! ============================================================================
+//! Submodules:
+//! - terminal → Reproduces the classic `println!` look of the lessons
+//! - markdown → GitHub-flavoured Markdown (output lines become ```text fences)
+//! - html     → HTML fragments for the static site export
+//!
+//! Renderers are stateful: spacing between blocks depends on what came before
+//! (e.g. the first section after a lesson heading gets no blank line).
+//! ============================================================================
+
+pub mod html;
+pub mod markdown;
+pub mod terminal;
+
+pub use html::HtmlRenderer;
+pub use markdown::MarkdownRenderer;
+pub use terminal::TerminalRenderer;
+
+use crate::content::Block;
+
+/// Converts blocks to text, one at a time, in order
+pub trait Renderer {
+    fn render(&mut self, block: &Block) -> String;
+
+    /// Closes anything still open (e.g. a code fence) after the last block
+    fn finish(&mut self) -> String {
+        String::new()
+    }
+}
+
+/// Renders a whole sequence of blocks, then finishes the renderer
+pub fn render_all(renderer: &mut dyn Renderer, blocks: &[Block]) -> String {
+    let mut text: String = blocks.iter().map(|block| renderer.render(block)).collect();
+    text.push_str(&renderer.finish());
+    text
+}
@@ -0,0 +1,177 @@
+//! ============================================================================
+//! TERMINAL RENDERER - The look of `cargo run`
+//! ============================================================================
+//! Spacing rules (a blank line is inserted before a block when):
+//! - `Section`   → anything but a lesson heading came before it
+//! - `Paragraph` → it follows a summary heading, table, list or warning
+//! - `Table`     → it follows another table, a list or a warning
+//! - `List`      → it follows a table, another list or a warning
+//! - `CodeSample`→ anything but a heading or section came before it
+//! - `Warning`   → anything but a heading or section came before it
+//! - `Output`    → it follows a code sample or a warning
+//!
+//! A summary is closed by a blank line once the next lesson starts or the
+//! renderer finishes.
+//!
+//! Code samples are indented by four spaces to set them apart from output,
+//! and syntax-highlighted when the renderer has `Colors::Ansi`.
+//! ============================================================================
+
+use super::Renderer;
+use crate::content::Block;
+use crate::highlight::{self, Colors};
+use crate::table::{self, Align, Style};
+
+const RULE: &str = "============================================================";
+const BOX_WIDTH: usize = 60;
+
+/// Renders blocks exactly as the lessons used to print them
+#[derive(Default)]
+pub struct TerminalRenderer {
+    previous: Option<Kind>,
+    in_summary: bool,
+    tables: Style,
+    colors: Colors,
+}
+
+impl TerminalRenderer {
+    /// A renderer drawing tables in `tables` and code in `colors` (the
+    /// default is box-drawn tables and plain code)
+    pub fn new(tables: Style, colors: Colors) -> TerminalRenderer {
+        TerminalRenderer {
+            previous: None,
+            in_summary: false,
+            tables,
+            colors,
+        }
+    }
+
+    /// The blank line that ends an open summary
+    fn close_summary(&mut self) -> &'static str {
+        if std::mem::take(&mut self.in_summary) {
+            "\n"
+        } else {
+            ""
+        }
+    }
+}
+
+/// The kind of the last rendered block, used for spacing decisions
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Banner,
+    Heading,
+    Summary,
+    Section,
+    Paragraph,
+    List,
+    Table,
+    Code,
+    Warning,
+    Output,
+}
+
+impl Renderer for TerminalRenderer {
+    fn render(&mut self, block: &Block) -> String {
+        let previous = self.previous;
+        let blank_if = |kinds: &[Kind]| match previous {
+            Some(kind) if kinds.contains(&kind) => "\n",
+            _ => "",
+        };
+
+        let close = match block {
+            Block::Banner(_) | Block::Heading { .. } => self.close_summary(),
+            _ => "",
+        };
+        let (kind, text) = match block {
+            Block::Banner(text) => {
+                let spacer = if previous.is_some() { "\n" } else { "" };
+                (Kind::Banner, format!("{}{}", spacer, banner_box(text)))
+            }
+            Block::Heading { number, title } => (
+                Kind::Heading,
+                format!("\n{}\n📘 LESSON {}: {}\n{}\n\n", RULE, number, title, RULE),
+            ),
+            Block::Summary(title) => {
+                self.in_summary = true;
+                (
+                    Kind::Summary,
+                    format!("\n{}\n📚 SUMMARY: {}\n{}\n", RULE, title, RULE),
+                )
+            }
+            Block::Section(title) => {
+                let spacer = match previous {
+                    None | Some(Kind::Heading) => "",
+                    Some(_) => "\n",
+                };
+                (Kind::Section, format!("{}--- {} ---\n", spacer, title))
+            }
+            Block::Paragraph(text) => (
+                Kind::Paragraph,
+                format!(
+                    "{}{}\n",
+                    blank_if(&[Kind::Summary, Kind::Table, Kind::List, Kind::Warning]),
+                    text
+                ),
+            ),
+            Block::List(items) => {
+                let lines: String = items.iter().map(|item| format!("• {}\n", item)).collect();
+                (
+                    Kind::List,
+                    format!(
+                        "{}{}",
+                        blank_if(&[Kind::Table, Kind::List, Kind::Warning]),
+                        lines
+                    ),
+                )
+            }
+            Block::Table(table) => (
+                Kind::Table,
+                format!(
+                    "{}{}",
+                    blank_if(&[Kind::Table, Kind::List, Kind::Warning]),
+                    table.render(self.tables)
+                ),
+            ),
+            Block::CodeSample(code) => {
+                let spacer = match previous {
+                    None | Some(Kind::Heading | Kind::Section) => "",
+                    Some(_) => "\n",
+                };
+                (
+                    Kind::Code,
+                    format!("{}{}", spacer, highlight::indented(code, self.colors)),
+                )
+            }
+            Block::Warning(text) => {
+                let spacer = match previous {
+                    None | Some(Kind::Heading | Kind::Section) => "",
+                    Some(_) => "\n",
+                };
+                (Kind::Warning, format!("{}⚠️  {}\n", spacer, text))
+            }
+            Block::Output(line) => {
+                let spacer = blank_if(&[Kind::Code, Kind::Warning]);
+                (Kind::Output, format!("{}{}\n", spacer, line))
+            }
+        };
+
+        self.previous = Some(kind);
+        format!("{}{}", close, text)
+    }
+
+    fn finish(&mut self) -> String {
+        self.close_summary().to_string()
+    }
+}
+
+/// The double-line box used for the guide's opening and closing banners
+fn banner_box(text: &str) -> String {
+    let rule = "═".repeat(BOX_WIDTH);
+    format!(
+        "╔{}╗\n║{}║\n╚{}╝\n",
+        rule,
+        table::pad(text, BOX_WIDTH, Align::Center),
+        rule
+    )
+}
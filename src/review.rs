@@ -0,0 +1,567 @@
+//! ============================================================================
+//! REVIEW MODULE - Flashcards with spaced repetition (SM-2)
+//! ============================================================================
+//! Every glossary term (`glossary::terms`) is a flashcard: the term on the
+//! front, its definition on the back. `rust-lab review` shows the cards that
+//! are due, the learner grades how well they remembered each one, and the
+//! grade decides when the card comes back:
+//!
+//! | Grade | Meaning                          | Next review                 |
+//! |-------|----------------------------------|-----------------------------|
+//! | 5     | Perfect, no hesitation           | Interval × ease, ease +0.10 |
+//! | 4     | Correct after a moment           | Interval × ease             |
+//! | 3     | Correct, but it took real effort | Interval × ease, ease −0.14 |
+//! | 0–2   | Forgotten                        | Tomorrow, from the start    |
+//!
+//! The first two successful reviews are 1 and 6 days apart; after that each
+//! interval is the previous one times the card's ease (2.5 for a new card,
+//! never below 1.3). Cards graded below 4 are asked again at the end of the
+//! session, without changing their schedule, until they are remembered.
+//!
+//! Schedules live in `cards.txt` next to `progress.txt`:
+//!
+//!   rust-lab-cards 1
+//!   card option due=1760832000 interval=6 ease=250 reps=2 term=`.and_then(f)`
+//!
+//! Ease is kept in hundredths (`250` = 2.5), so the same answers always give
+//! the same dates. A card is identified by lesson id and term: editing a
+//! definition keeps its schedule, renaming the term starts it over.
+//! ============================================================================
+
+use std::collections::{BTreeMap, VecDeque};
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+
+use crate::glossary::Term;
+use crate::lessons::Registry;
+use crate::progress::{self, ProgressError};
+
+const HEADER: &str = "rust-lab-cards 1";
+const FILE_NAME: &str = "cards.txt";
+const DAY: u64 = 86_400;
+
+/// New cards introduced per session, so the first review is not all 70 terms
+pub const NEW_PER_SESSION: usize = 10;
+
+/// Where the current time comes from
+pub trait Clock {
+    /// Seconds since the Unix epoch
+    fn now(&self) -> u64;
+}
+
+/// The real time
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> u64 {
+        progress::now()
+    }
+}
+
+/// When a card is next due and how fast its intervals grow
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Schedule {
+    /// Successful reviews in a row
+    pub reps: u32,
+    /// Days until the next review
+    pub interval: u32,
+    /// Ease factor in hundredths
+    pub ease: u32,
+    /// Unix time the card is due
+    pub due: u64,
+}
+
+impl Schedule {
+    /// A card that was never reviewed
+    pub const NEW: Schedule = Schedule {
+        reps: 0,
+        interval: 0,
+        ease: 250,
+        due: 0,
+    };
+
+    const MIN_EASE: u32 = 130;
+
+    /// The schedule after a review graded `grade` (0–5) at `now`
+    pub fn next(self, grade: u8, now: u64) -> Schedule {
+        let grade = grade.min(5);
+        if grade < 3 {
+            // Forgotten: learn it again from the first interval, same ease
+            return Schedule {
+                reps: 0,
+                interval: 1,
+                due: now + DAY,
+                ..self
+            };
+        }
+
+        let interval = match self.reps {
+            0 => 1,
+            1 => 6,
+            // interval × ease, rounded
+            _ => (self.interval * self.ease + 50) / 100,
+        };
+        // EF' = EF + 0.1 − (5 − q) × (0.08 + (5 − q) × 0.02), in hundredths
+        let miss = 5 - i64::from(grade);
+        let ease = i64::from(self.ease) + 10 - miss * (8 + miss * 2);
+        Schedule {
+            reps: self.reps + 1,
+            interval,
+            ease: (ease as u32).max(Self::MIN_EASE),
+            due: now + u64::from(interval) * DAY,
+        }
+    }
+}
+
+/// The schedules of every card reviewed so far, keyed by (lesson id, term)
+#[derive(Debug, Default, PartialEq)]
+pub struct Deck {
+    cards: BTreeMap<(String, String), Schedule>,
+}
+
+impl Deck {
+    fn key(term: &Term) -> (String, String) {
+        (term.lesson.to_string(), term.term.clone())
+    }
+
+    /// `None` for a card never reviewed
+    pub fn schedule(&self, term: &Term) -> Option<Schedule> {
+        self.cards.get(&Deck::key(term)).copied()
+    }
+
+    /// Reschedules a card after it was graded `grade` at `now`
+    pub fn record(&mut self, term: &Term, grade: u8, now: u64) -> Schedule {
+        let schedule = self
+            .schedule(term)
+            .unwrap_or(Schedule::NEW)
+            .next(grade, now);
+        self.cards.insert(Deck::key(term), schedule);
+        schedule
+    }
+
+    /// Cards to review at `now`: reviewed cards that are due, most overdue
+    /// first, then up to `new_limit` never-reviewed cards in teaching order
+    pub fn due<'t>(&self, terms: &'t [Term], now: u64, new_limit: usize) -> Vec<&'t Term> {
+        let mut seen = Vec::new();
+        let mut due = Vec::new();
+        let mut new = Vec::new();
+        for term in terms {
+            // A term listed twice in one lesson is one card
+            if seen.contains(&Deck::key(term)) {
+                continue;
+            }
+            seen.push(Deck::key(term));
+            match self.schedule(term) {
+                Some(schedule) if schedule.due <= now => due.push((schedule.due, term)),
+                Some(_) => {}
+                None if new.len() < new_limit => new.push(term),
+                None => {}
+            }
+        }
+        // Stable sort: cards due at the same time keep teaching order
+        due.sort_by_key(|&(time, _)| time);
+        due.into_iter().map(|(_, term)| term).chain(new).collect()
+    }
+
+    /// The earliest due time of a reviewed card
+    pub fn next_due(&self) -> Option<u64> {
+        self.cards.values().map(|schedule| schedule.due).min()
+    }
+
+    /// Serializes to the text format described at the top of this module
+    fn to_text(&self) -> String {
+        let mut text = format!("{}\n", HEADER);
+        for ((lesson, term), s) in &self.cards {
+            text.push_str(&format!(
+                "card {} due={} interval={} ease={} reps={} term={}\n",
+                lesson, s.due, s.interval, s.ease, s.reps, term
+            ));
+        }
+        text
+    }
+
+    /// Parses the text format; errors carry a 1-based line number and a reason
+    fn from_text(text: &str) -> Result<Deck, (usize, String)> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.trim()));
+        match lines.next() {
+            Some((_, HEADER)) => {}
+            Some((n, other)) => {
+                return Err((n, format!("expected `{}`, found `{}`", HEADER, other)));
+            }
+            None => return Err((1, "empty file".to_string())),
+        }
+
+        let mut deck = Deck::default();
+        for (n, line) in lines {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            // The term may contain spaces, so it is everything after `term=`
+            let (fields, term) = line.split_once(" term=").ok_or_else(|| {
+                (
+                    n,
+                    format!("expected `card <lesson> ... term=<term>`, found `{}`", line),
+                )
+            })?;
+            let mut words = fields.split_whitespace();
+            let lesson = match (words.next(), words.next()) {
+                (Some("card"), Some(lesson)) => lesson,
+                _ => return Err((n, format!("expected `card <lesson> ...`, found `{}`", line))),
+            };
+
+            let mut schedule = Schedule::NEW;
+            for field in words {
+                let (key, value) = field
+                    .split_once('=')
+                    .ok_or_else(|| (n, format!("expected key=value, found `{}`", field)))?;
+                let bad_value = || (n, format!("invalid value for `{}`: `{}`", key, value));
+                match key {
+                    "due" => schedule.due = value.parse().map_err(|_| bad_value())?,
+                    "interval" => schedule.interval = value.parse().map_err(|_| bad_value())?,
+                    "ease" => schedule.ease = value.parse().map_err(|_| bad_value())?,
+                    "reps" => schedule.reps = value.parse().map_err(|_| bad_value())?,
+                    // Fields written by newer versions are skipped, not rejected
+                    _ => {}
+                }
+            }
+            deck.cards
+                .insert((lesson.to_string(), term.to_string()), schedule);
+        }
+        Ok(deck)
+    }
+}
+
+/// The card schedules on disk
+pub struct DeckStore {
+    path: PathBuf,
+}
+
+impl DeckStore {
+    /// The store in the learner's data directory
+    pub fn locate() -> Result<DeckStore, ProgressError> {
+        Ok(DeckStore {
+            path: progress::data_dir()?.join(FILE_NAME),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Reads the stored schedules; a missing file means no card reviewed yet
+    pub fn load(&self) -> Result<Deck, ProgressError> {
+        progress::load_file(&self.path, Deck::from_text).map(Option::unwrap_or_default)
+    }
+
+    pub fn save(&self, deck: &Deck) -> Result<(), ProgressError> {
+        progress::save_file(&self.path, &deck.to_text())
+    }
+
+    /// Deletes the schedules; returns whether there were any
+    pub fn reset(&self) -> Result<bool, ProgressError> {
+        progress::remove_file(&self.path)
+    }
+}
+
+/// What the learner typed at a prompt
+enum Reply {
+    Text(String),
+    /// `q`, or the end of the input
+    Stop,
+}
+
+fn read_reply(input: &mut dyn BufRead, out: &mut dyn Write) -> io::Result<Reply> {
+    write!(out, "> ")?;
+    out.flush()?;
+    let mut reply = String::new();
+    if input.read_line(&mut reply)? == 0 {
+        return Ok(Reply::Stop);
+    }
+    match reply.trim() {
+        "q" | "quit" => Ok(Reply::Stop),
+        text => Ok(Reply::Text(text.to_string())),
+    }
+}
+
+/// Asks for a grade until one is given
+fn read_grade(input: &mut dyn BufRead, out: &mut dyn Write) -> io::Result<Option<u8>> {
+    writeln!(
+        out,
+        "How well did you remember it? 5 perfect · 4 after a moment · 3 with effort · 0–2 forgot"
+    )?;
+    loop {
+        match read_reply(input, out)? {
+            Reply::Stop => return Ok(None),
+            Reply::Text(text) => match text.parse::<u8>() {
+                Ok(grade) if grade <= 5 => return Ok(Some(grade)),
+                _ => writeln!(out, "  (answer 0 to 5, or q to stop)")?,
+            },
+        }
+    }
+}
+
+/// How a session went
+#[derive(Debug, Default, PartialEq)]
+pub struct Summary {
+    /// Cards graded and rescheduled
+    pub reviewed: usize,
+    /// Of those, graded 3 or better the first time
+    pub remembered: usize,
+}
+
+/// Shows each card in `cards`, asks for a grade and reschedules it in `deck`
+///
+/// Cards graded below 4 come back at the end of the session until they are
+/// graded 4 or 5; only the first grade of a card changes its schedule. `q`
+/// or the end of the input stops early, keeping what was graded so far.
+pub fn run(
+    registry: &Registry,
+    cards: &[&Term],
+    deck: &mut Deck,
+    clock: &dyn Clock,
+    input: &mut dyn BufRead,
+    out: &mut dyn Write,
+) -> io::Result<Summary> {
+    let mut summary = Summary::default();
+    // (card, first time in this session?)
+    let mut queue: VecDeque<(&Term, bool)> = cards.iter().map(|&card| (card, true)).collect();
+
+    while let Some((card, first)) = queue.pop_front() {
+        let lesson = registry
+            .find(card.lesson)
+            .map_or(card.lesson.to_string(), |l| {
+                format!("Lesson {}: {}", l.number(), l.title())
+            });
+        let label = if first {
+            format!("Card {}/{}", summary.reviewed + 1, cards.len())
+        } else {
+            "Again".to_string()
+        };
+        writeln!(out, "\n{} · {} · {}", label, lesson, card.group)?;
+        writeln!(out, "\n    {}\n", card.term)?;
+        writeln!(out, "(Enter to show the answer, q to stop)")?;
+        if let Reply::Stop = read_reply(input, out)? {
+            break;
+        }
+        writeln!(out, "    → {}\n", card.definition)?;
+
+        let Some(grade) = read_grade(input, out)? else {
+            break;
+        };
+        if first {
+            let now = clock.now();
+            let schedule = deck.record(card, grade, now);
+            summary.reviewed += 1;
+            if grade >= 3 {
+                summary.remembered += 1;
+            }
+            let days = schedule.interval;
+            writeln!(
+                out,
+                "  Next review in {} day{} ({})",
+                days,
+                if days == 1 { "" } else { "s" },
+                progress::format_date(schedule.due)
+            )?;
+        }
+        if grade < 4 {
+            queue.push_back((card, false));
+        }
+    }
+
+    writeln!(
+        out,
+        "\nReviewed {} card{}, {} remembered.",
+        summary.reviewed,
+        if summary.reviewed == 1 { "" } else { "s" },
+        summary.remembered
+    )?;
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lessons;
+
+    /// 2025-10-18 00:00 UTC
+    const START: u64 = 1_760_745_600;
+
+    struct FixedClock(u64);
+
+    impl Clock for FixedClock {
+        fn now(&self) -> u64 {
+            self.0
+        }
+    }
+
+    fn term(lesson: &'static str, term: &str) -> Term {
+        Term {
+            term: term.to_string(),
+            definition: format!("meaning of {}", term),
+            lesson,
+            group: "Key Concepts".to_string(),
+        }
+    }
+
+    /// Grades one schedule again and again, each review on its due date
+    fn intervals(grades: &[u8]) -> Vec<u32> {
+        let mut schedule = Schedule::NEW;
+        let mut now = START;
+        grades
+            .iter()
+            .map(|&grade| {
+                schedule = schedule.next(grade, now);
+                now = schedule.due;
+                schedule.interval
+            })
+            .collect()
+    }
+
+    #[test]
+    fn intervals_follow_sm2() {
+        // 1 day, 6 days, then × ease (2.5 stays 2.5 at grade 4)
+        assert_eq!(intervals(&[4, 4, 4, 4]), vec![1, 6, 15, 38]);
+        // Grade 5 raises the ease by 0.10 each time: 2.6, 2.7, 2.8
+        assert_eq!(intervals(&[5, 5, 5, 5]), vec![1, 6, 16, 45]);
+        // Grade 3 lowers it by 0.14: 2.36, 2.22, 2.08
+        assert_eq!(intervals(&[3, 3, 3, 3]), vec![1, 6, 13, 27]);
+    }
+
+    #[test]
+    fn forgetting_restarts_the_intervals_but_keeps_the_ease() {
+        let learned = Schedule::NEW.next(5, START).next(5, START).next(5, START);
+        assert_eq!((learned.reps, learned.interval, learned.ease), (3, 16, 280));
+
+        let forgotten = learned.next(1, START + 100);
+        assert_eq!(
+            forgotten,
+            Schedule {
+                reps: 0,
+                interval: 1,
+                ease: 280,
+                due: START + 100 + DAY,
+            }
+        );
+        assert_eq!(forgotten.next(4, START).interval, 1);
+    }
+
+    #[test]
+    fn ease_never_drops_below_the_minimum() {
+        let mut schedule = Schedule::NEW;
+        for _ in 0..10 {
+            schedule = schedule.next(3, START);
+        }
+        assert_eq!(schedule.ease, Schedule::MIN_EASE);
+    }
+
+    #[test]
+    fn due_lists_overdue_cards_then_new_ones() {
+        let terms = vec![
+            term("option", "`.map(f)`"),
+            term("option", "`.unwrap()`"),
+            term("result", "`?`"),
+            term("result", "`.map(f)`"),
+            term("result", "`?`"),
+            term("iterators", "`.filter()`"),
+        ];
+        let mut deck = Deck::default();
+        deck.record(&terms[1], 4, START); // due START + 1 day
+        deck.record(&terms[3], 1, START - DAY); // due START
+        deck.record(&terms[5], 5, START + DAY); // due START + 2 days
+
+        let ids = |cards: Vec<&Term>| -> Vec<String> {
+            cards
+                .iter()
+                .map(|t| format!("{} {}", t.lesson, t.term))
+                .collect()
+        };
+        assert_eq!(
+            ids(deck.due(&terms, START + DAY, 10)),
+            vec![
+                "result `.map(f)`",
+                "option `.unwrap()`",
+                "option `.map(f)`",
+                "result `?`"
+            ]
+        );
+        assert_eq!(
+            ids(deck.due(&terms, START, 1)),
+            vec!["result `.map(f)`", "option `.map(f)`"]
+        );
+        assert_eq!(deck.next_due(), Some(START));
+    }
+
+    #[test]
+    fn deck_round_trips_through_text() {
+        let mut deck = Deck::default();
+        deck.record(&term("option", "`.unwrap_or(x)`"), 5, START);
+        deck.record(&term("loops", "`for i in range { }`"), 2, START);
+        let text = deck.to_text();
+        assert!(text.contains(
+            "card loops due=1760832000 interval=1 ease=250 reps=0 term=`for i in range { }`\n"
+        ));
+        assert_eq!(Deck::from_text(&text), Ok(deck));
+
+        assert_eq!(
+            Deck::from_text("rust-lab-cards 1\ncard option due=soon term=x").unwrap_err(),
+            (2, "invalid value for `due`: `soon`".to_string())
+        );
+        assert_eq!(Deck::from_text("").unwrap_err().0, 1);
+    }
+
+    #[test]
+    fn session_grades_and_repeats_until_remembered() {
+        let registry = lessons::registry();
+        let terms = [term("option", "`.map(f)`"), term("result", "`?`")];
+        let cards: Vec<&Term> = terms.iter().collect();
+        let mut deck = Deck::default();
+
+        // Card 1: shown, grade 5. Card 2: shown, "x" rejected, grade 2.
+        // Card 2 again: grade 4, which leaves its schedule alone.
+        let mut input = "\n5\n\nx\n2\n\n4\n".as_bytes();
+        let mut out = Vec::new();
+        let summary = run(
+            &registry,
+            &cards,
+            &mut deck,
+            &FixedClock(START),
+            &mut input,
+            &mut out,
+        )
+        .unwrap();
+        let out = String::from_utf8(out).unwrap();
+
+        assert_eq!(
+            summary,
+            Summary {
+                reviewed: 2,
+                remembered: 1
+            }
+        );
+        assert_eq!(deck.schedule(&terms[0]), Some(Schedule::NEW.next(5, START)));
+        assert_eq!(deck.schedule(&terms[1]), Some(Schedule::NEW.next(2, START)));
+        assert!(out.contains("Card 2/2 · Lesson 14: Result<T, E> Type · Key Concepts"));
+        assert!(out.contains("(answer 0 to 5, or q to stop)"));
+        assert!(out.contains("Again · Lesson 14"));
+        assert!(out.contains("Next review in 1 day (2025-10-19)"));
+
+        // `q` stops before anything is graded
+        let mut deck = Deck::default();
+        let mut input = "q\n".as_bytes();
+        let summary = run(
+            &registry,
+            &cards,
+            &mut deck,
+            &FixedClock(START),
+            &mut input,
+            &mut Vec::new(),
+        )
+        .unwrap();
+        assert_eq!(summary, Summary::default());
+        assert_eq!(deck, Deck::default());
+    }
+}
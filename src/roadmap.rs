@@ -0,0 +1,234 @@
+//! ============================================================================
+//! ROADMAP MODULE - Keeping `learning_curve.md` in step with the lessons
+//! ============================================================================
+//! The roadmap is plain Markdown: `## ` headings open a section (phases are
+//! titled `📍 Phase N — Title`) and `- [ ]` / `- [x]` lines are items.
+//! `LINKS` says which lessons teach which item, and an item is completed once
+//! all of its lessons have been run to the end.
+//!
+//! `roadmap --write` only ever ticks boxes: a box ticked by hand stays ticked,
+//! and every byte other than the ticked marks (line endings included) is
+//! written back unchanged.
+//! ============================================================================
+
+use std::collections::HashSet;
+
+use crate::lessons::Registry;
+use crate::progress::Progress;
+
+/// Roadmap items (matched by a case-insensitive piece of their text) and the
+/// lessons that teach them
+pub const LINKS: &[(&str, &[&str])] = &[
+    // Phase 1 — Core Safety & Patterns
+    ("Ownership deep dive", &["ownership", "borrowing"]),
+    ("Structs & nested structs", &["structs"]),
+    ("Enums with data", &["enums"]),
+    ("`Option<T>` and `Result<T, E>`", &["option", "result"]),
+    ("Slices & `Vec<T>`", &["arrays", "vectors"]),
+    ("Iterators (`map`", &["iterators"]),
+    ("`String` & heap memory", &["ownership", "collections"]),
+    (
+        "references in functions",
+        &["functions-ownership", "borrowing-functions"],
+    ),
+    // Phase 3 — Error Handling & Pipelines
+    ("`?` operator", &["result"]),
+    ("`.map_err()`, `.and_then()`", &["option", "result"]),
+    // Phase 4 — Collections & Data Structures
+    ("`VecDeque`, `HashMap`, `HashSet`", &["collections"]),
+    ("Borrowing rules for collections", &["vectors"]),
+    ("Iterators for collections", &["iterators"]),
+    ("`.collect()` into different types", &["iterators"]),
+];
+
+/// A `## ` section of the roadmap
+#[derive(Debug, PartialEq)]
+pub struct Section {
+    pub title: String,
+    pub items: Vec<Item>,
+}
+
+/// One `- [ ] text` line
+#[derive(Debug, PartialEq)]
+pub struct Item {
+    /// 0-based line index in the file
+    pub line: usize,
+    pub text: String,
+}
+
+/// How far the learner has got with a roadmap item
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Status {
+    NoLesson,
+    Available,
+    Completed,
+}
+
+/// Splits the roadmap into sections; text before the first `## ` is ignored
+pub fn parse(text: &str) -> Vec<Section> {
+    let mut sections: Vec<Section> = Vec::new();
+
+    for (line, raw) in text.lines().enumerate() {
+        if let Some(heading) = raw.strip_prefix("## ") {
+            let title = heading
+                .trim_start_matches(|c: char| !c.is_alphanumeric())
+                .trim()
+                .to_string();
+            sections.push(Section {
+                title,
+                items: Vec::new(),
+            });
+        } else if let (Some(section), Some((_, text))) = (sections.last_mut(), checkbox(raw)) {
+            section.items.push(Item {
+                line,
+                text: text.to_string(),
+            });
+        }
+    }
+    sections
+}
+
+/// `- [ ] text` / `- [x] text` → (checked, text)
+fn checkbox(line: &str) -> Option<(bool, &str)> {
+    let rest = line.trim_start().strip_prefix("- [")?;
+    let (mark, text) = rest.split_at_checked(1)?;
+    let text = text.strip_prefix("] ")?;
+    match mark {
+        " " => Some((false, text.trim())),
+        "x" | "X" => Some((true, text.trim())),
+        _ => None,
+    }
+}
+
+/// Lessons linked to an item, or an empty slice
+pub fn lessons_for(item: &str) -> &'static [&'static str] {
+    let item = item.to_lowercase();
+    LINKS
+        .iter()
+        .find(|(pattern, _)| item.contains(&pattern.to_lowercase()))
+        .map_or(&[], |(_, lessons)| lessons)
+}
+
+/// Completed when every linked lesson has been run to the end
+pub fn status(item: &Item, registry: &Registry, progress: &Progress) -> Status {
+    let lessons = lessons_for(&item.text);
+    if lessons.is_empty() {
+        return Status::NoLesson;
+    }
+    let completed = lessons.iter().all(|id| {
+        registry.find(id).is_some()
+            && progress
+                .lesson(id)
+                .is_some_and(|lesson| lesson.completed.is_some())
+    });
+    if completed {
+        Status::Completed
+    } else {
+        Status::Available
+    }
+}
+
+/// Ticks the box of every completed item that is still `[ ]`; boxes are never
+/// unticked. Returns the new text and how many boxes were ticked
+pub fn sync_checkboxes(text: &str, registry: &Registry, progress: &Progress) -> (String, usize) {
+    let completed: HashSet<usize> = parse(text)
+        .iter()
+        .flat_map(|section| &section.items)
+        .filter(|item| status(item, registry, progress) == Status::Completed)
+        .map(|item| item.line)
+        .collect();
+
+    let mut changed = 0;
+    let mut result = String::with_capacity(text.len());
+    // `split_inclusive` keeps each line's own ending (`\n` or `\r\n`)
+    for (line, raw) in text.split_inclusive('\n').enumerate() {
+        if completed.contains(&line) && checkbox(raw).is_some_and(|(checked, _)| !checked) {
+            let start = raw.find("- [ ]").expect("unticked checkbox line");
+            result.push_str(&raw[..start]);
+            result.push_str("- [x]");
+            result.push_str(&raw[start + "- [x]".len()..]);
+            changed += 1;
+        } else {
+            result.push_str(raw);
+        }
+    }
+    (result, changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lessons;
+
+    const ROADMAP: &str = "# Roadmap\r\n\
+\r\n\
+## 📍 Phase 1 — Core Safety\r\n\
+- [ ] Ownership deep dive\r\n\
+- [x] Lifetimes, ticked by hand\r\n\
+  - [x] Structs & nested structs\r\n\
+- [ ] Enums with data\r\n\
+Notes without a trailing newline";
+
+    fn progress(completed: &[&str]) -> Progress {
+        let mut progress = Progress::default();
+        for id in completed {
+            progress.record_run(id, 1_760_745_600);
+        }
+        progress
+    }
+
+    #[test]
+    fn parse_finds_sections_and_items() {
+        let sections = parse(ROADMAP);
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].title, "Phase 1 — Core Safety");
+        let items: Vec<(usize, &str)> = sections[0]
+            .items
+            .iter()
+            .map(|item| (item.line, item.text.as_str()))
+            .collect();
+        assert_eq!(
+            items,
+            [
+                (3, "Ownership deep dive"),
+                (4, "Lifetimes, ticked by hand"),
+                (5, "Structs & nested structs"),
+                (6, "Enums with data"),
+            ]
+        );
+    }
+
+    #[test]
+    fn sync_only_ticks_completed_items() {
+        let registry = lessons::registry();
+        let (synced, changed) =
+            sync_checkboxes(ROADMAP, &registry, &progress(&["ownership", "borrowing"]));
+        assert_eq!(changed, 1);
+        assert_eq!(
+            synced,
+            ROADMAP.replace("- [ ] Ownership deep dive", "- [x] Ownership deep dive")
+        );
+    }
+
+    #[test]
+    fn sync_keeps_hand_ticked_boxes_and_every_other_byte() {
+        let registry = lessons::registry();
+        // Nothing completed: "Structs" has a lesson but stays ticked
+        let (synced, changed) = sync_checkboxes(ROADMAP, &registry, &progress(&[]));
+        assert_eq!(changed, 0);
+        assert_eq!(synced, ROADMAP);
+        assert_eq!(synced.matches("\r\n").count(), 7);
+    }
+
+    #[test]
+    fn links_name_known_lessons() {
+        let registry = lessons::registry();
+        let unknown: Vec<String> = LINKS
+            .iter()
+            .flat_map(|(item, ids)| ids.iter().map(move |id| (item, id)))
+            .filter(|(_, id)| registry.find(id).is_none())
+            .map(|(item, id)| format!("roadmap item '{}' links to unknown lesson '{}'", item, id))
+            .collect();
+        assert_eq!(unknown, Vec::<String>::new());
+    }
+}
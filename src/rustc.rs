@@ -0,0 +1,278 @@
+//! ============================================================================
+//! RUSTC MODULE - Compiles snippets with the local rustc and reads diagnostics
+//! ============================================================================
+//! `check()` writes a snippet into a fresh temporary directory and runs
+//!
+//!     rustc --edition 2024 --crate-type bin --emit=metadata --error-format=json
+//!
+//! on it. Each stderr line is one JSON diagnostic, parsed into a `Diagnostic`.
+//! `parse_diagnostics()` also reads `cargo build --message-format=json` output,
+//! where every diagnostic is wrapped in a `compiler-message` record.
+//!
+//! `--emit=metadata` stops after borrow checking, so no code is generated and
+//! no binary is written. rustc runs inside the temporary directory on a
+//! relative file name, so the rendered messages say `--> snippet.rs:4:9`
+//! instead of a temp path. `$RUSTC` overrides which compiler is used, as it
+//! does for cargo.
+//! ============================================================================
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::process::{self, Command};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::json::{self, Json};
+
+/// Everything that can stop a snippet from being compiled at all
+#[derive(Debug)]
+pub enum RustcError {
+    /// rustc could not be started (usually: not installed or not on PATH)
+    Spawn {
+        program: String,
+        error: io::Error,
+    },
+    Io {
+        path: PathBuf,
+        error: io::Error,
+    },
+}
+
+impl fmt::Display for RustcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RustcError::Spawn { program, error } => write!(
+                f,
+                "cannot run `{}`: {} (is Rust installed? set RUSTC to point at rustc)",
+                program, error
+            ),
+            RustcError::Io { path, error } => write!(f, "{}: {}", path.display(), error),
+        }
+    }
+}
+
+impl std::error::Error for RustcError {}
+
+/// The primary location of a diagnostic
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span {
+    pub file: String,
+    pub line: usize,
+    pub column: usize,
+    pub label: Option<String>,
+}
+
+/// One message from rustc
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    /// `error`, `warning`, `note`, ...
+    pub level: String,
+    /// Error code such as `E0382`, when rustc gives one
+    pub code: Option<String>,
+    pub message: String,
+    pub primary: Option<Span>,
+    /// The message as rustc would print it, with source excerpt and carets
+    pub rendered: Option<String>,
+}
+
+impl Diagnostic {
+    /// Reads a rustc diagnostic object; `None` for any other JSON
+    pub fn from_json(value: &Json) -> Option<Diagnostic> {
+        let message = value.get("message")?.as_str()?.to_string();
+        let level = value.get("level")?.as_str()?.to_string();
+        let code = value
+            .get("code")
+            .and_then(|code| code.get("code"))
+            .and_then(Json::as_str)
+            .map(String::from);
+        let primary = value
+            .get("spans")
+            .map(Json::as_array)
+            .unwrap_or_default()
+            .iter()
+            .find(|span| span.get("is_primary").and_then(Json::as_bool) == Some(true))
+            .and_then(|span| {
+                Some(Span {
+                    file: span.get("file_name")?.as_str()?.to_string(),
+                    line: span.get("line_start")?.as_usize()?,
+                    column: span.get("column_start")?.as_usize()?,
+                    label: span.get("label").and_then(Json::as_str).map(String::from),
+                })
+            });
+        let rendered = value
+            .get("rendered")
+            .and_then(Json::as_str)
+            .map(String::from);
+
+        Some(Diagnostic {
+            level,
+            code,
+            message,
+            primary,
+            rendered,
+        })
+    }
+
+    /// A real error, not the closing "aborting due to N previous errors"
+    pub fn is_error(&self) -> bool {
+        self.level == "error" && !self.message.starts_with("aborting due to")
+    }
+}
+
+/// Every diagnostic in rustc's (or cargo's) JSON output; lines that are not
+/// JSON diagnostics are skipped
+pub fn parse_diagnostics(text: &str) -> Vec<Diagnostic> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| line.starts_with('{'))
+        .filter_map(|line| json::parse(line).ok())
+        .filter_map(|value| Diagnostic::from_json(unwrap_cargo(&value)))
+        .collect()
+}
+
+/// The rustc diagnostic inside a cargo `{"reason": "compiler-message",
+/// "message": {...}}` record; any other value is returned as is
+fn unwrap_cargo(value: &Json) -> &Json {
+    match value.get("reason").and_then(Json::as_str) {
+        Some("compiler-message") => value.get("message").unwrap_or(value),
+        _ => value,
+    }
+}
+
+/// The result of compiling one snippet
+#[derive(Debug)]
+pub struct Compilation {
+    pub success: bool,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Compilation {
+    /// The errors, in the order rustc reported them
+    pub fn errors(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.diagnostics.iter().filter(|d| d.is_error())
+    }
+
+    /// Codes of every error (`E0382`, ...), in order
+    pub fn error_codes(&self) -> Vec<&str> {
+        self.errors().filter_map(|d| d.code.as_deref()).collect()
+    }
+}
+
+/// The compiler to run: `$RUSTC` or `rustc` from PATH
+pub fn program() -> String {
+    std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string())
+}
+
+/// `rustc --version`, also a quick check that the compiler can be run
+pub fn version() -> Result<String, RustcError> {
+    let program = program();
+    let output = Command::new(&program)
+        .arg("--version")
+        .output()
+        .map_err(|error| RustcError::Spawn { program, error })?;
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Compiles `source` as a binary crate named after `file_name`
+pub fn check(source: &str, file_name: &str) -> Result<Compilation, RustcError> {
+    // Tests compile snippets in parallel, so every call gets its own directory
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+    let dir = std::env::temp_dir().join(format!(
+        "rust-lab-rustc-{}-{}",
+        process::id(),
+        NEXT.fetch_add(1, Ordering::Relaxed)
+    ));
+    let io_error = |path: &PathBuf| {
+        let path = path.clone();
+        move |error| RustcError::Io { path, error }
+    };
+    fs::create_dir_all(&dir).map_err(io_error(&dir))?;
+    let file = dir.join(file_name);
+    fs::write(&file, source).map_err(io_error(&file))?;
+
+    let program = program();
+    let output = Command::new(&program)
+        .current_dir(&dir)
+        .args([
+            "--edition",
+            "2024",
+            "--crate-type",
+            "bin",
+            "--emit=metadata",
+        ])
+        .arg("--error-format=json")
+        .arg(file_name)
+        .output();
+    let _ = fs::remove_dir_all(&dir);
+    let output = output.map_err(|error| RustcError::Spawn { program, error })?;
+
+    Ok(Compilation {
+        success: output.status.success(),
+        diagnostics: parse_diagnostics(&String::from_utf8_lossy(&output.stderr)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOVED: &str = r#"{"$message_type":"diagnostic","message":"borrow of moved value: `s`","code":{"code":"E0382","explanation":"A variable was used after its contents were moved."},"level":"error","spans":[{"file_name":"snippet.rs","line_start":2,"column_start":9,"is_primary":false,"label":"move occurs because `s` has type `String`"},{"file_name":"snippet.rs","line_start":4,"column_start":20,"is_primary":true,"label":"value borrowed here after move"}],"children":[],"rendered":"error[E0382]: borrow of moved value: `s`\n"}"#;
+    const UNUSED: &str = r#"{"message":"unused variable: `x`","code":{"code":"unused_variables"},"level":"warning","spans":[],"rendered":null}"#;
+    const ABORTING: &str =
+        r#"{"message":"aborting due to 1 previous error","code":null,"level":"error","spans":[]}"#;
+
+    #[test]
+    fn reads_rustc_diagnostics() {
+        let text = format!("{}\n{}\n{}\n", MOVED, UNUSED, ABORTING);
+        let diagnostics = parse_diagnostics(&text);
+        assert_eq!(diagnostics.len(), 3);
+
+        let moved = &diagnostics[0];
+        assert_eq!(moved.level, "error");
+        assert_eq!(moved.code.as_deref(), Some("E0382"));
+        assert_eq!(moved.message, "borrow of moved value: `s`");
+        assert_eq!(
+            moved.primary,
+            Some(Span {
+                file: "snippet.rs".to_string(),
+                line: 4,
+                column: 20,
+                label: Some("value borrowed here after move".to_string()),
+            })
+        );
+        assert_eq!(
+            moved.rendered.as_deref(),
+            Some("error[E0382]: borrow of moved value: `s`\n")
+        );
+
+        assert_eq!(diagnostics[1].primary, None);
+        assert_eq!(diagnostics[1].rendered, None);
+        assert!(!diagnostics[1].is_error());
+        assert_eq!(diagnostics[2].code, None);
+        assert!(!diagnostics[2].is_error());
+
+        let compilation = Compilation {
+            success: false,
+            diagnostics,
+        };
+        assert_eq!(compilation.errors().count(), 1);
+        assert_eq!(compilation.error_codes(), ["E0382"]);
+    }
+
+    #[test]
+    fn unwraps_cargo_records_and_skips_everything_else() {
+        let text = format!(
+            "   Compiling demo v0.1.0\n\
+             {{\"reason\":\"compiler-artifact\",\"package_id\":\"demo\"}}\n\
+             {{\"reason\":\"compiler-message\",\"package_id\":\"demo\",\"message\":{}}}\n\
+             {{ not json\n\
+             {{\"reason\":\"build-finished\",\"success\":false}}\n\
+             error: could not compile `demo`\n",
+            MOVED
+        );
+        let diagnostics = parse_diagnostics(&text);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code.as_deref(), Some("E0382"));
+    }
+}
@@ -0,0 +1,456 @@
+//! ============================================================================
+//! SEARCH MODULE - Full-text search over lesson titles, sections and concepts
+//! ============================================================================
+//! `rust-lab search <terms>` looks words up in an index built in memory from
+//! every lesson. Each indexed piece of text is an `Entry`:
+//!
+//! | Field      | Text                                      | Weight |
+//! |------------|-------------------------------------------|--------|
+//! | `Title`    | `Iterators & Functional Combinators`      | 5      |
+//! | `Section`  | `chain(): Concatenate Iterators`          | 3      |
+//! | `Concepts` | One line of the `Key Concepts` doc text   | 1      |
+//!
+//! Words are runs of letters, digits and `_`, lowercased and stemmed
+//! (`borrowing`, `borrowed`, `borrows` → `borrow`). `iter_mut` is indexed as
+//! `iter_mut`, `iter` and `mut`, so both the whole name and its parts find it.
+//!
+//! Score = Σ weight × idf × (1 + ln tf) over the matched terms, scaled by the
+//! share of query terms matched, so entries with every term rank first.
+//! ============================================================================
+
+use std::collections::HashMap;
+
+use crate::content::Block;
+use crate::highlight::Colors;
+use crate::lessons::{self, Lesson, Registry};
+use crate::output::CaptureOutput;
+use crate::source;
+
+/// Where in a lesson a piece of text comes from
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Field {
+    Title,
+    Section,
+    Concepts,
+}
+
+impl Field {
+    fn weight(self) -> f64 {
+        match self {
+            Field::Title => 5.0,
+            Field::Section => 3.0,
+            Field::Concepts => 1.0,
+        }
+    }
+}
+
+/// One indexed piece of text
+#[derive(Debug)]
+pub struct Entry {
+    pub lesson: &'static str,
+    pub field: Field,
+    /// Section title for `Field::Section`
+    pub section: Option<String>,
+    pub text: String,
+}
+
+/// Words that carry no meaning on their own; ignored unless the query has
+/// nothing else
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "are", "by", "do", "does", "for", "how", "i", "in", "is", "it", "of", "on",
+    "or", "the", "to", "what", "when", "where", "which", "why", "with",
+];
+
+/// The inverted index: term → (entry, occurrences in that entry)
+pub struct Index {
+    entries: Vec<Entry>,
+    postings: HashMap<String, Vec<(usize, u32)>>,
+}
+
+impl Index {
+    /// Indexes every lesson of the registry
+    pub fn build(registry: &Registry) -> Index {
+        let mut entries = Vec::new();
+        for lesson in registry.lessons() {
+            entries.extend(lesson_entries(lesson));
+        }
+        Index::from_entries(entries)
+    }
+
+    fn from_entries(entries: Vec<Entry>) -> Index {
+        let mut postings: HashMap<String, Vec<(usize, u32)>> = HashMap::new();
+        for (i, entry) in entries.iter().enumerate() {
+            let mut counts: HashMap<String, u32> = HashMap::new();
+            for (_, word) in words(&entry.text) {
+                for term in terms(word) {
+                    *counts.entry(term).or_default() += 1;
+                }
+            }
+            for (term, count) in counts {
+                postings.entry(term).or_default().push((i, count));
+            }
+        }
+        Index { entries, postings }
+    }
+
+    /// Entries matching any query term, best first
+    pub fn search(&self, query: &str) -> Vec<&Entry> {
+        let query = query_terms(query);
+        let total = self.entries.len() as f64;
+        // entry → (score, number of query terms matched)
+        let mut scores: HashMap<usize, (f64, usize)> = HashMap::new();
+
+        for term in &query {
+            let Some(postings) = self.postings.get(term) else {
+                continue;
+            };
+            let idf = (1.0 + total / postings.len() as f64).ln();
+            for &(i, count) in postings {
+                let score = self.entries[i].field.weight() * idf * (1.0 + f64::from(count).ln());
+                let slot = scores.entry(i).or_default();
+                slot.0 += score;
+                slot.1 += 1;
+            }
+        }
+
+        let mut hits: Vec<(usize, f64)> = scores
+            .into_iter()
+            .map(|(i, (score, matched))| {
+                let coverage = matched as f64 / query.len() as f64;
+                (i, score * coverage * coverage)
+            })
+            .collect();
+        // Ties keep teaching order (entries are indexed lesson by lesson)
+        hits.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        hits.into_iter().map(|(i, _)| &self.entries[i]).collect()
+    }
+}
+
+/// Title, section titles (as the lesson prints them) and Key Concepts lines
+fn lesson_entries(lesson: &dyn Lesson) -> Vec<Entry> {
+    let meta = lesson.meta();
+    let entry = |field, section, text: &str| Entry {
+        lesson: meta.id,
+        field,
+        section,
+        text: text.to_string(),
+    };
+    let mut entries = vec![entry(Field::Title, None, meta.title)];
+
+    let mut out = CaptureOutput::default();
+    lesson.run(&mut out);
+    for block in out.into_blocks() {
+        if let Block::Section(title) = block {
+            entries.push(entry(Field::Section, Some(title.clone()), &title));
+        }
+    }
+
+    let doc = lessons::module_source(meta.module)
+        .and_then(|module| source::doc_comment(module, meta.entry))
+        .unwrap_or_default();
+    for line in doc.lines() {
+        let line = clean_doc_line(line);
+        if words(&line).next().is_some() && !is_table_rule(&line) {
+            entries.push(entry(Field::Concepts, None, &line));
+        }
+    }
+    entries
+}
+
+/// A doc line without list bullets, table borders or repeated spaces
+fn clean_doc_line(line: &str) -> String {
+    let line = line.trim();
+    let line = line.strip_prefix("- ").unwrap_or(line);
+    let line = line.trim_matches('|');
+    line.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// `---|:---:|---` between a table header and its rows
+fn is_table_rule(line: &str) -> bool {
+    line.chars().all(|c| matches!(c, '-' | ':' | '|' | ' '))
+}
+
+/// Runs of letters, digits and `_` with their byte offsets
+fn words(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let mut rest = text.char_indices().peekable();
+    std::iter::from_fn(move || {
+        let (start, _) = rest.find(|&(_, c)| is_word(c))?;
+        let mut end = text.len();
+        while let Some(&(i, c)) = rest.peek() {
+            if !is_word(c) {
+                end = i;
+                break;
+            }
+            rest.next();
+        }
+        Some((start, &text[start..end]))
+    })
+}
+
+/// Index terms of one word: its stem, plus the stems of its `_`-separated parts
+fn terms(word: &str) -> Vec<String> {
+    let mut terms = vec![stem(word)];
+    if word.contains('_') {
+        for part in word.split('_').filter(|p| !p.is_empty()) {
+            let part = stem(part);
+            if !terms.contains(&part) {
+                terms.push(part);
+            }
+        }
+    }
+    terms
+}
+
+/// Lowercases and strips common English endings: `-ies`, `-es`, `-s`,
+/// `-ing`, `-ed` (keeping at least three letters)
+fn stem(word: &str) -> String {
+    let word = word.to_lowercase();
+    let keep = |suffix: &str| word.len() >= suffix.len() + 3 && word.ends_with(suffix);
+    // Only the `es` goes after a hissing sound, so `boxes` keeps three letters
+    let hissing = |suffix: &str| word.len() >= 5 && word.ends_with(suffix);
+
+    if keep("ies") {
+        format!("{}y", &word[..word.len() - 3])
+    } else if keep("ing") {
+        word[..word.len() - 3].to_string()
+    } else if keep("ed") || ["sses", "xes", "ches", "shes"].iter().any(|s| hissing(s)) {
+        // `-ed`, or the `-es` plural after a hissing sound
+        word[..word.len() - 2].to_string()
+    } else if keep("s") && !word.ends_with("ss") && !word.ends_with("us") {
+        word[..word.len() - 1].to_string()
+    } else {
+        word
+    }
+}
+
+/// Distinct stems of the query, without stop words (unless that leaves none)
+fn query_terms(query: &str) -> Vec<String> {
+    let all: Vec<&str> = words(query).map(|(_, word)| word).collect();
+    let meaningful: Vec<&str> = all
+        .iter()
+        .copied()
+        .filter(|word| !STOP_WORDS.contains(&word.to_lowercase().as_str()))
+        .collect();
+    let chosen = if meaningful.is_empty() {
+        all
+    } else {
+        meaningful
+    };
+
+    let mut terms = Vec::new();
+    for word in chosen {
+        let term = stem(word);
+        if !terms.contains(&term) {
+            terms.push(term);
+        }
+    }
+    terms
+}
+
+/// Longest snippet shown, in characters
+const SNIPPET_WIDTH: usize = 90;
+
+/// The entry text cut to `SNIPPET_WIDTH` around the first match, with the
+/// matched words in bold yellow (`Colors::Ansi`) or `[brackets]` (`Plain`)
+pub fn snippet(entry: &Entry, query: &str, colors: Colors) -> String {
+    let query = query_terms(query);
+    let text = &entry.text;
+    let matches: Vec<(usize, &str)> = words(text)
+        .filter(|(_, word)| terms(word).iter().any(|t| query.contains(t)))
+        .collect();
+
+    // Window of SNIPPET_WIDTH characters, starting a little before the match
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let first = matches.first().map_or(0, |&(at, _)| at);
+    let first_char = chars.iter().position(|&(i, _)| i >= first).unwrap_or(0);
+    let start_char = if chars.len() <= SNIPPET_WIDTH {
+        0
+    } else {
+        first_char
+            .saturating_sub(20)
+            .min(chars.len() - SNIPPET_WIDTH)
+    };
+    let end_char = (start_char + SNIPPET_WIDTH).min(chars.len());
+    let start = chars.get(start_char).map_or(text.len(), |&(i, _)| i);
+    let end = chars.get(end_char).map_or(text.len(), |&(i, _)| i);
+
+    let mut snippet = String::new();
+    if start > 0 {
+        snippet.push('…');
+    }
+    let mut at = start;
+    for &(word_start, word) in &matches {
+        let word_end = word_start + word.len();
+        if word_start < start || word_end > end {
+            continue;
+        }
+        snippet.push_str(&text[at..word_start]);
+        match colors {
+            Colors::Ansi => snippet.push_str(&format!("\x1b[1;33m{}\x1b[0m", word)),
+            Colors::Plain => snippet.push_str(&format!("[{}]", word)),
+        }
+        at = word_end;
+    }
+    snippet.push_str(&text[at..end]);
+    if end < text.len() {
+        snippet.push('…');
+    }
+    snippet
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(lesson: &'static str, field: Field, text: &str) -> Entry {
+        Entry {
+            lesson,
+            field,
+            section: None,
+            text: text.to_string(),
+        }
+    }
+
+    fn found(index: &Index, query: &str) -> Vec<(&'static str, Field)> {
+        index
+            .search(query)
+            .iter()
+            .map(|entry| (entry.lesson, entry.field))
+            .collect()
+    }
+
+    #[test]
+    fn word_forms_share_a_stem() {
+        for word in ["borrowing", "borrowed", "borrows", "Borrow"] {
+            assert_eq!(stem(word), "borrow", "{}", word);
+        }
+        assert_eq!(stem("entries"), "entry");
+        assert_eq!(stem("boxes"), "box");
+        assert_eq!(stem("classes"), "class");
+        assert_eq!(stem("status"), "status");
+        assert_eq!(stem("is"), "is");
+        assert_eq!(stem("red"), "red");
+    }
+
+    #[test]
+    fn snake_case_names_are_found_whole_and_by_part() {
+        assert_eq!(terms("iter_mut"), ["iter_mut", "iter", "mut"]);
+        let index = Index::from_entries(vec![
+            entry(
+                "iterators",
+                Field::Concepts,
+                "`iter_mut()` → Mutable references",
+            ),
+            entry("loops", Field::Concepts, "Plain loops"),
+        ]);
+        for query in ["iter_mut", "iter", "mut", "ITER_MUT"] {
+            assert_eq!(
+                found(&index, query),
+                [("iterators", Field::Concepts)],
+                "{}",
+                query
+            );
+        }
+    }
+
+    #[test]
+    fn stop_words_are_dropped_unless_nothing_else_is_left() {
+        assert_eq!(query_terms("what is borrowing?"), ["borrow"]);
+        assert_eq!(query_terms("the of the"), ["the", "of"]);
+        let index = Index::from_entries(vec![entry("enums", Field::Concepts, "Which one is it")]);
+        assert_eq!(found(&index, "which is it"), [("enums", Field::Concepts)]);
+        assert!(found(&index, "which is ownership").is_empty());
+    }
+
+    #[test]
+    fn titles_outrank_sections_outrank_concepts() {
+        let index = Index::from_entries(vec![
+            entry("a", Field::Concepts, "Ownership moves values"),
+            entry("b", Field::Section, "Ownership in functions"),
+            entry("c", Field::Title, "Ownership"),
+            entry("d", Field::Concepts, "Unrelated"),
+        ]);
+        assert_eq!(
+            found(&index, "ownership"),
+            [
+                ("c", Field::Title),
+                ("b", Field::Section),
+                ("a", Field::Concepts)
+            ]
+        );
+    }
+
+    #[test]
+    fn entries_matching_every_term_rank_first() {
+        let index = Index::from_entries(vec![
+            entry("a", Field::Title, "Vectors"),
+            entry("b", Field::Concepts, "Borrowing a vector"),
+            entry("c", Field::Title, "Borrowing"),
+        ]);
+        assert_eq!(found(&index, "borrow vectors")[0], ("b", Field::Concepts));
+    }
+
+    #[test]
+    fn ties_keep_index_order() {
+        let index = Index::from_entries(vec![
+            entry("first", Field::Section, "Slices"),
+            entry("second", Field::Section, "Slices"),
+        ]);
+        assert_eq!(
+            found(&index, "slice"),
+            [("first", Field::Section), ("second", Field::Section)]
+        );
+    }
+
+    #[test]
+    fn snippet_marks_every_match() {
+        let entry = entry(
+            "x",
+            Field::Concepts,
+            "Borrowing rules: borrowed values can't outlive owners",
+        );
+        assert_eq!(
+            snippet(&entry, "borrow", Colors::Plain),
+            "[Borrowing] rules: [borrowed] values can't outlive owners"
+        );
+        assert_eq!(
+            snippet(&entry, "rules", Colors::Ansi),
+            "Borrowing \x1b[1;33mrules\x1b[0m: borrowed values can't outlive owners"
+        );
+    }
+
+    #[test]
+    fn snippet_window_counts_characters_not_bytes() {
+        // 60 two-byte and 60 three-byte characters before the match
+        let text = format!(
+            "{}{} ✅ borrowing at the end",
+            "é".repeat(60),
+            "中".repeat(60)
+        );
+        let entry = entry("x", Field::Concepts, &text);
+        let snippet = snippet(&entry, "borrow", Colors::Plain);
+        assert!(snippet.starts_with('…'), "{}", snippet);
+        assert!(
+            snippet.ends_with("✅ [borrowing] at the end"),
+            "{}",
+            snippet
+        );
+        // The window plus the ellipsis, minus the two added brackets
+        assert_eq!(snippet.chars().count(), SNIPPET_WIDTH + 1 + 2);
+    }
+
+    #[test]
+    fn snippet_of_a_long_line_is_cut_around_the_first_match() {
+        let text = format!("start {} ownership {}", "x".repeat(100), "y".repeat(100));
+        let entry = entry("x", Field::Concepts, &text);
+        let snippet = snippet(&entry, "ownership", Colors::Plain);
+        assert!(
+            snippet.starts_with('…') && snippet.ends_with('…'),
+            "{}",
+            snippet
+        );
+        assert!(snippet.contains(" [ownership] "), "{}", snippet);
+        assert_eq!(snippet.chars().count(), SNIPPET_WIDTH + 2 + 2);
+    }
+}
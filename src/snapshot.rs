@@ -0,0 +1,100 @@
+//! ============================================================================
+//! SNAPSHOT MODULE - Golden-output tests for every lesson
+//! ============================================================================
+//! Each registered lesson is run against a `CaptureOutput` and compared with
+//! `snapshots/<lesson-id>.snap`. On mismatch the test prints a line diff:
+//!
+//! - `- line` → in the snapshot, missing from the actual output
+//! - `+ line` → in the actual output, missing from the snapshot
+//!
+//! To accept new output after an intentional change:
+//!
+//!     RUST_LAB_BLESS=1 cargo test
+//! ============================================================================
+
+use std::fs;
+use std::path::PathBuf;
+
+use crate::diff::line_diff;
+
+/// Set to `1` to write actual output to the snapshot files instead of comparing
+pub const BLESS_ENV: &str = "RUST_LAB_BLESS";
+
+/// Directory holding the `.snap` files
+pub fn snapshot_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("snapshots")
+}
+
+fn blessing() -> bool {
+    std::env::var(BLESS_ENV).is_ok_and(|v| v == "1")
+}
+
+/// Compares `actual` with the stored snapshot `name`, or stores it when blessing.
+/// Returns a readable report on mismatch.
+pub fn check(name: &str, actual: &str) -> Result<(), String> {
+    let path = snapshot_dir().join(format!("{}.snap", name));
+
+    if blessing() {
+        fs::create_dir_all(snapshot_dir()).map_err(|e| e.to_string())?;
+        return fs::write(&path, actual).map_err(|e| format!("{}: {}", path.display(), e));
+    }
+
+    let expected = fs::read_to_string(&path).map_err(|_| {
+        format!(
+            "snapshot '{}' is missing; run `{}=1 cargo test` to create it",
+            name, BLESS_ENV
+        )
+    })?;
+
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(format!(
+            "snapshot '{}' does not match ({}):\n{}",
+            name,
+            path.display(),
+            line_diff(&expected, actual)
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lessons;
+    use crate::output::CaptureOutput;
+
+    #[test]
+    fn every_lesson_matches_its_snapshot() {
+        let registry = lessons::registry();
+        let failures: Vec<String> = registry
+            .lessons()
+            .filter_map(|lesson| {
+                let mut out = CaptureOutput::default();
+                lessons::run_lesson(lesson, &mut out);
+                check(lesson.id(), &out.text()).err()
+            })
+            .collect();
+
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    }
+
+    #[test]
+    fn no_snapshot_without_a_lesson() {
+        let registry = lessons::registry();
+        let orphans: Vec<String> = fs::read_dir(snapshot_dir())
+            .expect("snapshots directory")
+            .filter_map(|entry| {
+                let name = entry.ok()?.file_name().into_string().ok()?;
+                let id = name.strip_suffix(".snap")?.to_string();
+                registry.find(&id).is_none().then_some(name)
+            })
+            .collect();
+
+        assert!(
+            orphans.is_empty(),
+            "snapshots for unknown lessons: {:?}",
+            orphans
+        );
+    }
+}
@@ -0,0 +1,436 @@
+//! ============================================================================
+//! SOURCE MODULE - Reading lesson code and doc comments back from the source
+//! ============================================================================
+//! Lesson modules are embedded with `include_str!` (see
+//! `lessons::module_source`), so the binary can show its own code and the
+//! `Key Concepts` blocks written above each `learn_*` function.
+//!
+//! What can be read back:
+//! - `doc_comment()`       → Doc text above a function, banner rules removed
+//! - `doc_blocks()`        → That text as content blocks (paragraphs, lists, tables)
+//! - `function_source()`   → Full text of a top-level function
+//! - `example_functions()` → An entry function plus the helpers it calls
+//! - `section_code()`      → The statements under one `out.section(..)` call
+//!
+//! ============================================================================
+
+use crate::content::Block;
+use crate::lexer;
+use crate::table::Table;
+
+/// Line index of the top-level `fn name(` / `pub fn name(` definition
+fn definition_line(lines: &[&str], name: &str) -> Option<usize> {
+    let plain = format!("fn {}(", name);
+    let public = format!("pub fn {}(", name);
+    lines
+        .iter()
+        .position(|line| line.starts_with(&plain) || line.starts_with(&public))
+}
+
+/// Names of all top-level functions, in source order
+pub fn functions(source: &str) -> Vec<&str> {
+    source
+        .lines()
+        .filter_map(|line| {
+            let rest = line
+                .strip_prefix("pub fn ")
+                .or_else(|| line.strip_prefix("fn "))?;
+            rest.split(['(', '<']).next()
+        })
+        .collect()
+}
+
+/// Doc comment above a top-level function, without `///` prefixes, `====`
+/// rules or the numbered title line (`13. OPTION<T> TYPE`)
+pub fn doc_comment(source: &str, function: &str) -> Option<String> {
+    let lines: Vec<&str> = source.lines().collect();
+    let definition = definition_line(&lines, function)?;
+
+    let start = lines[..definition]
+        .iter()
+        .rposition(|line| !line.starts_with("///"))
+        .map_or(0, |i| i + 1);
+
+    let text: Vec<&str> = lines[start..definition]
+        .iter()
+        .map(|line| {
+            let line = line.strip_prefix("///").unwrap_or(line);
+            line.strip_prefix(' ').unwrap_or(line).trim_end()
+        })
+        .filter(|line| !is_rule(line) && !is_numbered_title(line))
+        .collect();
+
+    let text = text.join("\n").trim().to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+fn is_rule(line: &str) -> bool {
+    !line.is_empty() && line.chars().all(|c| c == '=')
+}
+
+fn is_numbered_title(line: &str) -> bool {
+    line.split_once(". ")
+        .is_some_and(|(number, _)| number.parse::<u32>().is_ok())
+}
+
+/// Turns doc text into blocks: `- item` runs become lists, `| a | b |` runs
+/// become tables, `Note:` lines become warnings, everything else becomes
+/// paragraphs
+pub fn doc_blocks(doc: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let lines: Vec<&str> = doc.lines().collect();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i].trim();
+        if line.is_empty() {
+            i += 1;
+        } else if line.starts_with('|') {
+            let end = run_end(&lines, i, |l| l.starts_with('|'));
+            blocks.push(Block::Table(parse_table(&lines[i..end])));
+            i = end;
+        } else if line.starts_with("- ") {
+            let end = run_end(&lines, i, |l| l.starts_with("- "));
+            let items = lines[i..end]
+                .iter()
+                .map(|l| l.trim()[2..].trim().to_string())
+                .collect();
+            blocks.push(Block::List(items));
+            i = end;
+        } else if let Some(note) = line.strip_prefix("Note:") {
+            blocks.push(Block::Warning(note.trim().to_string()));
+            i += 1;
+        } else {
+            blocks.push(Block::Paragraph(line.to_string()));
+            i += 1;
+        }
+    }
+    blocks
+}
+
+/// Index one past the last consecutive line (from `start`) matching `pred`
+fn run_end(lines: &[&str], start: usize, pred: impl Fn(&str) -> bool) -> usize {
+    lines[start..]
+        .iter()
+        .position(|l| !pred(l.trim()))
+        .map_or(lines.len(), |offset| start + offset)
+}
+
+/// Parses a Markdown table; the `|---|---|` separator row is skipped
+fn parse_table(lines: &[&str]) -> Table {
+    let cells = |line: &str| -> Vec<String> {
+        let line = line.trim().trim_start_matches('|').trim_end_matches('|');
+        line.split('|')
+            .map(|cell| cell.trim().to_string())
+            .collect()
+    };
+    let is_separator = |line: &str| line.chars().all(|c| matches!(c, '|' | '-' | ':' | ' '));
+
+    let headers = cells(lines[0]);
+    let mut table = Table::new(&headers.iter().map(String::as_str).collect::<Vec<_>>());
+    for line in &lines[1..] {
+        if is_separator(line) {
+            continue;
+        }
+        let mut row = cells(line);
+        row.resize(headers.len(), String::new());
+        table.rows.push(row);
+    }
+    table
+}
+
+/// Full text of a top-level function, from its signature to its closing brace
+pub fn function_source<'a>(source: &'a str, name: &str) -> Option<&'a str> {
+    let lines: Vec<&str> = source.lines().collect();
+    let definition = definition_line(&lines, name)?;
+    let start: usize = lines[..definition].iter().map(|l| l.len() + 1).sum();
+    let end = start + body_end(&source[start..])?;
+    Some(&source[start..end])
+}
+
+/// Byte offset just past the brace that closes the first `{ ... }` block;
+/// strings, chars and comments are single tokens, so their braces don't count
+fn body_end(code: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    for token in lexer::tokenize(code) {
+        if token.is("{") {
+            depth += 1;
+        } else if token.is("}") {
+            depth = depth.checked_sub(1)?;
+            if depth == 0 {
+                return Some(token.start + 1);
+            }
+        }
+    }
+    None
+}
+
+/// The entry function followed by every module function it calls
+/// (directly or through other helpers), in order of first call
+pub fn example_functions<'a>(source: &'a str, entry: &'a str) -> Vec<&'a str> {
+    reachable(source, vec![entry])
+}
+
+/// `roots` followed by every module function they call, transitively
+fn reachable<'a>(source: &'a str, roots: Vec<&'a str>) -> Vec<&'a str> {
+    let known = functions(source);
+    let mut found = roots;
+    let mut i = 0;
+
+    while i < found.len() {
+        if let Some(body) = function_source(source, found[i]) {
+            for name in called_names(body) {
+                if let Some(&name) = known.iter().find(|k| **k == name)
+                    && !found.contains(&name)
+                {
+                    found.push(name);
+                }
+            }
+        }
+        i += 1;
+    }
+    found
+}
+
+/// The code a lesson section runs: the statements after
+/// `out.section("title")` in the entry function, up to the next section, plus
+/// every module helper those statements call. `None` as the title selects the
+/// statements before the first section (the whole body if there is none).
+pub fn section_code(source: &str, entry: &str, title: Option<&str>) -> Option<String> {
+    let function = function_source(source, entry)?;
+    let body = &function[function.find('{')? + 1..function.rfind('}')?];
+    let lines: Vec<&str> = body.lines().collect();
+    let is_section = |line: &str| line.trim_start().starts_with("out.section(");
+
+    let start = match title {
+        None => 0,
+        Some(title) => {
+            let call = format!("out.section(\"{}\")", title);
+            lines.iter().position(|line| line.contains(&call))? + 1
+        }
+    };
+    let end = lines[start..]
+        .iter()
+        .position(|line| is_section(line))
+        .map_or(lines.len(), |offset| start + offset);
+
+    // Comments right above the next section describe that section, not this one
+    let mut snippet = &lines[start..end];
+    while let Some((last, rest)) = snippet.split_last() {
+        let last = last.trim();
+        if last.is_empty() || last.starts_with("//") {
+            snippet = rest;
+        } else {
+            break;
+        }
+    }
+    if snippet.iter().all(|line| line.trim().is_empty()) {
+        return None;
+    }
+
+    let snippet_text = snippet.join("\n");
+    let helpers: Vec<&str> = reachable(source, called_names(&snippet_text))
+        .into_iter()
+        .filter(|&name| name != entry && function_source(source, name).is_some())
+        .collect();
+
+    // A section that only calls helpers (`map_examples(out);`) is shown as
+    // the helpers themselves
+    let only_calls = snippet
+        .iter()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with("//"))
+        .all(|line| {
+            line.strip_suffix("(out);")
+                .is_some_and(|name| helpers.contains(&name))
+        });
+
+    let mut parts = Vec::new();
+    if !only_calls {
+        parts.push(dedent(snippet));
+    }
+    parts.extend(
+        helpers
+            .into_iter()
+            .filter_map(|name| function_source(source, name))
+            .map(String::from),
+    );
+    Some(parts.join("\n\n"))
+}
+
+/// Removes the indentation shared by all non-blank lines and leading blanks
+fn dedent(lines: &[&str]) -> String {
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    let text: Vec<&str> = lines
+        .iter()
+        .skip_while(|line| line.trim().is_empty())
+        .map(|line| line.get(indent..).unwrap_or("").trim_end())
+        .collect();
+    text.join("\n")
+}
+
+/// Identifiers directly followed by `(` (calls or definitions), in order
+fn called_names(code: &str) -> Vec<&str> {
+    let mut names = Vec::new();
+    let bytes = code.as_bytes();
+    let is_ident = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+    let mut start = None;
+
+    for (i, &b) in bytes.iter().enumerate() {
+        match (is_ident(b), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                if b == b'(' {
+                    names.push(&code[s..i]);
+                }
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lessons;
+    use crate::output::CaptureOutput;
+
+    const SAMPLE: &str = r#"pub fn learn(out: &mut dyn Output) {
+    let intro = 1;
+
+    // First part
+    out.section("First");
+    let a = 1;
+    outln!(out, "{}", a);
+
+    // Second part
+    out.section("Second");
+    second_examples(out);
+}
+
+fn second_examples(out: &mut dyn Output) {
+    let b = "{ not a brace }";
+    helper(b);
+}
+
+fn helper(b: &str) -> usize {
+    b.len()
+}
+"#;
+
+    #[test]
+    fn section_code_stops_at_the_next_section() {
+        assert_eq!(
+            section_code(SAMPLE, "learn", Some("First")).as_deref(),
+            Some("let a = 1;\noutln!(out, \"{}\", a);")
+        );
+        assert_eq!(
+            section_code(SAMPLE, "learn", None).as_deref(),
+            Some("let intro = 1;")
+        );
+        assert_eq!(section_code(SAMPLE, "learn", Some("Missing")), None);
+    }
+
+    #[test]
+    fn helper_only_section_shows_the_helpers() {
+        let code = section_code(SAMPLE, "learn", Some("Second")).unwrap();
+        assert!(code.starts_with("fn second_examples("), "{}", code);
+        assert!(code.ends_with("fn helper(b: &str) -> usize {\n    b.len()\n}"));
+        assert!(!code.contains("second_examples(out);"));
+    }
+
+    #[test]
+    fn braces_in_literals_and_comments_do_not_close_the_body() {
+        let source = concat!(
+            "fn tricky() -> usize {\n",
+            "    let quote = '\\'';\n",
+            "    let open = '{'; // }\n",
+            "    /* } */\n",
+            "    let text = \"}\\\"}\";\n",
+            "    let raw = r#\"}\"#;\n",
+            "    quote.len_utf8() + open.len_utf8() + text.len() + raw.len()\n",
+            "}\n",
+            "fn after() {}\n",
+        );
+        let function = function_source(source, "tricky").unwrap();
+        assert!(function.ends_with("raw.len()\n}"), "{}", function);
+        assert_eq!(function_source(source, "after"), Some("fn after() {}"));
+    }
+
+    #[test]
+    fn note_lines_become_warnings() {
+        let blocks = doc_blocks("Intro\n- item\nNote: mind the gap");
+        assert_eq!(
+            blocks,
+            vec![
+                Block::Paragraph("Intro".to_string()),
+                Block::List(vec!["item".to_string()]),
+                Block::Warning("mind the gap".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn every_entry_function_is_found() {
+        let registry = lessons::registry();
+        for lesson in registry.lessons() {
+            let meta = lesson.meta();
+            let source = lessons::module_source(meta.module).expect("module source");
+            let function = function_source(source, meta.entry)
+                .unwrap_or_else(|| panic!("{}: no fn {}", meta.id, meta.entry));
+            assert!(function.ends_with('}'), "{}: body not closed", meta.id);
+            assert!(doc_comment(source, meta.entry).is_some(), "{}", meta.id);
+        }
+    }
+
+    #[test]
+    fn every_printed_section_has_code() {
+        let registry = lessons::registry();
+        let mut missing = Vec::new();
+        for lesson in registry.lessons() {
+            let meta = lesson.meta();
+            let source = lessons::module_source(meta.module).expect("module source");
+            let mut out = CaptureOutput::default();
+            lesson.run(&mut out);
+            let titles: Vec<String> = out
+                .into_blocks()
+                .into_iter()
+                .filter_map(|block| match block {
+                    Block::Section(title) => Some(title),
+                    _ => None,
+                })
+                .collect();
+
+            if titles.is_empty() && section_code(source, meta.entry, None).is_none() {
+                missing.push(format!("{} (whole lesson)", meta.id));
+            }
+            for title in titles {
+                let code = section_code(source, meta.entry, Some(&title));
+                if code.is_none_or(|code| code.trim().is_empty()) {
+                    missing.push(format!("{}: \"{}\"", meta.id, title));
+                }
+            }
+        }
+        assert!(missing.is_empty(), "sections without code: {:?}", missing);
+    }
+
+    #[test]
+    fn iterator_sections_show_their_example_functions() {
+        let source = lessons::module_source("iterators").unwrap();
+        let entry = lessons::registry().find("iterators").unwrap().meta().entry;
+        for (title, function) in [
+            ("map(): Transform Elements", "map_examples"),
+            ("filter(): Select Elements", "filter_examples"),
+        ] {
+            let code = section_code(source, entry, Some(title)).unwrap();
+            assert_eq!(code, function_source(source, function).unwrap());
+        }
+    }
+}
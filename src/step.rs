@@ -0,0 +1,304 @@
+//! ============================================================================
+//! STEP MODULE - Paging through lessons one section at a time
+//! ============================================================================
+//! `rust-lab run <lesson> --step` captures each selected lesson into a
+//! `CaptureOutput` first, cuts it into pages at every `Section` block and
+//! prints one page at a time, waiting for a key (and Enter) in between:
+//!
+//! | Key          | Action                                          |
+//! |--------------|-------------------------------------------------|
+//! | Enter or `n` | Next section (the next lesson after the last)   |
+//! | `b`          | Previous section, re-rendered from the capture  |
+//! | `q`          | Stop here; the next `--step` run resumes here   |
+//!
+//! Page 0 of a lesson is its heading and introduction; page N starts at
+//! section N. The pages of all selected lessons form one sequence, so `b` on
+//! a lesson's first page goes back into the previous one. Going back re-runs
+//! nothing: the captured blocks are rendered again.
+//! ============================================================================
+
+use std::io::{self, BufRead, Write};
+
+use crate::content::Block;
+use crate::highlight::Colors;
+use crate::lessons::Lesson;
+use crate::render::{self, TerminalRenderer};
+use crate::table::Style;
+
+/// One captured lesson, cut at its sections
+pub struct Stepper<'l> {
+    pub lesson: &'l dyn Lesson,
+    pub pages: Vec<Vec<Block>>,
+}
+
+impl<'l> Stepper<'l> {
+    /// Splits the captured `blocks` of `lesson` before every `Section`
+    pub fn new(lesson: &'l dyn Lesson, blocks: Vec<Block>) -> Stepper<'l> {
+        let mut pages = vec![Vec::new()];
+        for block in blocks {
+            if matches!(block, Block::Section(_)) {
+                pages.push(Vec::new());
+            }
+            pages.last_mut().expect("starts with one page").push(block);
+        }
+        Stepper { lesson, pages }
+    }
+}
+
+/// A page of one of the lessons: (lesson index, page index)
+pub type Position = (usize, usize);
+
+/// How a step-through session ended
+#[derive(Debug, PartialEq)]
+pub struct Stepped {
+    /// Indexes of the lessons paged past their last section
+    pub finished: Vec<usize>,
+    /// Where the learner stopped; `None` when every lesson was finished
+    pub stopped_at: Option<Position>,
+}
+
+/// What the learner asked for at a pause
+enum Key {
+    Next,
+    Back,
+    Quit,
+}
+
+fn read_key(input: &mut dyn BufRead, out: &mut dyn Write) -> io::Result<Key> {
+    loop {
+        write!(out, "> ")?;
+        out.flush()?;
+        let mut reply = String::new();
+        if input.read_line(&mut reply)? == 0 {
+            return Ok(Key::Quit);
+        }
+        match reply.trim().to_lowercase().as_str() {
+            "" | "n" | "next" => return Ok(Key::Next),
+            "b" | "back" => return Ok(Key::Back),
+            "q" | "quit" => return Ok(Key::Quit),
+            _ => writeln!(out, "  (Enter or n: next · b: back · q: quit)")?,
+        }
+    }
+}
+
+/// Shows the pages of `lessons` from `start`, one at a time
+pub fn run(
+    lessons: &[Stepper],
+    start: Position,
+    style: Style,
+    colors: Colors,
+    input: &mut dyn BufRead,
+    out: &mut dyn Write,
+) -> io::Result<Stepped> {
+    let mut finished = Vec::new();
+    let (mut lesson, mut page) = start;
+
+    while lesson < lessons.len() {
+        let current = &lessons[lesson];
+        let mut renderer = TerminalRenderer::new(style, colors);
+        write!(
+            out,
+            "{}",
+            render::render_all(&mut renderer, &current.pages[page])
+        )?;
+
+        let last = current.pages.len() - 1;
+        let next = if page < last {
+            "Enter: next section"
+        } else if lesson + 1 < lessons.len() {
+            "Enter: next lesson"
+        } else {
+            "Enter: finish"
+        };
+        let place = match page {
+            0 => "introduction".to_string(),
+            _ => format!("section {}/{}", page, last),
+        };
+        writeln!(
+            out,
+            "\n── Lesson {} · {} · {} · b: back · q: quit ──",
+            current.lesson.number(),
+            place,
+            next
+        )?;
+
+        match read_key(input, out)? {
+            Key::Next if page < last => page += 1,
+            Key::Next => {
+                if !finished.contains(&lesson) {
+                    finished.push(lesson);
+                }
+                lesson += 1;
+                page = 0;
+            }
+            Key::Back if page > 0 => page -= 1,
+            Key::Back if lesson > 0 => {
+                lesson -= 1;
+                page = lessons[lesson].pages.len() - 1;
+            }
+            Key::Back => writeln!(out, "  (already at the start)")?,
+            Key::Quit => {
+                return Ok(Stepped {
+                    finished,
+                    stopped_at: Some((lesson, page)),
+                });
+            }
+        }
+    }
+    Ok(Stepped {
+        finished,
+        stopped_at: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lessons::{LessonMeta, Phase};
+    use crate::output::Output;
+
+    struct Fake(&'static LessonMeta);
+
+    impl Lesson for Fake {
+        fn meta(&self) -> &'static LessonMeta {
+            self.0
+        }
+
+        fn run(&self, _out: &mut dyn Output) {}
+    }
+
+    const fn meta(id: &'static str, number: u32) -> LessonMeta {
+        LessonMeta {
+            id,
+            number,
+            title: id,
+            phase: Phase::Basics,
+            module: "basics",
+            entry: "learn",
+            requires: &[],
+        }
+    }
+
+    static FIRST: Fake = Fake(&meta("first", 1));
+    static SECOND: Fake = Fake(&meta("second", 2));
+
+    /// An introduction paragraph followed by one section per title
+    fn stepper(lesson: &'static Fake, sections: &[&str]) -> Stepper<'static> {
+        let mut blocks = vec![Block::Paragraph(format!("{} intro", lesson.0.id))];
+        for title in sections {
+            blocks.push(Block::Section(title.to_string()));
+            blocks.push(Block::Paragraph(format!("{} text", title)));
+        }
+        Stepper::new(lesson, blocks)
+    }
+
+    /// Runs from the start with `keys` typed, returning the result and what
+    /// was printed
+    fn step(lessons: &[Stepper], keys: &str) -> (Stepped, String) {
+        let mut out = Vec::new();
+        let stepped = run(
+            lessons,
+            (0, 0),
+            Style::Ascii,
+            Colors::Plain,
+            &mut keys.as_bytes(),
+            &mut out,
+        )
+        .unwrap();
+        (stepped, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn pages_split_before_each_section() {
+        let stepper = stepper(&FIRST, &["One", "Two"]);
+        assert_eq!(
+            stepper.pages,
+            [
+                vec![Block::Paragraph("first intro".to_string())],
+                vec![
+                    Block::Section("One".to_string()),
+                    Block::Paragraph("One text".to_string()),
+                ],
+                vec![
+                    Block::Section("Two".to_string()),
+                    Block::Paragraph("Two text".to_string()),
+                ],
+            ]
+        );
+        assert_eq!(Stepper::new(&FIRST, Vec::new()).pages.len(), 1);
+    }
+
+    #[test]
+    fn enter_pages_through_every_lesson() {
+        let lessons = [stepper(&FIRST, &["One"]), stepper(&SECOND, &[])];
+        let (stepped, printed) = step(&lessons, "\nn\n\n");
+        assert_eq!(
+            stepped,
+            Stepped {
+                finished: vec![0, 1],
+                stopped_at: None,
+            }
+        );
+        assert!(printed.contains("Lesson 1 · introduction · Enter: next section"));
+        assert!(printed.contains("Lesson 1 · section 1/1 · Enter: next lesson"));
+        assert!(printed.contains("Lesson 2 · introduction · Enter: finish"));
+    }
+
+    #[test]
+    fn back_crosses_into_the_previous_lesson() {
+        let lessons = [stepper(&FIRST, &["One", "Two"]), stepper(&SECOND, &[])];
+        let (stepped, printed) = step(&lessons, "\n\n\nb\nq\n");
+        assert_eq!(stepped.stopped_at, Some((0, 2)));
+        assert_eq!(printed.matches("Two text").count(), 2);
+    }
+
+    #[test]
+    fn back_at_the_start_stays_put() {
+        let lessons = [stepper(&FIRST, &["One"])];
+        let (stepped, printed) = step(&lessons, "b\nq\n");
+        assert_eq!(stepped.stopped_at, Some((0, 0)));
+        assert!(printed.contains("(already at the start)"));
+    }
+
+    #[test]
+    fn quit_reports_where_it_stopped() {
+        let lessons = [stepper(&FIRST, &["One"]), stepper(&SECOND, &["Two"])];
+        let (stepped, _) = step(&lessons, "\n\n\nquit\n");
+        assert_eq!(
+            stepped,
+            Stepped {
+                finished: vec![0],
+                stopped_at: Some((1, 1)),
+            }
+        );
+    }
+
+    #[test]
+    fn end_of_input_quits() {
+        let lessons = [stepper(&FIRST, &["One"])];
+        let (stepped, _) = step(&lessons, "\n");
+        assert_eq!(
+            stepped,
+            Stepped {
+                finished: Vec::new(),
+                stopped_at: Some((0, 1)),
+            }
+        );
+    }
+
+    #[test]
+    fn unknown_keys_ask_again() {
+        let lessons = [stepper(&FIRST, &[])];
+        let (stepped, printed) = step(&lessons, "x\n\n");
+        assert_eq!(stepped.stopped_at, None);
+        assert!(printed.contains("(Enter or n: next · b: back · q: quit)"));
+    }
+
+    #[test]
+    fn finishing_a_lesson_twice_lists_it_once() {
+        let lessons = [stepper(&FIRST, &[]), stepper(&SECOND, &[])];
+        let (stepped, _) = step(&lessons, "\nb\n\n\n");
+        assert_eq!(stepped.finished, [0, 1]);
+        assert_eq!(stepped.stopped_at, None);
+    }
+}
@@ -0,0 +1,383 @@
+//! ============================================================================
+//! TABLE MODULE - Building tables and lining them up in any terminal
+//! ============================================================================
+//! Tables are built from headers and rows, then rendered in one of three
+//! styles. Column widths are measured in terminal columns, not bytes or chars,
+//! so emoji like ✅ / ❌ (two columns) and box-drawing characters (one column)
+//! never push a border out of line.
+//!
+//! | Style      | Corners and rules    | Used by                    |
+//! |------------|----------------------|----------------------------|
+//! | `Box`      | `┌ ┬ ┐ ├ ┼ ┤ └ ┴ ┘ ─` | Terminal output            |
+//! | `Ascii`    | `+` and `-`          | Terminals with `TERM=dumb` |
+//! | `Markdown` | `---` / `:---:` rows | Markdown export            |
+//!
+//! `display_width()` and `pad()` are public for other fixed-width layouts
+//! (the banner box, the menu panes).
+//! ============================================================================
+
+/// How a table column lines up its cells
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Align {
+    Left,
+    Center,
+}
+
+/// How a table is drawn
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Style {
+    #[default]
+    Box,
+    Ascii,
+    Markdown,
+}
+
+impl Style {
+    /// `Box` unless the terminal declares it cannot draw lines (`TERM=dumb`)
+    pub fn for_terminal() -> Style {
+        match std::env::var("TERM") {
+            Ok(term) if term == "dumb" => Style::Ascii,
+            _ => Style::Box,
+        }
+    }
+}
+
+/// A table with a header row; every row has one cell per header
+#[derive(Debug, Clone, PartialEq)]
+pub struct Table {
+    pub headers: Vec<String>,
+    pub align: Vec<Align>,
+    /// Columns are at least this wide, even when every cell is narrower
+    pub min_widths: Vec<usize>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    /// Starts a table; every column is left-aligned until `align()` says otherwise
+    pub fn new(headers: &[&str]) -> Table {
+        Table {
+            headers: headers.iter().map(|h| h.to_string()).collect(),
+            align: vec![Align::Left; headers.len()],
+            min_widths: vec![0; headers.len()],
+            rows: Vec::new(),
+        }
+    }
+
+    /// Sets the alignment of one column
+    pub fn align(mut self, column: usize, align: Align) -> Table {
+        self.align[column] = align;
+        self
+    }
+
+    /// Keeps one column at least `width` terminal columns wide
+    pub fn min_width(mut self, column: usize, width: usize) -> Table {
+        self.min_widths[column] = width;
+        self
+    }
+
+    /// Appends a row; panics unless it has one cell per header
+    pub fn row(mut self, cells: &[&str]) -> Table {
+        assert_eq!(
+            cells.len(),
+            self.headers.len(),
+            "row width != header width in {:?}",
+            cells
+        );
+        self.rows
+            .push(cells.iter().map(|c| c.to_string()).collect());
+        self
+    }
+
+    /// Draws the table, one line per row plus borders, ending with a newline
+    pub fn render(&self, style: Style) -> String {
+        match style {
+            Style::Box => self.render_ruled(&BOX),
+            Style::Ascii => self.render_ruled(&ASCII),
+            Style::Markdown => self.render_markdown(),
+        }
+    }
+
+    /// Widest cell of each column, in terminal columns
+    fn widths(&self, cell: impl Fn(&str) -> String) -> Vec<usize> {
+        (0..self.headers.len())
+            .map(|col| {
+                std::iter::once(&self.headers[col])
+                    .chain(self.rows.iter().map(|row| &row[col]))
+                    .map(|text| display_width(&cell(text)))
+                    .fold(self.min_widths[col], usize::max)
+            })
+            .collect()
+    }
+
+    /// One line of cells, each padded to its column with one space either side
+    fn line(&self, cells: &[String], widths: &[usize], bar: &str) -> String {
+        let padded: Vec<String> = cells
+            .iter()
+            .zip(widths)
+            .zip(&self.align)
+            .map(|((cell, &width), &align)| format!(" {} ", pad(cell, width, align)))
+            .collect();
+        format!("{}{}{}\n", bar, padded.join(bar), bar)
+    }
+
+    fn render_ruled(&self, chars: &Rules) -> String {
+        let widths = self.widths(str::to_string);
+        let border = |[left, mid, right]: [&str; 3]| {
+            let segments: Vec<String> = widths
+                .iter()
+                .map(|w| chars.horizontal.repeat(w + 2))
+                .collect();
+            format!("{}{}{}\n", left, segments.join(mid), right)
+        };
+
+        let mut text = border(chars.top);
+        text.push_str(&self.line(&self.headers, &widths, chars.vertical));
+        text.push_str(&border(chars.middle));
+        for row in &self.rows {
+            text.push_str(&self.line(row, &widths, chars.vertical));
+        }
+        text.push_str(&border(chars.bottom));
+        text
+    }
+
+    fn render_markdown(&self) -> String {
+        let escape = |cell: &str| cell.replace('|', "\\|");
+        let escaped = |cells: &[String]| cells.iter().map(|c| escape(c)).collect::<Vec<_>>();
+        let widths = self.widths(escape);
+
+        let separator: Vec<String> = widths
+            .iter()
+            .zip(&self.align)
+            .map(|(&width, align)| match align {
+                Align::Left => "-".repeat(width + 2),
+                Align::Center => format!(":{}:", "-".repeat(width)),
+            })
+            .collect();
+
+        let mut text = self.line(&escaped(&self.headers), &widths, "|");
+        text.push_str(&format!("|{}|\n", separator.join("|")));
+        for row in &self.rows {
+            text.push_str(&self.line(&escaped(row), &widths, "|"));
+        }
+        text
+    }
+}
+
+/// The characters a ruled (`Box` / `Ascii`) table is drawn with
+struct Rules {
+    horizontal: &'static str,
+    vertical: &'static str,
+    top: [&'static str; 3],
+    middle: [&'static str; 3],
+    bottom: [&'static str; 3],
+}
+
+const BOX: Rules = Rules {
+    horizontal: "─",
+    vertical: "│",
+    top: ["┌", "┬", "┐"],
+    middle: ["├", "┼", "┤"],
+    bottom: ["└", "┴", "┘"],
+};
+
+const ASCII: Rules = Rules {
+    horizontal: "-",
+    vertical: "|",
+    top: ["+", "+", "+"],
+    middle: ["+", "+", "+"],
+    bottom: ["+", "+", "+"],
+};
+
+/// Pads `text` with spaces to `width` terminal columns
+pub fn pad(text: &str, width: usize, align: Align) -> String {
+    let free = width.saturating_sub(display_width(text));
+    let (left, right) = match align {
+        Align::Left => (0, free),
+        Align::Center => (free / 2, free - free / 2),
+    };
+    format!("{}{}{}", " ".repeat(left), text, " ".repeat(right))
+}
+
+/// Terminal columns taken by `text`
+///
+/// - Emoji and East Asian wide characters take two columns
+/// - Combining marks, zero-width joiners and variation selectors take none
+/// - A narrow symbol followed by U+FE0F (emoji presentation, e.g. ⚠️) takes two
+/// - Everything else, including box-drawing characters, takes one
+pub fn display_width(text: &str) -> usize {
+    let mut width = 0;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        let w = char_width(c);
+        width += if w == 1 && chars.peek() == Some(&'\u{FE0F}') {
+            2
+        } else {
+            w
+        };
+    }
+    width
+}
+
+fn char_width(c: char) -> usize {
+    match c as u32 {
+        // Zero width: combining marks, ZWSP/ZWJ/direction marks, variation selectors
+        0x0300..=0x036F | 0x200B..=0x200F | 0x20D0..=0x20FF | 0xFE00..=0xFE0F => 0,
+        // Symbols drawn as emoji by default
+        0x231A..=0x231B
+        | 0x23E9..=0x23EC
+        | 0x23F0
+        | 0x23F3
+        | 0x25FD..=0x25FE
+        | 0x2614..=0x2615
+        | 0x2648..=0x2653
+        | 0x267F
+        | 0x2693
+        | 0x26A1
+        | 0x26AA..=0x26AB
+        | 0x26BD..=0x26BE
+        | 0x26C4..=0x26C5
+        | 0x26CE
+        | 0x26D4
+        | 0x26EA
+        | 0x26F2..=0x26F3
+        | 0x26F5
+        | 0x26FA
+        | 0x26FD
+        | 0x2705
+        | 0x270A..=0x270B
+        | 0x2728
+        | 0x274C
+        | 0x274E
+        | 0x2753..=0x2755
+        | 0x2757
+        | 0x2795..=0x2797
+        | 0x27B0
+        | 0x27BF
+        | 0x2B1B..=0x2B1C
+        | 0x2B50
+        | 0x2B55 => 2,
+        // East Asian wide / fullwidth blocks
+        0x1100..=0x115F
+        | 0x2E80..=0x303E
+        | 0x3041..=0x33FF
+        | 0x3400..=0x4DBF
+        | 0x4E00..=0x9FFF
+        | 0xA000..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFE30..=0xFE4F
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6 => 2,
+        // Emoji planes (flags, pictographs, emoticons, transport, supplemental)
+        0x1F1E6..=0x1F1FF | 0x1F300..=0x1F64F | 0x1F680..=0x1F6FF | 0x1F900..=0x1FAFF => 2,
+        // CJK extensions
+        0x20000..=0x3FFFD => 2,
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Table {
+        Table::new(&["Case", "Ok"])
+            .align(1, Align::Center)
+            .row(&["&T", "✅"])
+            .row(&["a|b", "❌"])
+    }
+
+    #[test]
+    fn display_width_counts_terminal_columns() {
+        assert_eq!(display_width("abc"), 3);
+        assert_eq!(display_width("✅"), 2);
+        assert_eq!(display_width("🦀 ok"), 5);
+        assert_eq!(display_width("─┼─"), 3);
+        assert_eq!(display_width("e\u{301}"), 1); // e + combining acute
+        assert_eq!(display_width("⚠\u{FE0F}"), 2);
+        assert_eq!(display_width("⚠"), 1);
+        assert_eq!(display_width("日本"), 4);
+    }
+
+    #[test]
+    fn pad_fills_to_the_width_in_columns() {
+        assert_eq!(pad("ab", 5, Align::Left), "ab   ");
+        assert_eq!(pad("ab", 5, Align::Center), " ab  ");
+        assert_eq!(pad("✅", 5, Align::Center), " ✅  ");
+        assert_eq!(pad("e\u{301}", 3, Align::Left), "e\u{301}  ");
+        assert_eq!(pad("too wide", 3, Align::Left), "too wide");
+    }
+
+    #[test]
+    fn box_style_lines_up_double_width_cells() {
+        assert_eq!(
+            sample().render(Style::Box),
+            "\
+┌──────┬────┐
+│ Case │ Ok │
+├──────┼────┤
+│ &T   │ ✅ │
+│ a|b  │ ❌ │
+└──────┴────┘
+"
+        );
+    }
+
+    #[test]
+    fn ascii_style_uses_plus_and_minus() {
+        assert_eq!(
+            sample().render(Style::Ascii),
+            "\
++------+----+
+| Case | Ok |
++------+----+
+| &T   | ✅ |
+| a|b  | ❌ |
++------+----+
+"
+        );
+    }
+
+    #[test]
+    fn markdown_style_escapes_pipes_and_marks_centered_columns() {
+        assert_eq!(
+            sample().render(Style::Markdown),
+            "\
+| Case | Ok |
+|------|:--:|
+| &T   | ✅ |
+| a\\|b | ❌ |
+"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "row width != header width")]
+    fn short_rows_are_rejected() {
+        let _ = Table::new(&["Case", "Ok"]).row(&["&T"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "row width != header width")]
+    fn long_rows_are_rejected() {
+        let _ = Table::new(&["Case"]).row(&["&T", "✅"]);
+    }
+
+    #[test]
+    fn min_width_widens_narrow_columns_only() {
+        let table = sample().min_width(0, 6).min_width(1, 1);
+        assert_eq!(
+            table.render(Style::Ascii),
+            "+--------+----+\n| Case   | Ok |\n+--------+----+\n| &T     | ✅ |\n| a|b    | ❌ |\n+--------+----+\n"
+        );
+    }
+
+    #[test]
+    fn combining_marks_do_not_widen_a_column() {
+        let table = Table::new(&["Word"]).row(&["cafe\u{301}"]).row(&["tea"]);
+        assert_eq!(
+            table.render(Style::Ascii),
+            "+------+\n| Word |\n+------+\n| cafe\u{301} |\n| tea  |\n+------+\n"
+        );
+    }
+}
@@ -0,0 +1,439 @@
+//! ============================================================================
+//! VALIDATE MODULE - Startup checks for lesson metadata
+//! ============================================================================
+//! Checks performed on the registry before any command runs:
+//! - Lesson ids are unique
+//! - Lesson numbers run 1..=N with no gaps or duplicates
+//! - Phases never go backwards as lesson numbers increase
+//! - Every module declared in `lessons/mod.rs` registers at least one lesson
+//! - Prerequisites name known, earlier lessons and form no cycle
+//! - Every lesson's `module` and `entry` function exist in the source
+//! - The `/// N. TITLE` doc header above each entry matches the lesson number
+//!
+//! Tables that only change with the code (roadmap links, quiz questions,
+//! exercises, challenges, explain topics, lint rules) are checked by tests
+//! instead, so a typo in one of them fails `cargo test` rather than every
+//! command; `check_lesson_section()` is shared by those tests.
+//! ============================================================================
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use crate::graph;
+use crate::lessons::{self, Lesson, Registry};
+
+/// Every problem found while validating the registry
+#[derive(Debug)]
+pub struct ValidationReport {
+    problems: Vec<String>,
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "❌ Lesson metadata is inconsistent ({} problem{}):",
+            self.problems.len(),
+            if self.problems.len() == 1 { "" } else { "s" }
+        )?;
+        for problem in &self.problems {
+            writeln!(f, "  - {}", problem)?;
+        }
+        write!(
+            f,
+            "Fix the `LessonMeta` declarations in src/lessons/ and try again."
+        )
+    }
+}
+
+impl std::error::Error for ValidationReport {}
+
+/// Runs every check and collects all problems, rather than stopping at the first
+pub fn validate(registry: &Registry) -> Result<(), ValidationReport> {
+    let mut problems = Vec::new();
+
+    check_unique_ids(registry, &mut problems);
+    check_numbering(registry, &mut problems);
+    check_modules(registry, &mut problems);
+    check_prerequisites(registry, &mut problems);
+    for lesson in registry.lessons() {
+        check_source(lesson, &mut problems);
+    }
+
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationReport { problems })
+    }
+}
+
+fn check_unique_ids(registry: &Registry, problems: &mut Vec<String>) {
+    let mut seen: HashMap<&str, u32> = HashMap::new();
+    for lesson in registry.lessons() {
+        if let Some(first) = seen.insert(lesson.id(), lesson.number()) {
+            problems.push(format!(
+                "duplicate lesson id '{}' (lessons {} and {})",
+                lesson.id(),
+                first,
+                lesson.number()
+            ));
+        }
+    }
+}
+
+fn check_numbering(registry: &Registry, problems: &mut Vec<String>) {
+    let mut by_number: BTreeMap<u32, Vec<&str>> = BTreeMap::new();
+    for lesson in registry.lessons() {
+        by_number
+            .entry(lesson.number())
+            .or_default()
+            .push(lesson.id());
+    }
+
+    for (number, ids) in &by_number {
+        if ids.len() > 1 {
+            problems.push(format!(
+                "lesson number {} is used by {}",
+                number,
+                ids.iter()
+                    .map(|id| format!("'{}'", id))
+                    .collect::<Vec<_>>()
+                    .join(" and ")
+            ));
+        }
+    }
+
+    let highest = by_number.keys().next_back().copied().unwrap_or(0);
+    for missing in (1..=highest).filter(|n| !by_number.contains_key(n)) {
+        problems.push(format!(
+            "lesson number {} is missing (numbers must be contiguous)",
+            missing
+        ));
+    }
+
+    // Lessons are sorted by number, so phases must never go backwards
+    let lessons: Vec<&dyn Lesson> = registry.lessons().collect();
+    for pair in lessons.windows(2) {
+        let (prev, lesson) = (pair[0], pair[1]);
+        if prev.phase() > lesson.phase() {
+            problems.push(format!(
+                "lesson {} '{}' (phase {}) comes after lesson {} '{}' (phase {}); phases must not go backwards",
+                lesson.number(),
+                lesson.id(),
+                lesson.phase().number(),
+                prev.number(),
+                prev.id(),
+                prev.phase().number()
+            ));
+        }
+    }
+}
+
+fn check_modules(registry: &Registry, problems: &mut Vec<String>) {
+    let declared = lessons::declared_modules();
+
+    for module in &declared {
+        if !registry.lessons().any(|l| l.meta().module == *module) {
+            problems.push(format!("module '{}' registers no lessons", module));
+        }
+        if lessons::module_source(module).is_none() {
+            problems.push(format!(
+                "module '{}' is missing from lessons::module_source()",
+                module
+            ));
+        }
+    }
+    for lesson in registry.lessons() {
+        if !declared.contains(&lesson.meta().module) {
+            problems.push(format!(
+                "lesson '{}' names module '{}', which is not declared in lessons/mod.rs",
+                lesson.id(),
+                lesson.meta().module
+            ));
+        }
+    }
+}
+
+fn check_source(lesson: &dyn Lesson, problems: &mut Vec<String>) {
+    let meta = lesson.meta();
+    let Some(source) = lessons::module_source(meta.module) else {
+        return; // already reported by check_modules
+    };
+
+    let lines: Vec<&str> = source.lines().collect();
+    let signature = format!("pub fn {}(", meta.entry);
+    let Some(entry_line) = lines.iter().position(|l| l.starts_with(&signature)) else {
+        problems.push(format!(
+            "lesson '{}': no `pub fn {}` in {}.rs",
+            meta.id, meta.entry, meta.module
+        ));
+        return;
+    };
+
+    // The doc block directly above the entry holds a line like `/// 13. OPTION<T> TYPE`
+    let doc_number = lines[..entry_line]
+        .iter()
+        .rev()
+        .take_while(|l| l.starts_with("///"))
+        .find_map(|l| {
+            let (number, _) = l.trim_start_matches('/').trim().split_once(". ")?;
+            number.parse::<u32>().ok()
+        });
+
+    match doc_number {
+        Some(n) if n == meta.number => {}
+        Some(n) => problems.push(format!(
+            "lesson '{}': doc header says {} but metadata says {}",
+            meta.id, n, meta.number
+        )),
+        None => problems.push(format!(
+            "lesson '{}': `{}` has no numbered doc header",
+            meta.id, meta.entry
+        )),
+    }
+}
+
+fn check_prerequisites(registry: &Registry, problems: &mut Vec<String>) {
+    for lesson in registry.lessons() {
+        for &id in lesson.meta().requires {
+            // Unknown ids are reported by `graph::order` below
+            let Some(required) = registry.find(id) else {
+                continue;
+            };
+            if required.number() >= lesson.number() {
+                problems.push(format!(
+                    "lesson {} '{}' requires lesson {} '{}', which is not taught before it",
+                    lesson.number(),
+                    lesson.id(),
+                    required.number(),
+                    required.id()
+                ));
+            }
+        }
+    }
+    if let Err(err) = graph::order(registry) {
+        problems.push(err.to_string());
+    }
+}
+
+/// `lesson` exists and, if given, `section` is one of its own sections:
+/// an `out.section(..)` call in its entry function or a helper it calls
+#[cfg(test)]
+pub fn check_lesson_section(
+    registry: &Registry,
+    label: &str,
+    lesson: &str,
+    section: Option<&str>,
+    problems: &mut Vec<String>,
+) {
+    let Some(found) = registry.find(lesson) else {
+        problems.push(format!("{} names unknown lesson '{}'", label, lesson));
+        return;
+    };
+    let Some(section) = section else {
+        return;
+    };
+    let meta = found.meta();
+    let code = lessons::module_source(meta.module).unwrap_or_default();
+    let call = format!("out.section(\"{}\")", section);
+    let in_lesson = crate::source::example_functions(code, meta.entry)
+        .into_iter()
+        .filter_map(|name| crate::source::function_source(code, name))
+        .any(|body| body.contains(&call));
+    if !in_lesson {
+        problems.push(format!(
+            "{}: lesson '{}' has no section \"{}\"",
+            label, lesson, section
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lessons::{LessonMeta, Phase};
+    use crate::output::Output;
+
+    struct Fake(&'static LessonMeta);
+
+    impl Lesson for Fake {
+        fn meta(&self) -> &'static LessonMeta {
+            self.0
+        }
+
+        fn run(&self, _out: &mut dyn Output) {}
+    }
+
+    const fn meta(id: &'static str, number: u32, phase: Phase) -> LessonMeta {
+        LessonMeta {
+            id,
+            number,
+            title: id,
+            phase,
+            module: "basics",
+            entry: "learn_variables_and_mutability",
+            requires: &[],
+        }
+    }
+
+    fn registry(metas: impl IntoIterator<Item = &'static LessonMeta>) -> Registry {
+        let mut registry = Registry::default();
+        for meta in metas {
+            registry.register(Fake(meta));
+        }
+        registry
+    }
+
+    /// The problems one check finds in `registry`
+    fn found(registry: &Registry, check: fn(&Registry, &mut Vec<String>)) -> Vec<String> {
+        let mut problems = Vec::new();
+        check(registry, &mut problems);
+        problems
+    }
+
+    /// Every real lesson as a fake, except those `skip` rejects
+    fn real_lessons_except(skip: impl Fn(&LessonMeta) -> bool) -> Vec<&'static LessonMeta> {
+        let real = lessons::registry();
+        real.lessons()
+            .map(|lesson| lesson.meta())
+            .filter(|&meta| !skip(meta))
+            .collect()
+    }
+
+    #[test]
+    fn duplicate_ids_are_reported() {
+        static METAS: [LessonMeta; 3] = [
+            meta("a", 1, Phase::Basics),
+            meta("b", 2, Phase::Basics),
+            meta("a", 3, Phase::Basics),
+        ];
+        assert_eq!(
+            found(&registry(&METAS), check_unique_ids),
+            ["duplicate lesson id 'a' (lessons 1 and 3)"]
+        );
+    }
+
+    #[test]
+    fn numbering_gaps_and_duplicates_are_reported() {
+        static METAS: [LessonMeta; 3] = [
+            meta("a", 1, Phase::Basics),
+            meta("b", 3, Phase::Basics),
+            meta("c", 3, Phase::Basics),
+        ];
+        assert_eq!(
+            found(&registry(&METAS), check_numbering),
+            [
+                "lesson number 3 is used by 'b' and 'c'",
+                "lesson number 2 is missing (numbers must be contiguous)",
+            ]
+        );
+    }
+
+    #[test]
+    fn phases_going_backwards_are_reported() {
+        static METAS: [LessonMeta; 2] = [
+            meta("a", 1, Phase::DataStructures),
+            meta("b", 2, Phase::Basics),
+        ];
+        assert_eq!(
+            found(&registry(&METAS), check_numbering),
+            [
+                "lesson 2 'b' (phase 1) comes after lesson 1 'a' (phase 2); phases must not go backwards"
+            ]
+        );
+    }
+
+    #[test]
+    fn unregistered_and_undeclared_modules_are_reported() {
+        static UNDECLARED: LessonMeta = LessonMeta {
+            module: "strings",
+            ..meta("strings", 17, Phase::CustomTypes)
+        };
+        let mut metas = real_lessons_except(|meta| meta.module == "arrays");
+        metas.push(&UNDECLARED);
+        assert_eq!(
+            found(&registry(metas), check_modules),
+            [
+                "module 'arrays' registers no lessons",
+                "lesson 'strings' names module 'strings', which is not declared in lessons/mod.rs",
+            ]
+        );
+    }
+
+    #[test]
+    fn doc_headers_must_match_the_numbers() {
+        static RENUMBERED: LessonMeta = meta("variables", 2, Phase::Basics);
+        static NO_ENTRY: LessonMeta = LessonMeta {
+            entry: "learn_nothing",
+            ..meta("nothing", 3, Phase::Basics)
+        };
+        let mut problems = Vec::new();
+        check_source(&Fake(&RENUMBERED), &mut problems);
+        check_source(&Fake(&NO_ENTRY), &mut problems);
+        assert_eq!(
+            problems,
+            [
+                "lesson 'variables': doc header says 1 but metadata says 2",
+                "lesson 'nothing': no `pub fn learn_nothing` in basics.rs",
+            ]
+        );
+    }
+
+    #[test]
+    fn the_report_lists_every_problem() {
+        static METAS: [LessonMeta; 2] = [
+            meta("variables", 1, Phase::Basics),
+            meta("variables", 1, Phase::Basics),
+        ];
+        let mut metas = real_lessons_except(|meta| meta.number == 1);
+        metas.extend(&METAS);
+        let Err(report) = validate(&registry(metas)) else {
+            panic!("two lessons numbered 1 should be reported");
+        };
+        assert_eq!(
+            report.to_string(),
+            "❌ Lesson metadata is inconsistent (2 problems):\n\
+             \x20 - duplicate lesson id 'variables' (lessons 1 and 1)\n\
+             \x20 - lesson number 1 is used by 'variables' and 'variables'\n\
+             Fix the `LessonMeta` declarations in src/lessons/ and try again."
+        );
+    }
+
+    #[test]
+    fn registry_passes_the_startup_checks() {
+        if let Err(report) = validate(&lessons::registry()) {
+            panic!("{}", report);
+        }
+    }
+
+    #[test]
+    fn sections_must_belong_to_the_named_lesson() {
+        let registry = lessons::registry();
+        let mut problems = Vec::new();
+        check_lesson_section(
+            &registry,
+            "test",
+            "loops",
+            Some("While Loop"),
+            &mut problems,
+        );
+        assert_eq!(problems, Vec::<String>::new());
+
+        // Same module (basics.rs), different lesson
+        check_lesson_section(
+            &registry,
+            "test",
+            "variables",
+            Some("While Loop"),
+            &mut problems,
+        );
+        check_lesson_section(&registry, "test", "nope", None, &mut problems);
+        assert_eq!(
+            problems,
+            vec![
+                "test: lesson 'variables' has no section \"While Loop\"".to_string(),
+                "test names unknown lesson 'nope'".to_string(),
+            ]
+        );
+    }
+}